@@ -1,4 +1,5 @@
 use super::CsvEncoder;
+use bytes::BytesMut;
 use csv_core::{QuoteStyle, Terminator, WriterBuilder};
 /// Builds a [`CsvEncoder`] with various configuration knobs.
 ///
@@ -30,9 +31,7 @@ impl CsvEncoderBuilder {
 	/// To convert a builder into a writer, call one of the methods starting
 	/// with `from_`.
 	pub fn new() -> Self {
-		let mut builder = CsvEncoderBuilder::default();
-		builder.quote_style(QuoteStyle::Never);
-		builder
+		CsvEncoderBuilder::default()
 	}
 
 	/// The field delimiter to use when writing CSV.
@@ -45,14 +44,9 @@ impl CsvEncoderBuilder {
 
 	/// Whether to write a header row before writing any other row.
 	///
-	/// When this is enabled and the `serialize` method is used to write data
-	/// with something that contains field names (i.e., a struct), then a
-	/// header row is written containing the field names before any other row
-	/// is written.
-	///
-	/// This option has no effect when using other methods to write rows. That
-	/// is, if you don't use `serialize`, then you must write your header row
-	/// explicitly if you want a header row.
+	/// When this is enabled, the [`header`](crate::encode::Encoder::header)
+	/// of the encoder is the row of the column names of the records, which
+	/// senders write at the start of every file, including after rotation.
 	///
 	/// This is enabled by default.
 	pub const fn has_headers(&mut self, yes: bool) -> &mut Self {
@@ -144,10 +138,11 @@ impl CsvEncoderBuilder {
 
 	pub fn build(&self) -> CsvEncoder {
 		CsvEncoder {
-			buffer: vec![0; self.capacity],
+			field: BytesMut::new(),
 			capacity: self.capacity,
 			flexible: self.flexible,
 			has_headers: self.has_headers,
+			fields: None,
 			writer: self.builder.build(),
 		}
 	}
@@ -1,11 +1,18 @@
 use super::{CodecEncodeError, Encoder};
-use bytes::BytesMut;
+use bytes::{BufMut, BytesMut, buf::UninitSlice};
 use csv_core::{WriteResult, Writer};
-use prism_core::sender::Sendable;
+use log::warn;
+use prism_event::time::{self, TimeSettings, Timestamp};
+use serde::Serialize;
+use std::{
+	fmt::{Display, Write},
+	mem,
+	sync::Arc,
+};
 mod builder;
+mod records;
 pub use builder::CsvEncoderBuilder;
-use bytes::{BufMut, buf::UninitSlice};
-// use serde::Serialize;
+pub use records::CsvRecords;
 
 /// An already configured CSV writer.
 ///
@@ -18,160 +25,183 @@ use bytes::{BufMut, buf::UninitSlice};
 /// 1. Quote fields when necessary.
 /// 2. Check that all records have the same number of fields.
 /// 3. Write records with a single empty field correctly.
-/// 4. Automatically serialize normal Rust types to CSV records. When that
-///    type is a struct, a header row is automatically written corresponding
-///    to the fields of that struct.
+/// 4. Write the records of [`CsvRecords`] items, e.g. one row per sample of a
+///    metric batch, and their header row through [`Encoder::header`].
 /// 5. Use buffering intelligently and otherwise avoid allocation. (This means
 ///    that callers should not do their own buffering.)
 ///
-/// All of the above can be configured using a
-/// [`WriterBuilder`](struct.WriterBuilder.html).
-/// However, a `Writer` has a couple of convenience constructors (`from_path`
-/// and `from_writer`) that use the default configuration.
+/// All of the above can be configured using a [`CsvEncoderBuilder`].
 ///
 /// Note that the default configuration of a `Writer` uses `\n` for record
 /// terminators instead of `\r\n` as specified by RFC 4180. Use the
-/// `terminator` method on `WriterBuilder` to set the terminator to `\r\n` if
-/// it's desired.
+/// `terminator` method on `CsvEncoderBuilder` to set the terminator to `\r\n`
+/// if it's desired.
 pub struct CsvEncoder {
-	buffer: Vec<u8>,
+	/// Formatted field, reused from one field to the next
+	field: BytesMut,
 	capacity: usize,
 	flexible: bool,
 	has_headers: bool,
+	/// Number of fields of the first record, that the others must have
+	fields: Option<usize>,
 	writer: Writer,
 }
 
-// impl<S> Encoder<S> for CsvEncoder
-// where S: Sendable + Serialize {
-// 	type Error = CodecEncodeError;
-//
-// 	fn encode(&mut self, record: S, out: &mut BytesMut) -> Result<(), Self::Error> {
-// 		let hint = if self.capacity > 0 { self.capacity } else { 8 * 1024 };
-// 		out.reserve(hint);
-//
-// 		self.buffer.clear();
-// 		record.encode(&mut self.buffer)?;
-//
-// 		let mut field = &self.buffer[..];
-//
-// 		loop {
-// 			let (res, read, written) = {
-// 				let chunk = out.chunk_mut();
-// 				let buf = as_init_slice(chunk);
-// 				self.writer.field(field, buf)
-// 			};
-// 			field = &field[read..];
-// 			unsafe { out.advance_mut(written) };
-// 			match res {
-// 				WriteResult::InputEmpty => break,
-// 				WriteResult::OutputFull => out.reserve(8 * 1024),
-// 			}
-// 		}
-//
-// 		loop {
-// 			let res = {
-// 				let chunk = out.chunk_mut();
-// 				let buf = as_init_slice(chunk);
-// 				let (res, n) = self.writer.terminator(buf);
-// 				unsafe { out.advance_mut(n) };
-// 				res
-// 			};
-// 			if matches!(res, WriteResult::InputEmpty) {
-// 				break;
-// 			}
-// 			out.reserve(8 * 1024);
-// 		}
-//
-// 		Ok(())
-// 	}
-// }
-
-impl<I, S> Encoder<I> for CsvEncoder
+impl<S> Encoder<S> for CsvEncoder
 where
-	I: IntoIterator<Item = S>,
-	S: Sendable,
+	S: CsvRecords,
 {
 	type Error = CodecEncodeError;
 
-	fn encode(&mut self, records: I, out: &mut BytesMut) -> Result<(), Self::Error> {
+	fn encode(&mut self, item: &S, out: &mut BytesMut) -> Result<(), Self::Error> {
 		let hint = if self.capacity > 0 { self.capacity } else { 8 * 1024 };
 		out.reserve(hint);
+		item.write_records(&mut RecordWriter::new(self, out))
+	}
 
-		#[inline]
-		fn write_delimiter(writer: &mut Writer, out: &mut BytesMut) {
-			loop {
-				let res = {
-					let chunk = out.chunk_mut();
-					let buf = as_init_slice(chunk);
-					let (res, n) = writer.delimiter(buf);
-					unsafe { out.advance_mut(n) };
-					res
-				};
-				if matches!(res, WriteResult::InputEmpty) {
-					break;
-				}
-				out.reserve(8 * 1024);
-			}
+	fn header(&mut self, out: &mut BytesMut) -> Result<(), Self::Error> {
+		if !self.has_headers || S::HEADER.is_empty() {
+			return Ok(());
 		}
+		let mut record = RecordWriter::new(self, out);
+		for column in S::HEADER {
+			record.field(column);
+		}
+		record.end()
+	}
+}
 
-		#[inline]
-		fn write_field(writer: &mut Writer, mut field: &[u8], out: &mut BytesMut) {
-			loop {
-				let (res, read, written) = {
-					let chunk = out.chunk_mut();
-					let buf = as_init_slice(chunk);
-					writer.field(field, buf)
-				};
-				field = &field[read..];
-				unsafe { out.advance_mut(written) };
-				match res {
-					WriteResult::InputEmpty => break,
-					WriteResult::OutputFull => out.reserve(8 * 1024),
-				}
-			}
+/// Writes the fields of the records of one item, see [`CsvRecords`].
+pub struct RecordWriter<'a> {
+	encoder: &'a mut CsvEncoder,
+	out: &'a mut BytesMut,
+	settings: Arc<TimeSettings>,
+	/// Fields already written to the current record
+	fields: usize,
+	/// Length of `out` at the start of the current record
+	start: usize,
+}
+
+impl<'a> RecordWriter<'a> {
+	fn new(encoder: &'a mut CsvEncoder, out: &'a mut BytesMut) -> Self {
+		let start = out.len();
+		Self { encoder, out, settings: time::settings(), fields: 0, start }
+	}
+
+	/// Writes `field` as is, quoted when needed.
+	pub fn field(&mut self, field: impl AsRef<[u8]>) {
+		if self.fields > 0 {
+			write_delimiter(&mut self.encoder.writer, self.out);
 		}
+		write_field(&mut self.encoder.writer, field.as_ref(), self.out);
+		self.fields += 1;
+	}
 
-		#[inline]
-		fn write_finish(writer: &mut Writer, out: &mut BytesMut) {
-			loop {
-				let res = {
-					let chunk = out.chunk_mut();
-					let buf = as_init_slice(chunk);
-					let (res, n) = writer.finish(buf);
-					unsafe { out.advance_mut(n) };
-					res
-				};
-				if matches!(res, WriteResult::InputEmpty) {
-					break;
-				}
-				out.reserve(8 * 1024);
-			}
+	/// Writes `value` formatted with [`Display`], e.g. a number.
+	pub fn display(&mut self, value: impl Display) -> Result<(), CodecEncodeError> {
+		let mut field = mem::take(&mut self.encoder.field);
+		field.clear();
+		let written = write!(field, "{value}");
+		self.field(&field);
+		self.encoder.field = field;
+		Ok(written?)
+	}
+
+	/// Writes `value` as JSON, e.g. the buckets of a histogram or a set of tags.
+	pub fn json(&mut self, value: &impl Serialize) -> Result<(), CodecEncodeError> {
+		let mut field = mem::take(&mut self.encoder.field);
+		field.clear();
+		let written = serde_json::to_writer((&mut field).writer(), value);
+		self.field(&field);
+		self.encoder.field = field;
+		Ok(written?)
+	}
+
+	/// Writes `timestamp` in the format and timezone of the [time settings](time::settings).
+	pub fn timestamp(&mut self, timestamp: Timestamp) -> Result<(), CodecEncodeError> {
+		let mut field = mem::take(&mut self.encoder.field);
+		field.clear();
+		let written = self.settings.write(timestamp, &mut field);
+		self.field(&field);
+		self.encoder.field = field;
+		Ok(written?)
+	}
+
+	/// Terminates the current record, which must have as many fields as the
+	/// first one unless the encoder is flexible. A record with another number
+	/// of fields is left out of the output with a warning, so that it stays
+	/// valid CSV and the records around it are still written.
+	pub fn end(&mut self) -> Result<(), CodecEncodeError> {
+		let len = mem::take(&mut self.fields);
+		let error = match self.encoder.fields {
+			None => {
+				self.encoder.fields = Some(len);
+				None
+			},
+			Some(expected) if expected != len && !self.encoder.flexible => {
+				Some(CodecEncodeError::UnequalLengths { expected, len })
+			},
+			Some(_) => None,
+		};
+		// Also resets the writer of an invalid record, before removing it
+		write_terminator(&mut self.encoder.writer, self.out);
+		if let Some(error) = error {
+			warn!("Left out a CSV record: {error}");
+			self.out.truncate(self.start);
 		}
+		self.start = self.out.len();
+		Ok(())
+	}
+}
 
-		#[inline]
-		fn write_terminator(writer: &mut Writer, out: &mut BytesMut) {
-			loop {
-				let res = {
-					let chunk = out.chunk_mut();
-					let buf = as_init_slice(chunk);
-					let (res, n) = writer.terminator(buf);
-					unsafe { out.advance_mut(n) };
-					res
-				};
-				if matches!(res, WriteResult::InputEmpty) {
-					break;
-				}
-				out.reserve(8 * 1024);
-			}
+#[inline]
+fn write_delimiter(writer: &mut Writer, out: &mut BytesMut) {
+	loop {
+		let res = {
+			let chunk = out.chunk_mut();
+			let buf = as_init_slice(chunk);
+			let (res, n) = writer.delimiter(buf);
+			unsafe { out.advance_mut(n) };
+			res
+		};
+		if matches!(res, WriteResult::InputEmpty) {
+			break;
+		}
+		out.reserve(8 * 1024);
+	}
+}
+
+#[inline]
+fn write_field(writer: &mut Writer, mut field: &[u8], out: &mut BytesMut) {
+	loop {
+		let (res, read, written) = {
+			let chunk = out.chunk_mut();
+			let buf = as_init_slice(chunk);
+			writer.field(field, buf)
+		};
+		field = &field[read..];
+		unsafe { out.advance_mut(written) };
+		match res {
+			WriteResult::InputEmpty => break,
+			WriteResult::OutputFull => out.reserve(8 * 1024),
 		}
+	}
+}
 
-		for field in records.into_iter() {
-			let mut buf = BytesMut::new();
-			let _ = field.encode(&mut buf);
-			write_field(&mut self.writer, &buf, out);
-			write_terminator(&mut self.writer, out);
+#[inline]
+fn write_terminator(writer: &mut Writer, out: &mut BytesMut) {
+	loop {
+		let res = {
+			let chunk = out.chunk_mut();
+			let buf = as_init_slice(chunk);
+			let (res, n) = writer.terminator(buf);
+			unsafe { out.advance_mut(n) };
+			res
+		};
+		if matches!(res, WriteResult::InputEmpty) {
+			break;
 		}
-		Ok(())
+		out.reserve(8 * 1024);
 	}
 }
 
@@ -182,122 +212,198 @@ fn as_init_slice(chunk: &mut UninitSlice) -> &mut [u8] {
 	unsafe { std::slice::from_raw_parts_mut(ptr, len) }
 }
 
-#[test]
-fn serialize_fields() {
-	let event = vec![
-		"bar".to_string(),
-		123.to_string(),
-		"abc,bcd".to_string(),
-		3.1415925.to_string(),
-		"sp ace".to_string(),
-		"2023-02-27T15:04:49.363+08:00".to_string(),
-		"the \"quote\" should be escaped".to_string(),
-		true.to_string(),
-		"data".to_string(),
-	];
-	let mut encoder = CsvEncoderBuilder::new().build();
-	let mut bytes = BytesMut::new();
-
-	encoder.encode(event.clone(), &mut bytes).unwrap();
-	// encoder.encode(event, &mut bytes).unwrap();
-	println!("{}", String::from_utf8_lossy(&bytes));
-	assert_eq!(
-			bytes.freeze(),
-			b"bar,123,\"abc,bcd\",3.1415925,sp ace,2023-02-27T15:04:49.363+08:00,\"the \"\"quote\"\" should be escaped\",true,data".as_slice()
-		);
-}
-#[test]
-fn correct_quoting() {
-	let event = vec![
-		"hello world".to_string(),
-		1.to_string(),
-		"foo\"bar".to_string(),
-		"baz,bas".to_string(),
-	];
-
-	let mut default_bytes = BytesMut::new();
-	let mut never_bytes = BytesMut::new();
-	let mut always_bytes = BytesMut::new();
-	let mut non_numeric_bytes = BytesMut::new();
-
-	CsvEncoderBuilder::new()
-		.build()
-		.encode(event.clone(), &mut default_bytes)
-		.unwrap();
-
-	CsvEncoderBuilder::new()
-		.quote_style(csv_core::QuoteStyle::Never)
-		.build()
-		.encode(event.clone(), &mut never_bytes)
-		.unwrap();
-
-	CsvEncoderBuilder::new()
-		.quote_style(csv_core::QuoteStyle::Always)
-		.build()
-		.encode(event.clone(), &mut always_bytes)
-		.unwrap();
-
-	CsvEncoderBuilder::new()
-		.quote_style(csv_core::QuoteStyle::NonNumeric)
-		.build()
-		.encode(event.clone(), &mut non_numeric_bytes)
-		.unwrap();
-
-	assert_eq!(default_bytes.freeze(), b"hello world,1,\"foo\"\"bar\",\"baz,bas\"".as_slice());
-	assert_eq!(never_bytes.freeze(), b"hello world,1,foo\"bar,baz,bas".as_slice());
-	assert_eq!(
-		always_bytes.freeze(),
-		b"\"hello world\",\"1\",\"foo\"\"bar\",\"baz,bas\"".as_slice()
-	);
-	assert_eq!(
-		non_numeric_bytes.freeze(),
-		b"\"hello world\",1,\"foo\"\"bar\",\"baz,bas\"".as_slice()
-	);
-}
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use prism_event::{
+		counter,
+		event::{Event, Severity},
+		gauge, info_metric,
+		metric::MetricBatch,
+		string_metric,
+	};
+
+	/// A record of the given fields, without header
+	struct Row(Vec<&'static str>);
+
+	impl CsvRecords for Row {
+		const HEADER: &'static [&'static str] = &[];
+
+		fn write_records(&self, writer: &mut RecordWriter<'_>) -> Result<(), CodecEncodeError> {
+			for field in &self.0 {
+				writer.field(field);
+			}
+			writer.end()
+		}
+	}
+
+	fn encode<S: CsvRecords>(encoder: &mut CsvEncoder, item: &S) -> BytesMut {
+		let mut bytes = BytesMut::new();
+		encoder.encode(item, &mut bytes).unwrap();
+		bytes
+	}
 
-#[test]
-fn custom_delimiter() {
-	let event = vec!["value1".to_string(), "value2".to_string()];
-	let mut encoder = CsvEncoderBuilder::new().delimiter(b'\t').build();
+	fn read(bytes: &[u8]) -> Vec<csv::StringRecord> {
+		csv::Reader::from_reader(bytes).records().map(Result::unwrap).collect()
+	}
 
-	let mut bytes = BytesMut::new();
+	#[test]
+	fn serialize_fields() {
+		let event = Row(vec![
+			"bar",
+			"123",
+			"abc,bcd",
+			"3.1415925",
+			"sp ace",
+			"2023-02-27T15:04:49.363+08:00",
+			"the \"quote\" should be escaped",
+			"true",
+			"data",
+		]);
+		let bytes = encode(&mut CsvEncoderBuilder::new().build(), &event);
+		assert_eq!(
+			bytes.freeze(),
+			b"bar,123,\"abc,bcd\",3.1415925,sp ace,2023-02-27T15:04:49.363+08:00,\"the \"\"quote\"\" should be escaped\",true,data\n".as_slice()
+		);
+	}
 
-	encoder.encode(event, &mut bytes).unwrap();
+	#[test]
+	fn correct_quoting() {
+		let event = Row(vec!["hello world", "1", "foo\"bar", "baz,bas"]);
 
-	assert_eq!(bytes.freeze(), b"value1\tvalue2".as_slice());
-}
+		let default_bytes = encode(&mut CsvEncoderBuilder::new().build(), &event);
+		let never_bytes = encode(
+			&mut CsvEncoderBuilder::new().quote_style(csv_core::QuoteStyle::Never).build(),
+			&event,
+		);
+		let always_bytes = encode(
+			&mut CsvEncoderBuilder::new().quote_style(csv_core::QuoteStyle::Always).build(),
+			&event,
+		);
+		let non_numeric_bytes = encode(
+			&mut CsvEncoderBuilder::new().quote_style(csv_core::QuoteStyle::NonNumeric).build(),
+			&event,
+		);
 
-#[test]
-fn custom_escape_char() {
-	let mut encoder = CsvEncoderBuilder::new().escape(b'\\').double_quote(false).build();
-	let event = vec!["foo\"bar".to_string()];
+		assert_eq!(
+			default_bytes.freeze(),
+			b"hello world,1,\"foo\"\"bar\",\"baz,bas\"\n".as_slice()
+		);
+		assert_eq!(never_bytes.freeze(), b"hello world,1,foo\"bar,baz,bas\n".as_slice());
+		assert_eq!(
+			always_bytes.freeze(),
+			b"\"hello world\",\"1\",\"foo\"\"bar\",\"baz,bas\"\n".as_slice()
+		);
+		assert_eq!(
+			non_numeric_bytes.freeze(),
+			b"\"hello world\",1,\"foo\"\"bar\",\"baz,bas\"\n".as_slice()
+		);
+	}
 
-	let mut bytes = BytesMut::new();
+	#[test]
+	fn custom_delimiter() {
+		let event = Row(vec!["value1", "value2"]);
+		let bytes = encode(&mut CsvEncoderBuilder::new().delimiter(b'\t').build(), &event);
+		assert_eq!(bytes.freeze(), b"value1\tvalue2\n".as_slice());
+	}
 
-	encoder.encode(event, &mut bytes).unwrap();
+	#[test]
+	fn custom_terminator() {
+		let event = Row(vec!["value1", "value2"]);
+		let mut encoder = CsvEncoderBuilder::new().terminator(csv_core::Terminator::CRLF).build();
+		assert_eq!(encode(&mut encoder, &event).freeze(), b"value1,value2\r\n".as_slice());
+	}
 
-	assert_eq!(bytes.freeze(), b"\"foo\\\"bar\"".as_slice());
-}
+	#[test]
+	fn custom_escape_char() {
+		let mut encoder = CsvEncoderBuilder::new().escape(b'\\').double_quote(false).build();
+		let bytes = encode(&mut encoder, &Row(vec!["foo\"bar"]));
+		assert_eq!(bytes.freeze(), b"\"foo\\\"bar\"\n".as_slice());
+	}
 
-#[test]
-fn custom_quote_char() {
-	let event = vec!["foo \" $ bar".to_string()];
-	let mut encoder = CsvEncoderBuilder::new().quote(b'$').build();
-	let mut bytes = BytesMut::new();
+	#[test]
+	fn custom_quote_char() {
+		let mut encoder = CsvEncoderBuilder::new().quote(b'$').build();
+		let bytes = encode(&mut encoder, &Row(vec!["foo \" $ bar"]));
+		assert_eq!(bytes.freeze(), b"$foo \" $$ bar$\n".as_slice());
+	}
 
-	encoder.encode(event, &mut bytes).unwrap();
-	// TODO:
-	assert_eq!(bytes.freeze(), b"$foo \" $$ bar$$$\n".as_slice());
-}
+	#[test]
+	fn more_input_then_capacity() {
+		let mut encoder = CsvEncoderBuilder::new().buffer_capacity(3).build();
+		let bytes = encode(&mut encoder, &Row(vec!["foo bar"]));
+		assert_eq!(bytes.freeze(), b"foo bar\n".as_slice());
+	}
 
-#[test]
-fn more_input_then_capacity() {
-	let event = vec!["foo bar".to_string()];
-	let mut encoder = CsvEncoderBuilder::new().buffer_capacity(3).build();
+	#[test]
+	fn unequal_lengths() {
+		let mut encoder = CsvEncoderBuilder::new().build();
+		encode(&mut encoder, &Row(vec!["a", "b"]));
+		let mut bytes = BytesMut::from("a,b\n");
+		encoder.encode(&Row(vec!["c,d"]), &mut bytes).unwrap();
+		// the invalid record is left out and the next ones are written as usual
+		assert_eq!(bytes, b"a,b\n".as_slice());
+		encoder.encode(&Row(vec!["c,d", "e"]), &mut bytes).unwrap();
+		assert_eq!(bytes, b"a,b\n\"c,d\",e\n".as_slice());
+
+		// including the records of the same item around it
+		let rows = vec![Row(vec!["f", "g"]), Row(vec!["h", "i", "j"]), Row(vec!["k", "l"])];
+		assert_eq!(encode(&mut encoder, &rows), b"f,g\nk,l\n".as_slice());
+
+		let mut encoder = CsvEncoderBuilder::new().flexible(true).build();
+		encode(&mut encoder, &Row(vec!["a", "b"]));
+		assert_eq!(encode(&mut encoder, &Row(vec!["a"])).freeze(), b"a\n".as_slice());
+	}
 
-	let mut bytes = BytesMut::new();
+	#[test]
+	fn metric_columns() {
+		let batch: MetricBatch = [
+			counter!("read_completed", 42u64, "disk", "device" => "sda"),
+			gauge!("temperature", 36.5, "hwmon"),
+			info_metric!("disk_info", namespace = "disk", "model" => "Samsung SSD 870, 1TB"),
+			string_metric!("firmware", "2B6Q", namespace = "disk"),
+		]
+		.into_iter()
+		.collect();
+
+		let mut encoder = CsvEncoderBuilder::new().build();
+		let mut bytes = BytesMut::new();
+		Encoder::<MetricBatch>::header(&mut encoder, &mut bytes).unwrap();
+		encoder.encode(&batch, &mut bytes).unwrap();
+
+		let text = std::str::from_utf8(&bytes).unwrap();
+		assert!(text.starts_with("name,namespace,value,timestamp,tags\nread_completed,disk,42,"));
+		let rows = read(&bytes);
+		assert_eq!(rows.len(), 4);
+		assert!(rows.iter().all(|row| row.len() == 5));
+		assert_eq!(&rows[0][4], r#"{"device":"sda"}"#);
+		assert_eq!((&rows[1][2], &rows[1][4]), ("36.5", ""));
+		assert_eq!((&rows[2][2], &rows[2][4]), ("1", r#"{"model":"Samsung SSD 870, 1TB"}"#));
+		assert_eq!(&rows[3][2], "2B6Q");
+		assert_eq!(&rows[0][3], &rows[3][3]);
+	}
 
-	encoder.encode(event, &mut bytes).unwrap();
+	#[test]
+	fn event_columns() {
+		let event = Event::new("oom_kill", Severity::Error, "Killed process 42 (java), UID 0")
+			.with_namespace(Some("kmsg"));
+		let mut encoder = CsvEncoderBuilder::new().delimiter(b';').build();
+		let mut bytes = BytesMut::new();
+		Encoder::<Vec<Event>>::header(&mut encoder, &mut bytes).unwrap();
+		encoder.encode(&vec![event], &mut bytes).unwrap();
+
+		let text = std::str::from_utf8(&bytes).unwrap();
+		assert!(text.starts_with(
+			"name;namespace;severity;message;timestamp;fields;tags\noom_kill;kmsg;error;Killed process 42 (java), UID 0;"
+		));
+		assert!(text.ends_with(";;\n"));
+	}
 
-	assert_eq!(bytes.freeze(), b"foo bar\n".as_slice());
+	#[test]
+	fn no_headers() {
+		let mut encoder = CsvEncoderBuilder::new().has_headers(false).build();
+		let mut bytes = BytesMut::new();
+		Encoder::<MetricBatch>::header(&mut encoder, &mut bytes).unwrap();
+		assert!(bytes.is_empty());
+	}
 }
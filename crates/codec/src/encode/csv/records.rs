@@ -0,0 +1,118 @@
+use super::RecordWriter;
+use crate::encode::CodecEncodeError;
+use prism_event::{
+	event::Event,
+	metric::{Metric, MetricBatch, MetricTags, MetricValue, StaticTags, static_tags},
+	time::Timestamp,
+};
+
+/// Columns of metric rows, the resolved tags being flattened into one JSON object.
+const METRIC_HEADER: &[&str] = &["name", "namespace", "value", "timestamp", "tags"];
+
+/// Columns of event rows, the fields and the static tags each being flattened
+/// into one JSON object.
+const EVENT_HEADER: &[&str] =
+	&["name", "namespace", "severity", "message", "timestamp", "fields", "tags"];
+
+/// Items written as CSV rows, e.g. one row per sample of a [`MetricBatch`].
+///
+/// Every row of a type has the same columns so that the output loads as a
+/// table, e.g. with pandas or ClickHouse.
+pub trait CsvRecords {
+	/// Names of the columns, written as the header row unless empty.
+	const HEADER: &'static [&'static str];
+
+	/// Writes the records of this item, each one ended with [`RecordWriter::end`].
+	fn write_records(&self, writer: &mut RecordWriter<'_>) -> Result<(), CodecEncodeError>;
+}
+
+impl<T: CsvRecords> CsvRecords for Vec<T> {
+	const HEADER: &'static [&'static str] = T::HEADER;
+
+	fn write_records(&self, writer: &mut RecordWriter<'_>) -> Result<(), CodecEncodeError> {
+		self.iter().try_for_each(|item| item.write_records(writer))
+	}
+}
+
+impl CsvRecords for Metric {
+	const HEADER: &'static [&'static str] = METRIC_HEADER;
+
+	fn write_records(&self, writer: &mut RecordWriter<'_>) -> Result<(), CodecEncodeError> {
+		let static_tags = static_tags();
+		let sample = Sample { name: self.name(), namespace: self.namespace(), tags: self.tags() };
+		sample.write(writer, &static_tags, self.value(), self.timestamp())
+	}
+}
+
+impl CsvRecords for MetricBatch {
+	const HEADER: &'static [&'static str] = METRIC_HEADER;
+
+	fn write_records(&self, writer: &mut RecordWriter<'_>) -> Result<(), CodecEncodeError> {
+		let static_tags = static_tags();
+		for (series, value) in self.iter() {
+			let sample =
+				Sample { name: series.name(), namespace: series.namespace(), tags: series.tags() };
+			sample.write(writer, &static_tags, value, self.timestamp())?;
+		}
+		Ok(())
+	}
+}
+
+impl CsvRecords for Event {
+	const HEADER: &'static [&'static str] = EVENT_HEADER;
+
+	fn write_records(&self, writer: &mut RecordWriter<'_>) -> Result<(), CodecEncodeError> {
+		writer.field(self.name());
+		writer.field(self.namespace().unwrap_or(""));
+		writer.field(self.severity().as_str());
+		writer.field(self.message());
+		writer.timestamp(self.timestamp())?;
+		match self.fields() {
+			Some(fields) if !fields.is_empty() => writer.json(fields)?,
+			_ => writer.field(""),
+		}
+		// Global and namespace tags, e.g. the host identity
+		let static_tags = static_tags();
+		let tags = static_tags.resolve_tags(self.namespace(), None);
+		if tags.is_empty() {
+			writer.field("");
+		} else {
+			writer.json(&tags)?;
+		}
+		writer.end()
+	}
+}
+
+/// Series of a metric row, whether from a [`Metric`] or a [`MetricBatch`].
+struct Sample<'a> {
+	name: &'a str,
+	namespace: Option<&'a str>,
+	tags: Option<&'a MetricTags>,
+}
+
+impl Sample<'_> {
+	fn write(
+		&self,
+		writer: &mut RecordWriter<'_>,
+		static_tags: &StaticTags,
+		value: &MetricValue,
+		timestamp: Timestamp,
+	) -> Result<(), CodecEncodeError> {
+		writer.field(self.name);
+		writer.field(self.namespace.unwrap_or(""));
+		match value {
+			MetricValue::Counter(value) | MetricValue::Gauge(value) => writer.display(value)?,
+			MetricValue::Info => writer.field("1"),
+			MetricValue::String(value) => writer.field(value.as_bytes()),
+			compound => writer.json(compound)?,
+		}
+		writer.timestamp(timestamp)?;
+		let tags = static_tags.resolve_tags(self.namespace, self.tags);
+		if tags.is_empty() {
+			writer.field("");
+		} else {
+			writer.json(&tags)?;
+		}
+		writer.end()
+	}
+}
@@ -0,0 +1,118 @@
+use prism_event::metric::MetricValue;
+use std::fmt::{self, Write};
+
+/// One number of a sample, for the formats writing a single value per line
+/// under a dotted path, i.e. Graphite and StatsD.
+pub(crate) enum Number<'a> {
+	/// Value of a counter or gauge, or `1` for an info metric
+	Value(f64),
+	/// Count of a histogram or summary, written under `.count`
+	Count(u64),
+	/// Sum of a histogram or summary, written under `.sum`
+	Sum(f64),
+	/// Cumulative count of a histogram bucket by upper bound, e.g. `.bucket.0_5`
+	Bucket(f64, u64),
+	/// Value of a summary quantile, e.g. `.quantile.0_99`
+	Quantile(f64, f64),
+	/// Whether a state of a state set is the current one, e.g. `.up`
+	State(&'a str, bool),
+}
+
+impl<'a> Number<'a> {
+	/// Numbers of `value`, none for a string metric that has no numeric value.
+	/// Only the buckets of a histogram are collected, the numbers of the other
+	/// values are borrowed from them.
+	pub(crate) fn of(value: &'a MetricValue) -> impl Iterator<Item = Self> + 'a {
+		let single = match value {
+			MetricValue::Counter(value) | MetricValue::Gauge(value) => Some(*value),
+			MetricValue::Info => Some(1.0),
+			_ => None,
+		};
+		let totals = match value {
+			MetricValue::Histogram(histogram) => Some((histogram.count(), histogram.sum())),
+			MetricValue::Summary(summary) => Some((summary.count(), summary.sum())),
+			_ => None,
+		};
+		let buckets = match value {
+			MetricValue::Histogram(histogram) => histogram.buckets().cumulative(),
+			_ => Vec::new(),
+		};
+		let quantiles = match value {
+			MetricValue::Summary(summary) => summary.quantiles(),
+			_ => &[],
+		};
+		let states = match value {
+			MetricValue::StateSet(states) => states.states(),
+			_ => &[],
+		};
+
+		single
+			.map(Self::Value)
+			.into_iter()
+			.chain(totals.into_iter().flat_map(|(count, sum)| [Self::Count(count), Self::Sum(sum)]))
+			.chain(buckets.into_iter().map(|(bound, count)| Self::Bucket(bound, count)))
+			.chain(quantiles.iter().map(|&(quantile, value)| Self::Quantile(quantile, value)))
+			.chain(states.iter().map(|(state, set)| Self::State(state, *set)))
+	}
+
+	/// The number, which the formats cannot represent unless finite.
+	pub(crate) fn value(&self) -> f64 {
+		match self {
+			Self::Value(value) | Self::Sum(value) | Self::Quantile(_, value) => *value,
+			Self::Count(count) | Self::Bucket(_, count) => *count as f64,
+			Self::State(_, set) => f64::from(u8::from(*set)),
+		}
+	}
+
+	/// Writes the path below the one of the sample, e.g. `.bucket.0_5`,
+	/// nothing for a [`Value`](Self::Value).
+	pub(crate) fn write_suffix(&self, o: &mut impl Write) -> fmt::Result {
+		match self {
+			Self::Value(_) => Ok(()),
+			Self::Count(_) => o.write_str(".count"),
+			Self::Sum(_) => o.write_str(".sum"),
+			Self::Bucket(bound, _) => {
+				o.write_str(".bucket.")?;
+				write_bound(o, *bound)
+			},
+			Self::Quantile(quantile, _) => {
+				o.write_str(".quantile.")?;
+				write_bound(o, *quantile)
+			},
+			Self::State(state, _) => {
+				o.write_char('.')?;
+				write_segment(o, state)
+			},
+		}
+	}
+}
+
+/// Writes one level of a path, replacing by `_` the characters that separate
+/// levels, tags or fields in Graphite and StatsD, e.g. the dot of `eth0.100`.
+pub(crate) fn write_segment(o: &mut impl Write, text: &str) -> fmt::Result {
+	for c in text.chars() {
+		match c {
+			'.' | ':' | '|' | '@' | ';' | ',' | '#' => o.write_char('_')?,
+			c if c.is_whitespace() => o.write_char('_')?,
+			c => o.write_char(c)?,
+		}
+	}
+	Ok(())
+}
+
+/// Writes a bucket bound or quantile as a path level, e.g. `0_5` or `inf`.
+fn write_bound(o: &mut impl Write, bound: f64) -> fmt::Result {
+	if bound == f64::INFINITY {
+		return o.write_str("inf");
+	}
+	write!(Segment(o), "{bound}")
+}
+
+/// Writer of a path level, see [`write_segment`].
+struct Segment<'a, W>(&'a mut W);
+
+impl<W: Write> Write for Segment<'_, W> {
+	fn write_str(&mut self, s: &str) -> fmt::Result {
+		write_segment(self.0, s)
+	}
+}
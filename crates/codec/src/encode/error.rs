@@ -1,4 +1,13 @@
 use thiserror::Error;
 
 #[derive(Debug, Error)]
-pub enum CodecEncodeError {}
+pub enum CodecEncodeError {
+	#[error("found record with {len} fields, but the previous record has {expected} fields")]
+	UnequalLengths { expected: usize, len: usize },
+	#[error("failed to serialize field: {0}")]
+	Serialize(#[from] serde_json::Error),
+	#[error("failed to format field")]
+	Format(#[from] std::fmt::Error),
+	#[error("failed to encode protobuf message: {0}")]
+	Protobuf(#[from] prost::EncodeError),
+}
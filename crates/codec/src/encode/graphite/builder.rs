@@ -0,0 +1,47 @@
+use super::{GraphiteEncoder, Template};
+use std::collections::BTreeMap;
+
+/// Builds a [`GraphiteEncoder`].
+#[derive(Debug, Default)]
+pub struct GraphiteEncoderBuilder {
+	template: Template,
+	namespaces: BTreeMap<String, Template>,
+	prefix: String,
+}
+
+impl GraphiteEncoderBuilder {
+	pub fn new() -> Self {
+		GraphiteEncoderBuilder::default()
+	}
+
+	/// Path of the samples whose namespace has no template of its own.
+	///
+	/// The default is `hostname.namespace.tags.name`.
+	pub fn template(&mut self, template: Template) -> &mut Self {
+		self.template = template;
+		self
+	}
+
+	/// Path of the samples of `namespace`, e.g. `hostname.namespace.name.tags`
+	/// for `net` to keep the counters of an interface next to each other.
+	pub fn namespace_template(&mut self, namespace: &str, template: Template) -> &mut Self {
+		self.namespaces.insert(namespace.to_string(), template);
+		self
+	}
+
+	/// Path under which every sample is written, e.g. `servers.linux`, since
+	/// the levels of a template other than `namespace`, `name` and `tags`
+	/// name tags. There is none by default.
+	pub fn prefix(&mut self, prefix: &str) -> &mut Self {
+		self.prefix = prefix.to_string();
+		self
+	}
+
+	pub fn build(&self) -> GraphiteEncoder {
+		GraphiteEncoder {
+			template: self.template.clone(),
+			namespaces: self.namespaces.clone(),
+			prefix: self.prefix.clone(),
+		}
+	}
+}
@@ -0,0 +1,169 @@
+use super::{CodecEncodeError, Encoder, dotted::Number, sample::MetricSamples};
+use bytes::BytesMut;
+use prism_event::metric::{Metric, MetricBatch, static_tags};
+use std::{collections::BTreeMap, fmt::Write};
+mod builder;
+mod template;
+pub use builder::GraphiteEncoderBuilder;
+pub use template::{Template, TemplateError};
+
+/// Writes the Graphite plaintext protocol, `path value timestamp` lines as
+/// accepted by carbon on port 2003.
+///
+/// The path of a sample is built by the [`Template`] of its namespace, or
+/// else by the default one, e.g. `web-1.disk.sda.read_completed`, below the
+/// prefix if one is set. The characters that Graphite reads as separators,
+/// e.g. dots and spaces, are replaced by `_` in every level, and timestamps
+/// are in seconds.
+///
+/// Counters and gauges are written as is and info metrics as `1`.
+/// Histograms and summaries are written under `.count`, `.sum` and one path
+/// per cumulative bucket, e.g. `latency.bucket.0_5`, or per quantile, e.g.
+/// `latency.quantile.0_99`. State sets have one path per state set to `1` or
+/// `0`, e.g. `operstate.up`. String metrics, and `NaN` and infinite values,
+/// that Graphite cannot store, are left out.
+pub struct GraphiteEncoder {
+	template: Template,
+	namespaces: BTreeMap<String, Template>,
+	prefix: String,
+}
+
+impl Encoder<MetricBatch> for GraphiteEncoder {
+	type Error = CodecEncodeError;
+
+	fn encode(&mut self, item: &MetricBatch, out: &mut BytesMut) -> Result<(), Self::Error> {
+		self.write_lines(item, out)
+	}
+}
+
+impl Encoder<Vec<Metric>> for GraphiteEncoder {
+	type Error = CodecEncodeError;
+
+	fn encode(&mut self, item: &Vec<Metric>, out: &mut BytesMut) -> Result<(), Self::Error> {
+		self.write_lines(item, out)
+	}
+}
+
+impl GraphiteEncoder {
+	fn write_lines(
+		&self,
+		item: &impl MetricSamples,
+		out: &mut BytesMut,
+	) -> Result<(), CodecEncodeError> {
+		let static_tags = static_tags();
+		let mut path = String::new();
+		for sample in item.samples() {
+			let template = sample
+				.namespace
+				.and_then(|namespace| self.namespaces.get(namespace))
+				.unwrap_or(&self.template);
+			let tags = static_tags.resolve_tags(sample.namespace, sample.tags);
+			path.clear();
+			if !self.prefix.is_empty() {
+				path.push_str(&self.prefix);
+				path.push('.');
+			}
+			template.write(&mut path, &sample, &tags, &sample.series_tags(&static_tags))?;
+			let seconds = sample.timestamp.as_nanos().div_euclid(1_000_000_000);
+			for number in Number::of(sample.value) {
+				let value = number.value();
+				if !value.is_finite() {
+					continue;
+				}
+				out.extend_from_slice(path.as_bytes());
+				number.write_suffix(out)?;
+				writeln!(out, " {value} {seconds}")?;
+			}
+		}
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use prism_event::{
+		counter, gauge, histogram,
+		metric::{Buckets, MetricValue, StaticTags},
+		shared_tags, state_set, string_metric,
+	};
+
+	fn encode(builder: &GraphiteEncoderBuilder, metrics: &Vec<Metric>) -> Vec<String> {
+		let mut bytes = BytesMut::new();
+		builder.build().encode(metrics, &mut bytes).unwrap();
+		let text = std::str::from_utf8(&bytes).unwrap();
+		text.lines().map(|line| line.rsplit_once(' ').unwrap().0.to_string()).collect()
+	}
+
+	#[test]
+	fn templates() {
+		let tags = shared_tags!("device" => "sda", "partition" => "sda.1");
+		let metrics = vec![
+			counter!("read_completed", 42u64, "disk", tags = &tags),
+			gauge!("rx bytes", 7u64, "net", "interface" => "eth0"),
+			Metric::new("procs_running", MetricValue::Gauge(5.0)),
+		];
+		let mut builder = GraphiteEncoderBuilder::new();
+		builder
+			.prefix("servers")
+			.template("namespace.tags.name".parse().unwrap())
+			.namespace_template("net", "namespace.name.interface".parse().unwrap());
+		assert_eq!(
+			encode(&builder, &metrics),
+			[
+				"servers.disk.sda.sda_1.read_completed 42",
+				"servers.net.rx_bytes.eth0 7",
+				"servers.procs_running 5"
+			]
+		);
+	}
+
+	#[test]
+	fn values() {
+		let metrics = vec![
+			histogram!("latency", Buckets::fixed([0.5]), [0.1, 0.7], "disk"),
+			state_set!("operstate", ["up", "down"], "up", namespace = "net"),
+			string_metric!("firmware", "2B6Q", namespace = "disk"),
+			gauge!("temperature", f64::NAN, "hwmon"),
+		];
+		let mut builder = GraphiteEncoderBuilder::new();
+		builder.template("namespace.name".parse().unwrap());
+		assert_eq!(
+			encode(&builder, &metrics),
+			[
+				"disk.latency.count 2",
+				"disk.latency.sum 0.7999999999999999",
+				"disk.latency.bucket.0_5 1",
+				"disk.latency.bucket.inf 2",
+				"net.operstate.up 1",
+				"net.operstate.down 0",
+			]
+		);
+	}
+
+	#[test]
+	fn default_template_and_timestamp() {
+		let metrics = vec![counter!("read_completed", 42u64, "disk", "device" => "sda")];
+		let mut static_tags = StaticTags::default();
+		static_tags.add_global(&prism_event::metric_tags!("hostname" => "web-1").unwrap());
+		let template = Template::default();
+		let sample = metrics[0].samples().next().unwrap();
+		let mut path = String::new();
+		let tags = static_tags.resolve_tags(sample.namespace, sample.tags);
+		template
+			.write(&mut path, &sample, &tags, &sample.series_tags(&static_tags))
+			.unwrap();
+		assert_eq!(path, "web-1.disk.sda.read_completed");
+
+		let mut bytes = BytesMut::new();
+		GraphiteEncoderBuilder::new().build().encode(&metrics, &mut bytes).unwrap();
+		let seconds = metrics[0].timestamp().as_nanos() / 1_000_000_000;
+		assert!(std::str::from_utf8(&bytes).unwrap().ends_with(&format!(" 42 {seconds}\n")));
+	}
+
+	#[test]
+	fn invalid_templates() {
+		assert!("namespace..name".parse::<Template>().is_err());
+		assert!("hostname.namespace.tags".parse::<Template>().is_err());
+	}
+}
@@ -0,0 +1,102 @@
+use crate::encode::{dotted::write_segment, sample::Sample};
+use std::{
+	collections::BTreeMap,
+	fmt::{self, Write},
+	str::FromStr,
+};
+
+/// Invalid Graphite template.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TemplateError(String);
+
+impl fmt::Display for TemplateError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "invalid graphite template: {}", self.0)
+	}
+}
+
+impl std::error::Error for TemplateError {}
+
+/// Levels of the path of a sample, e.g. `hostname.namespace.tags.name`, as
+/// in the Graphite output of Telegraf.
+///
+/// `namespace` and `name` are those of the sample and `tags` the values of
+/// its own and namespace tags, by key, that the template does not name. Any
+/// other level is the value of the tag it names, among which the global ones,
+/// e.g. `hostname`. The levels a sample has no value for are left out, so
+/// that a template can start with `hostname` for the samples that have one.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Template(Vec<Level>);
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Level {
+	Namespace,
+	Name,
+	Tags,
+	Tag(String),
+}
+
+impl Default for Template {
+	/// `hostname.namespace.tags.name`, e.g. `web-1.disk.sda.read_completed`.
+	fn default() -> Self {
+		Self(vec![Level::Tag("hostname".into()), Level::Namespace, Level::Tags, Level::Name])
+	}
+}
+
+impl FromStr for Template {
+	type Err = TemplateError;
+
+	/// Dot separated levels, one of which must be `name`.
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		let levels = s
+			.split('.')
+			.map(|level| match level {
+				"" => Err(TemplateError(format!("empty level in `{s}`"))),
+				"namespace" => Ok(Level::Namespace),
+				"name" => Ok(Level::Name),
+				"tags" => Ok(Level::Tags),
+				tag => Ok(Level::Tag(tag.to_string())),
+			})
+			.collect::<Result<Vec<_>, _>>()?;
+		if !levels.contains(&Level::Name) {
+			return Err(TemplateError(format!("no `name` level in `{s}`")));
+		}
+		Ok(Self(levels))
+	}
+}
+
+impl Template {
+	/// Writes the path of `sample`, `tags` being its resolved tags and
+	/// `series_tags` those that are not global.
+	pub(super) fn write(
+		&self,
+		o: &mut impl Write,
+		sample: &Sample<'_>,
+		tags: &BTreeMap<&str, &str>,
+		series_tags: &BTreeMap<&str, &str>,
+	) -> fmt::Result {
+		let mut levels = Vec::new();
+		for level in &self.0 {
+			match level {
+				Level::Namespace => levels.extend(sample.namespace),
+				Level::Name => levels.push(sample.name),
+				Level::Tags => levels.extend(
+					series_tags.iter().filter(|(key, _)| !self.names(key)).map(|(_, value)| *value),
+				),
+				Level::Tag(key) => levels.extend(tags.get(key.as_str())),
+			}
+		}
+		for (i, level) in levels.into_iter().filter(|level| !level.is_empty()).enumerate() {
+			if i > 0 {
+				o.write_char('.')?;
+			}
+			write_segment(o, level)?;
+		}
+		Ok(())
+	}
+
+	/// Whether the template has a level for the tag `key`.
+	fn names(&self, key: &str) -> bool {
+		self.0.iter().any(|level| matches!(level, Level::Tag(tag) if tag == key))
+	}
+}
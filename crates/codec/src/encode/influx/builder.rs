@@ -0,0 +1,27 @@
+use super::InfluxEncoder;
+
+/// Builds an [`InfluxEncoder`].
+#[derive(Debug, Default)]
+pub struct InfluxEncoderBuilder {
+	group_fields: bool,
+}
+
+impl InfluxEncoderBuilder {
+	pub fn new() -> Self {
+		InfluxEncoderBuilder::default()
+	}
+
+	/// Whether the samples of one namespace sharing their tags and timestamp
+	/// are written as the fields of a single line, e.g. one `disk` line per
+	/// device with every diskstats field, rather than one line per sample.
+	///
+	/// This is disabled by default.
+	pub const fn group_fields(&mut self, yes: bool) -> &mut Self {
+		self.group_fields = yes;
+		self
+	}
+
+	pub const fn build(&self) -> InfluxEncoder {
+		InfluxEncoder { group_fields: self.group_fields }
+	}
+}
@@ -0,0 +1,347 @@
+use super::{
+	CodecEncodeError, Encoder,
+	sample::{MetricSamples, Sample},
+};
+use bytes::{BufMut, BytesMut};
+use prism_event::{
+	metric::{Metric, MetricBatch, MetricValue, static_tags},
+	time::Timestamp,
+};
+use std::{collections::BTreeMap, fmt::Write};
+mod builder;
+pub use builder::InfluxEncoderBuilder;
+
+/// Writes the InfluxDB line protocol, as Telegraf does.
+///
+/// The measurement is the namespace of a sample and its name the field key,
+/// `disk,device=sda read_completed=42 1738310400000000000`, or else the name
+/// with a `value` field. Tags are the resolved tags of the series, without
+/// the empty ones that the protocol does not allow, and timestamps are in
+/// nanoseconds.
+///
+/// Counters and gauges are float fields and info metrics the integer `1i`.
+/// Histograms and summaries are written as `_count`, `_sum` and one field per
+/// bucket, e.g. `latency_bucket_0.5`, or per quantile, e.g. `latency_0.99`.
+/// State sets have one boolean field per state, e.g. `operstate_up=true`, and
+/// string metrics a string field. `NaN` and infinite values, that the
+/// protocol cannot represent, are left out.
+pub struct InfluxEncoder {
+	group_fields: bool,
+}
+
+impl Encoder<MetricBatch> for InfluxEncoder {
+	type Error = CodecEncodeError;
+
+	fn encode(&mut self, item: &MetricBatch, out: &mut BytesMut) -> Result<(), Self::Error> {
+		self.write_lines(item, out)
+	}
+}
+
+impl Encoder<Vec<Metric>> for InfluxEncoder {
+	type Error = CodecEncodeError;
+
+	fn encode(&mut self, item: &Vec<Metric>, out: &mut BytesMut) -> Result<(), Self::Error> {
+		self.write_lines(item, out)
+	}
+}
+
+type LineKey<'a> = (&'a str, BTreeMap<&'a str, &'a str>, Timestamp);
+
+impl InfluxEncoder {
+	fn write_lines(
+		&self,
+		item: &impl MetricSamples,
+		out: &mut BytesMut,
+	) -> Result<(), CodecEncodeError> {
+		let static_tags = static_tags();
+		if !self.group_fields {
+			for sample in item.samples() {
+				let (measurement, field) = measurement(&sample);
+				let tags = static_tags.resolve_tags(sample.namespace, sample.tags);
+				write_line(out, (measurement, &tags, sample.timestamp), &[(field, sample.value)])?;
+			}
+			return Ok(());
+		}
+
+		let mut lines: BTreeMap<LineKey<'_>, Vec<(&str, &MetricValue)>> = BTreeMap::new();
+		for sample in item.samples() {
+			let (measurement, field) = measurement(&sample);
+			let tags = static_tags.resolve_tags(sample.namespace, sample.tags);
+			lines
+				.entry((measurement, tags, sample.timestamp))
+				.or_default()
+				.push((field, sample.value));
+		}
+		for ((measurement, tags, timestamp), fields) in &lines {
+			write_line(out, (measurement, tags, *timestamp), fields)?;
+		}
+		Ok(())
+	}
+}
+
+/// Measurement and field key of a sample.
+const fn measurement<'a>(sample: &Sample<'a>) -> (&'a str, &'a str) {
+	match sample.namespace {
+		Some(namespace) => (namespace, sample.name),
+		None => (sample.name, "value"),
+	}
+}
+
+/// Writes one line, or nothing when none of the `fields` has a value the
+/// protocol can represent.
+fn write_line(
+	out: &mut BytesMut,
+	(measurement, tags, timestamp): (&str, &BTreeMap<&str, &str>, Timestamp),
+	fields: &[(&str, &MetricValue)],
+) -> Result<(), CodecEncodeError> {
+	let start = out.len();
+	write_escaped(out, measurement, Escape::Measurement)?;
+	for (key, value) in tags.iter().filter(|(key, value)| !key.is_empty() && !value.is_empty()) {
+		out.put_u8(b',');
+		write_escaped(out, key, Escape::Key)?;
+		out.put_u8(b'=');
+		write_escaped(out, value, Escape::Key)?;
+	}
+	out.put_u8(b' ');
+
+	let mut line = FieldWriter { out, fields: 0 };
+	for (name, value) in fields {
+		match value {
+			MetricValue::Counter(value) | MetricValue::Gauge(value) => {
+				line.field(name, "", Field::Float(*value))?;
+			},
+			MetricValue::Histogram(histogram) => {
+				line.field(name, "_count", Field::Integer(histogram.count()))?;
+				line.field(name, "_sum", Field::Float(histogram.sum()))?;
+				for (bound, count) in histogram.buckets().cumulative() {
+					line.bucket(name, "_bucket_", bound, Field::Integer(count))?;
+				}
+			},
+			MetricValue::Summary(summary) => {
+				line.field(name, "_count", Field::Integer(summary.count()))?;
+				line.field(name, "_sum", Field::Float(summary.sum()))?;
+				for (quantile, value) in summary.quantiles() {
+					line.bucket(name, "_", *quantile, Field::Float(*value))?;
+				}
+			},
+			MetricValue::Info => line.field(name, "", Field::Integer(1))?,
+			MetricValue::StateSet(states) => {
+				for (state, set) in states.states() {
+					line.state(name, state, *set)?;
+				}
+			},
+			MetricValue::String(value) => line.field(name, "", Field::String(value))?,
+		}
+	}
+	if line.fields == 0 {
+		out.truncate(start);
+		return Ok(());
+	}
+	writeln!(out, " {}", timestamp.as_nanos())?;
+	Ok(())
+}
+
+enum Field<'a> {
+	Float(f64),
+	Integer(u64),
+	Boolean(bool),
+	String(&'a str),
+}
+
+impl Field<'_> {
+	/// Whether the protocol can represent the value.
+	const fn is_valid(&self) -> bool {
+		match self {
+			Self::Float(value) => value.is_finite(),
+			_ => true,
+		}
+	}
+}
+
+/// Writes the comma separated fields of a line.
+struct FieldWriter<'a> {
+	out: &'a mut BytesMut,
+	fields: usize,
+}
+
+impl FieldWriter<'_> {
+	/// Writes the field `name` followed by `suffix`.
+	fn field(
+		&mut self,
+		name: &str,
+		suffix: &str,
+		value: Field<'_>,
+	) -> Result<(), CodecEncodeError> {
+		if !self.key(value.is_valid()) {
+			return Ok(());
+		}
+		write_escaped(self.out, name, Escape::Key)?;
+		write_escaped(self.out, suffix, Escape::Key)?;
+		self.value(value)
+	}
+
+	/// Writes the field of a bucket or quantile, e.g. `latency_bucket_0.5`.
+	fn bucket(
+		&mut self,
+		name: &str,
+		infix: &str,
+		bound: f64,
+		value: Field<'_>,
+	) -> Result<(), CodecEncodeError> {
+		if !self.key(value.is_valid()) {
+			return Ok(());
+		}
+		write_escaped(self.out, name, Escape::Key)?;
+		write_escaped(self.out, infix, Escape::Key)?;
+		if bound == f64::INFINITY {
+			self.out.put_slice(b"+Inf");
+		} else {
+			write!(self.out, "{bound:?}")?;
+		}
+		self.value(value)
+	}
+
+	/// Writes the field of a state, e.g. `operstate_up`.
+	fn state(&mut self, name: &str, state: &str, set: bool) -> Result<(), CodecEncodeError> {
+		self.key(true);
+		write_escaped(self.out, name, Escape::Key)?;
+		self.out.put_u8(b'_');
+		write_escaped(self.out, state, Escape::Key)?;
+		self.value(Field::Boolean(set))
+	}
+
+	/// Starts a field if `valid`, returning whether it was started.
+	fn key(&mut self, valid: bool) -> bool {
+		if valid && self.fields > 0 {
+			self.out.put_u8(b',');
+		}
+		valid
+	}
+
+	fn value(&mut self, value: Field<'_>) -> Result<(), CodecEncodeError> {
+		self.out.put_u8(b'=');
+		match value {
+			Field::Float(value) => write!(self.out, "{value}")?,
+			Field::Integer(value) => write!(self.out, "{value}i")?,
+			Field::Boolean(value) => write!(self.out, "{value}")?,
+			Field::String(value) => {
+				self.out.put_u8(b'"');
+				write_escaped(self.out, value, Escape::String)?;
+				self.out.put_u8(b'"');
+			},
+		}
+		self.fields += 1;
+		Ok(())
+	}
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Escape {
+	/// Commas and spaces
+	Measurement,
+	/// Commas, equals signs and spaces, of tag keys, tag values and field keys
+	Key,
+	/// Double quotes and backslashes, of string field values
+	String,
+}
+
+fn write_escaped(o: &mut impl Write, text: &str, escape: Escape) -> std::fmt::Result {
+	for c in text.chars() {
+		let escaped = match c {
+			',' | ' ' => escape != Escape::String,
+			'=' => escape == Escape::Key,
+			'"' | '\\' => escape == Escape::String,
+			'\n' => {
+				o.write_str("\\n")?;
+				continue;
+			},
+			_ => false,
+		};
+		if escaped {
+			o.write_char('\\')?;
+		}
+		o.write_char(c)?;
+	}
+	Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use prism_event::{
+		counter, gauge, histogram,
+		metric::{Buckets, Metric},
+		shared_tags, state_set, string_metric,
+	};
+
+	fn encode(group_fields: bool, batch: &MetricBatch) -> String {
+		let mut encoder = InfluxEncoderBuilder::new().group_fields(group_fields).build();
+		let mut bytes = BytesMut::new();
+		encoder.encode(batch, &mut bytes).unwrap();
+		String::from_utf8(bytes.to_vec()).unwrap()
+	}
+
+	fn disk_batch() -> MetricBatch {
+		let sda = shared_tags!("device" => "sda", "parent" => "");
+		let sdb = shared_tags!("device" => "sdb", "parent" => "");
+		[
+			counter!("read_completed", 42u64, "disk", tags = &sda),
+			counter!("read_completed", 7u64, "disk", tags = &sdb),
+			counter!("write_completed", 3u64, "disk", tags = &sda),
+			gauge!("ios_in_progress", f64::NAN, "disk", tags = &sda),
+		]
+		.into_iter()
+		.collect()
+	}
+
+	#[test]
+	fn line_per_sample() {
+		let batch = disk_batch();
+		let ts = batch.timestamp().as_nanos();
+		assert_eq!(
+			encode(false, &batch),
+			format!(
+				"disk,device=sda read_completed=42 {ts}\n\
+				 disk,device=sdb read_completed=7 {ts}\n\
+				 disk,device=sda write_completed=3 {ts}\n"
+			)
+		);
+	}
+
+	#[test]
+	fn grouped_fields() {
+		let batch = disk_batch();
+		let ts = batch.timestamp().as_nanos();
+		assert_eq!(
+			encode(true, &batch),
+			format!(
+				"disk,device=sda read_completed=42,write_completed=3 {ts}\n\
+				 disk,device=sdb read_completed=7 {ts}\n"
+			)
+		);
+	}
+
+	#[test]
+	fn values_and_escaping() {
+		let metrics: Vec<Metric> = vec![
+			histogram!("latency", Buckets::fixed([0.5]), [0.1, 0.7], "block io", "dev name" => "a,b=c"),
+			state_set!("operstate", ["up", "down"], "up", namespace = "net"),
+			string_metric!("firmware", "2B6Q \"rev\"", namespace = "disk"),
+			Metric::new("procs_running", MetricValue::Gauge(5.0)),
+		];
+		let mut encoder = InfluxEncoderBuilder::new().build();
+		let mut bytes = BytesMut::new();
+		encoder.encode(&metrics, &mut bytes).unwrap();
+		let text = std::str::from_utf8(&bytes).unwrap();
+		let lines: Vec<_> = text.lines().map(|line| line.rsplit_once(' ').unwrap().0).collect();
+		assert_eq!(
+			lines,
+			[
+				r"block\ io,dev\ name=a\,b\=c latency_count=2i,latency_sum=0.7999999999999999,latency_bucket_0.5=1i,latency_bucket_+Inf=2i",
+				"net operstate_up=true,operstate_down=false",
+				r#"disk firmware="2B6Q \"rev\"""#,
+				"procs_running value=5",
+			]
+		);
+	}
+}
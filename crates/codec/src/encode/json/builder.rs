@@ -0,0 +1,42 @@
+use super::{Framing, JsonEncoder};
+use prism_event::time::TimeSettings;
+use std::sync::Arc;
+
+/// Builds a [`JsonEncoder`].
+///
+/// By default every record is written on its own line, with timestamps
+/// written as set by [`time::set_settings`](prism_event::time::set_settings).
+#[derive(Debug, Default)]
+pub struct JsonEncoderBuilder {
+	framing: Framing,
+	time_settings: Option<TimeSettings>,
+}
+
+impl JsonEncoderBuilder {
+	pub fn new() -> Self {
+		JsonEncoderBuilder::default()
+	}
+
+	/// Whether a line holds one record or every record of an encoded item.
+	///
+	/// The default is [`Framing::Record`].
+	pub const fn framing(&mut self, framing: Framing) -> &mut Self {
+		self.framing = framing;
+		self
+	}
+
+	/// Format, precision and timezone of the timestamps, rather than those
+	/// of the agent. Epoch timestamps are written as numbers, the others as
+	/// strings.
+	pub fn time_settings(&mut self, settings: TimeSettings) -> &mut Self {
+		self.time_settings = Some(settings);
+		self
+	}
+
+	pub fn build(&self) -> JsonEncoder {
+		JsonEncoder {
+			framing: self.framing,
+			time_settings: self.time_settings.clone().map(Arc::new),
+		}
+	}
+}
@@ -0,0 +1,197 @@
+use super::{CodecEncodeError, Encoder};
+use bytes::{BufMut, BytesMut};
+use prism_event::time::{self, TimeSettings, Timestamp};
+use serde::{Serialize, Serializer};
+use std::{fmt, sync::Arc};
+mod builder;
+mod records;
+pub use builder::JsonEncoderBuilder;
+pub use records::JsonRecords;
+
+/// How records are grouped into lines.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Framing {
+	/// One line per record, e.g. `{"name":"user_usage",...}` per sample
+	#[default]
+	Record,
+	/// One line per encoded item, e.g. `{"metrics":[...]}` per batch
+	Batch,
+}
+
+/// Writes newline delimited JSON, also known as JSON Lines, that log
+/// pipelines such as Vector or Fluent Bit ingest as is.
+///
+/// Records are serialized straight into the output buffer. Metrics carry the
+/// type of their value, next to their tags as a nested object.
+pub struct JsonEncoder {
+	framing: Framing,
+	/// Used instead of the time settings of the agent when set
+	time_settings: Option<Arc<TimeSettings>>,
+}
+
+impl<S> Encoder<S> for JsonEncoder
+where
+	S: JsonRecords,
+{
+	type Error = CodecEncodeError;
+
+	fn encode(&mut self, item: &S, out: &mut BytesMut) -> Result<(), Self::Error> {
+		let settings = self.time_settings.as_ref().map_or_else(time::settings, Arc::clone);
+		if self.framing == Framing::Batch {
+			out.put_slice(b"{\"");
+			out.put_slice(S::BATCH_KEY.as_bytes());
+			out.put_slice(b"\":[");
+		}
+		let mut writer = RecordWriter { out, settings, framing: self.framing, records: 0 };
+		item.write_records(&mut writer)?;
+		if self.framing == Framing::Batch {
+			out.put_slice(b"]}\n");
+		}
+		Ok(())
+	}
+}
+
+/// Writes the records of one item, see [`JsonRecords`].
+pub struct RecordWriter<'a> {
+	out: &'a mut BytesMut,
+	settings: Arc<TimeSettings>,
+	framing: Framing,
+	/// Records already written
+	records: usize,
+}
+
+impl RecordWriter<'_> {
+	/// Settings to write timestamps with, see [`JsonTimestamp`].
+	pub fn time_settings(&self) -> Arc<TimeSettings> {
+		Arc::clone(&self.settings)
+	}
+
+	/// Writes `record` as a JSON object.
+	pub fn record(&mut self, record: &impl Serialize) -> Result<(), CodecEncodeError> {
+		if self.framing == Framing::Batch && self.records > 0 {
+			self.out.put_u8(b',');
+		}
+		serde_json::to_writer((&mut *self.out).writer(), record)?;
+		if self.framing == Framing::Record {
+			self.out.put_u8(b'\n');
+		}
+		self.records += 1;
+		Ok(())
+	}
+}
+
+/// Timestamp serialized as a number in the epoch format and as a string otherwise.
+pub struct JsonTimestamp<'a> {
+	settings: &'a TimeSettings,
+	timestamp: Timestamp,
+}
+
+impl<'a> JsonTimestamp<'a> {
+	pub const fn new(settings: &'a TimeSettings, timestamp: Timestamp) -> Self {
+		Self { settings, timestamp }
+	}
+}
+
+impl Serialize for JsonTimestamp<'_> {
+	fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		match self.settings.epoch(self.timestamp) {
+			Some(epoch) => serializer.serialize_i64(epoch),
+			None => serializer.collect_str(self),
+		}
+	}
+}
+
+impl fmt::Display for JsonTimestamp<'_> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		self.settings.write(self.timestamp, f)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use prism_event::{
+		counter,
+		event::{Event, Severity},
+		gauge, histogram,
+		metric::{Buckets, Metric, MetricBatch, add_namespace_tags},
+		metric_tags,
+		time::{Precision, TimestampFormat, Timezone},
+	};
+	use serde_json::{Value, json};
+
+	fn lines(bytes: &[u8]) -> Vec<Value> {
+		std::str::from_utf8(bytes)
+			.unwrap()
+			.lines()
+			.map(|line| serde_json::from_str(line).unwrap())
+			.collect()
+	}
+
+	#[test]
+	fn record_per_line() {
+		let metrics: Vec<Metric> = vec![
+			counter!("read_completed", 42u64, "disk", "device" => "sda"),
+			histogram!("latency", Buckets::fixed([0.5]), [0.1], "disk" => "sda"),
+		];
+		let mut encoder = JsonEncoderBuilder::new().build();
+		let mut bytes = BytesMut::new();
+		encoder.encode(&metrics, &mut bytes).unwrap();
+
+		let lines = lines(&bytes);
+		assert_eq!(lines.len(), 2);
+		assert_eq!(lines[0]["name"], "read_completed");
+		assert_eq!(lines[0]["namespace"], "disk");
+		assert_eq!(lines[0]["type"], "counter");
+		assert_eq!(lines[0]["value"], 42.0);
+		assert_eq!(lines[0]["tags"], json!({"device": "sda"}));
+		assert!(lines[0]["timestamp"].is_string());
+		assert_eq!(lines[1]["type"], "histogram");
+		assert_eq!(lines[1]["value"]["count"], 1);
+	}
+
+	#[test]
+	fn batch_per_line() {
+		let batch: MetricBatch =
+			[gauge!("temperature", 36.5, "hwmon"), gauge!("temperature", 41.0, "hwmon")]
+				.into_iter()
+				.collect();
+		let settings = TimeSettings::new(TimestampFormat::Epoch, Precision::Millis, Timezone::Utc);
+		let mut encoder = JsonEncoderBuilder::new()
+			.framing(Framing::Batch)
+			.time_settings(settings)
+			.build();
+		let mut bytes = BytesMut::new();
+		encoder.encode(&batch, &mut bytes).unwrap();
+
+		let lines = lines(&bytes);
+		assert_eq!(lines.len(), 1);
+		let metrics = lines[0]["metrics"].as_array().unwrap();
+		assert_eq!(metrics.len(), 2);
+		assert_eq!(metrics[1]["value"], 41.0);
+		assert!(metrics[1].get("tags").is_none());
+		assert_eq!(
+			metrics[0]["timestamp"].as_i64(),
+			Some(batch.timestamp().as_nanos() / 1_000_000)
+		);
+	}
+
+	#[test]
+	fn events() {
+		// namespace tags only, global ones would leak into the other tests
+		add_namespace_tags("kernel", &metric_tags!("host" => "web-1").unwrap());
+		let events = vec![
+			Event::new("oom_kill", Severity::Error, "Killed process 42 (java)")
+				.with_namespace(Some("kernel")),
+		];
+		let mut encoder = JsonEncoderBuilder::new().build();
+		let mut bytes = BytesMut::new();
+		encoder.encode(&events, &mut bytes).unwrap();
+
+		let lines = lines(&bytes);
+		assert_eq!(lines[0]["severity"], "error");
+		assert_eq!(lines[0]["message"], "Killed process 42 (java)");
+		assert!(lines[0].get("fields").is_none());
+		assert_eq!(lines[0]["tags"]["host"], "web-1");
+	}
+}
@@ -0,0 +1,130 @@
+use super::{JsonTimestamp, RecordWriter};
+use crate::encode::CodecEncodeError;
+use prism_event::{
+	event::{Event, EventFields},
+	metric::{Metric, MetricBatch, MetricTags, MetricValue, StaticTags, static_tags},
+	time::{TimeSettings, Timestamp},
+};
+use serde::Serialize;
+use std::collections::BTreeMap;
+
+/// Items written as JSON objects, e.g. one object per sample of a [`MetricBatch`].
+pub trait JsonRecords {
+	/// Key of the array of records when a line holds a whole item, see
+	/// [`Framing::Batch`](super::Framing::Batch).
+	const BATCH_KEY: &'static str;
+
+	/// Writes the records of this item with [`RecordWriter::record`].
+	fn write_records(&self, writer: &mut RecordWriter<'_>) -> Result<(), CodecEncodeError>;
+}
+
+impl<T: JsonRecords> JsonRecords for Vec<T> {
+	const BATCH_KEY: &'static str = T::BATCH_KEY;
+
+	fn write_records(&self, writer: &mut RecordWriter<'_>) -> Result<(), CodecEncodeError> {
+		self.iter().try_for_each(|item| item.write_records(writer))
+	}
+}
+
+impl JsonRecords for Metric {
+	const BATCH_KEY: &'static str = "metrics";
+
+	fn write_records(&self, writer: &mut RecordWriter<'_>) -> Result<(), CodecEncodeError> {
+		let static_tags = static_tags();
+		let settings = writer.time_settings();
+		let record = MetricRecord::new(
+			&settings,
+			&static_tags,
+			(self.name(), self.namespace(), self.tags()),
+			self.value(),
+			self.timestamp(),
+		);
+		writer.record(&record)
+	}
+}
+
+impl JsonRecords for MetricBatch {
+	const BATCH_KEY: &'static str = "metrics";
+
+	fn write_records(&self, writer: &mut RecordWriter<'_>) -> Result<(), CodecEncodeError> {
+		let static_tags = static_tags();
+		let settings = writer.time_settings();
+		for (series, value) in self.iter() {
+			let record = MetricRecord::new(
+				&settings,
+				&static_tags,
+				(series.name(), series.namespace(), series.tags()),
+				value,
+				self.timestamp(),
+			);
+			writer.record(&record)?;
+		}
+		Ok(())
+	}
+}
+
+impl JsonRecords for Event {
+	const BATCH_KEY: &'static str = "events";
+
+	fn write_records(&self, writer: &mut RecordWriter<'_>) -> Result<(), CodecEncodeError> {
+		let static_tags = static_tags();
+		let settings = writer.time_settings();
+		let record = EventRecord {
+			name: self.name(),
+			namespace: self.namespace(),
+			severity: self.severity().as_str(),
+			message: self.message(),
+			fields: self.fields().filter(|fields| !fields.is_empty()),
+			tags: static_tags.resolve_tags(self.namespace(), None),
+			timestamp: JsonTimestamp::new(&settings, self.timestamp()),
+		};
+		writer.record(&record)
+	}
+}
+
+#[derive(Serialize)]
+struct MetricRecord<'a> {
+	name: &'a str,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	namespace: Option<&'a str>,
+	#[serde(rename = "type")]
+	kind: &'static str,
+	value: &'a MetricValue,
+	#[serde(skip_serializing_if = "BTreeMap::is_empty")]
+	tags: BTreeMap<&'a str, &'a str>,
+	timestamp: JsonTimestamp<'a>,
+}
+
+impl<'a> MetricRecord<'a> {
+	fn new(
+		settings: &'a TimeSettings,
+		static_tags: &'a StaticTags,
+		(name, namespace, tags): (&'a str, Option<&'a str>, Option<&'a MetricTags>),
+		value: &'a MetricValue,
+		timestamp: Timestamp,
+	) -> Self {
+		Self {
+			name,
+			namespace,
+			kind: value.kind().as_str(),
+			value,
+			tags: static_tags.resolve_tags(namespace, tags),
+			timestamp: JsonTimestamp::new(settings, timestamp),
+		}
+	}
+}
+
+#[derive(Serialize)]
+struct EventRecord<'a> {
+	name: &'a str,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	namespace: Option<&'a str>,
+	severity: &'static str,
+	message: &'a str,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	fields: Option<&'a EventFields>,
+	/// Global and namespace tags, e.g. the host identity
+	#[serde(skip_serializing_if = "BTreeMap::is_empty")]
+	tags: BTreeMap<&'a str, &'a str>,
+	timestamp: JsonTimestamp<'a>,
+}
@@ -2,9 +2,25 @@ use bytes::BytesMut;
 pub use error::CodecEncodeError;
 
 pub mod csv;
+mod dotted;
 mod error;
+pub mod graphite;
+pub mod influx;
+pub mod json;
+pub mod otlp;
+pub mod prometheus;
+mod sample;
+pub mod statsd;
 
 pub trait Encoder<S>: Send + 'static {
 	type Error;
-	fn encode(&mut self, item: S, buffer: &mut BytesMut) -> Result<(), Self::Error>;
+	/// Appends `item` to `buffer`. The item is borrowed so that its allocation
+	/// can be handed back to the producer once encoded.
+	fn encode(&mut self, item: &S, buffer: &mut BytesMut) -> Result<(), Self::Error>;
+
+	/// Appends what the format expects at the start of every file or stream,
+	/// e.g. the header row of a CSV file. Nothing by default.
+	fn header(&mut self, _buffer: &mut BytesMut) -> Result<(), Self::Error> {
+		Ok(())
+	}
 }
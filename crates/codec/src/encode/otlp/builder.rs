@@ -0,0 +1,35 @@
+use super::OtlpEncoder;
+use prism_event::time::Timestamp;
+
+/// Builds an [`OtlpEncoder`].
+#[derive(Debug, Default)]
+pub struct OtlpEncoderBuilder {
+	start_time: Option<Timestamp>,
+	length_delimited: bool,
+}
+
+impl OtlpEncoderBuilder {
+	pub fn new() -> Self {
+		OtlpEncoderBuilder::default()
+	}
+
+	/// Start of the cumulative sums and histograms, e.g. the time the agent
+	/// started. It is left unset, i.e. zero, by default.
+	pub const fn start_time(&mut self, start_time: Timestamp) -> &mut Self {
+		self.start_time = Some(start_time);
+		self
+	}
+
+	/// Whether every request is prefixed with its length as a varint, so that
+	/// a file holding several of them can be read back.
+	///
+	/// This is disabled by default, each request then being a whole body.
+	pub const fn length_delimited(&mut self, yes: bool) -> &mut Self {
+		self.length_delimited = yes;
+		self
+	}
+
+	pub const fn build(&self) -> OtlpEncoder {
+		OtlpEncoder { start_time: self.start_time, length_delimited: self.length_delimited }
+	}
+}
@@ -0,0 +1,514 @@
+use super::{CodecEncodeError, Encoder, sample::MetricSamples};
+use bytes::BytesMut;
+use prism_event::{
+	metric::{BucketCounts, Metric, MetricBatch, MetricTags, MetricValue, StaticTags, static_tags},
+	time::Timestamp,
+};
+use prost::Message;
+use std::collections::BTreeMap;
+mod builder;
+pub mod proto;
+pub use builder::OtlpEncoderBuilder;
+use proto::{
+	AggregationTemporality, AnyValue, ExportMetricsServiceRequest, Gauge, Histogram,
+	HistogramDataPoint, InstrumentationScope, KeyValue, NumberDataPoint, Resource, ResourceMetrics,
+	ScopeMetrics, Sum, Summary, SummaryDataPoint, any_value,
+	exponential_histogram_data_point::Buckets as ExponentialBuckets, metric::Data,
+	number_data_point, summary_data_point::ValueAtQuantile,
+};
+
+/// Scope of the samples without a namespace.
+const DEFAULT_SCOPE: &str = "prism";
+
+/// OpenTelemetry names of the identity tags, the others keep their key.
+const RESOURCE_KEYS: &[(&str, &str)] = &[
+	("hostname", "host.name"),
+	("machine_id", "host.id"),
+	("os_id", "os.name"),
+	("os_version_id", "os.version"),
+];
+
+/// Writes an OTLP `ExportMetricsServiceRequest`, the body that an OTLP/HTTP
+/// exporter posts to `/v1/metrics` as `application/x-protobuf`.
+///
+/// The global static tags, among which the host identity, are the attributes
+/// of the single `Resource`, with the OpenTelemetry names `host.name`,
+/// `host.id`, `os.name` and `os.version` and a `service.name` of `prism`
+/// unless one is set. Every namespace, i.e. every collector, has its own
+/// `InstrumentationScope` holding one metric per name, whose data points
+/// carry the own and namespace tags of the series.
+///
+/// Counters are cumulative monotonic sums and gauges double gauges.
+/// Histograms are cumulative explicit bucket or exponential histograms, the
+/// latter downscaled to at most 160 buckets of each sign as the OpenTelemetry
+/// SDKs do, and summaries summaries. Info metrics are integer gauges of 1, state sets have
+/// one gauge point of 0 or 1 per state with the state as the attribute named
+/// after the metric, as Prometheus does, and string metrics a point of 1 with
+/// the string as its `value` attribute. A sample whose type differs from the
+/// first one of the same name and scope is left out.
+pub struct OtlpEncoder {
+	start_time: Option<Timestamp>,
+	length_delimited: bool,
+}
+
+impl Encoder<MetricBatch> for OtlpEncoder {
+	type Error = CodecEncodeError;
+
+	fn encode(&mut self, item: &MetricBatch, out: &mut BytesMut) -> Result<(), Self::Error> {
+		self.write_request(item, out)
+	}
+}
+
+impl Encoder<Vec<Metric>> for OtlpEncoder {
+	type Error = CodecEncodeError;
+
+	fn encode(&mut self, item: &Vec<Metric>, out: &mut BytesMut) -> Result<(), Self::Error> {
+		self.write_request(item, out)
+	}
+}
+
+impl OtlpEncoder {
+	fn write_request(
+		&self,
+		item: &impl MetricSamples,
+		out: &mut BytesMut,
+	) -> Result<(), CodecEncodeError> {
+		let Some(request) = self.request(item, &static_tags()) else {
+			return Ok(());
+		};
+		if self.length_delimited {
+			request.encode_length_delimited(out)?;
+		} else {
+			request.encode(out)?;
+		}
+		Ok(())
+	}
+
+	/// Request holding the samples of `item`, none when it has no sample.
+	fn request(
+		&self,
+		item: &impl MetricSamples,
+		static_tags: &StaticTags,
+	) -> Option<ExportMetricsServiceRequest> {
+		let start_time = self.start_time.map_or(0, unix_nanos);
+		let mut scopes: BTreeMap<&str, BTreeMap<&str, proto::Metric>> = BTreeMap::new();
+		for sample in item.samples() {
+			let attributes = sample
+				.series_tags(static_tags)
+				.into_iter()
+				.map(|(key, value)| attribute(key, value))
+				.collect();
+			let time = (start_time, unix_nanos(sample.timestamp));
+			let data = data(sample.name, sample.value, attributes, time);
+			let scope = scopes.entry(sample.namespace.unwrap_or(DEFAULT_SCOPE)).or_default();
+			match scope.get_mut(sample.name) {
+				Some(metric) => merge(metric, data),
+				None => {
+					let descriptor = sample.descriptor;
+					let metric = proto::Metric {
+						name: sample.name.to_owned(),
+						description: descriptor.map_or("", |d| d.description()).to_owned(),
+						unit: descriptor.map_or("", |d| d.unit().ucum()).to_owned(),
+						data: Some(data),
+					};
+					scope.insert(sample.name, metric);
+				},
+			}
+		}
+		if scopes.is_empty() {
+			return None;
+		}
+
+		let scope_metrics = scopes
+			.into_iter()
+			.map(|(name, metrics)| ScopeMetrics {
+				scope: Some(InstrumentationScope {
+					name: name.to_owned(),
+					version: env!("CARGO_PKG_VERSION").to_owned(),
+					..Default::default()
+				}),
+				metrics: metrics.into_values().collect(),
+				schema_url: String::new(),
+			})
+			.collect();
+		let resource =
+			Resource { attributes: resource(static_tags.global()), ..Default::default() };
+		Some(ExportMetricsServiceRequest {
+			resource_metrics: vec![ResourceMetrics {
+				resource: Some(resource),
+				scope_metrics,
+				schema_url: String::new(),
+			}],
+		})
+	}
+}
+
+/// Attributes of the resource from the global tags.
+fn resource(global: &MetricTags) -> Vec<KeyValue> {
+	let mut attributes = BTreeMap::new();
+	for (key, value) in global.iter() {
+		let key = RESOURCE_KEYS.iter().find(|(tag, _)| *tag == key).map_or(key, |(_, key)| key);
+		attributes.entry(key).or_insert(value);
+	}
+	attributes.entry("service.name").or_insert(DEFAULT_SCOPE);
+	attributes.into_iter().map(|(key, value)| attribute(key, value)).collect()
+}
+
+fn attribute(key: &str, value: &str) -> KeyValue {
+	KeyValue {
+		key: key.to_owned(),
+		value: Some(AnyValue { value: Some(any_value::Value::StringValue(value.to_owned())) }),
+	}
+}
+
+/// Data of a metric holding the points of one sample, taken at `time`, the
+/// start and sample timestamps in nanoseconds.
+fn data(
+	name: &str,
+	value: &MetricValue,
+	attributes: Vec<KeyValue>,
+	(start_time_unix_nano, time_unix_nano): (u64, u64),
+) -> Data {
+	let number = |attributes, value| NumberDataPoint {
+		attributes,
+		start_time_unix_nano,
+		time_unix_nano,
+		value: Some(value),
+		flags: 0,
+	};
+	let gauge = |data_points| Data::Gauge(Gauge { data_points });
+	match value {
+		MetricValue::Counter(value) => Data::Sum(Sum {
+			data_points: vec![number(attributes, number_data_point::Value::AsDouble(*value))],
+			aggregation_temporality: AggregationTemporality::Cumulative.into(),
+			is_monotonic: true,
+		}),
+		MetricValue::Gauge(value) => {
+			gauge(vec![number(attributes, number_data_point::Value::AsDouble(*value))])
+		},
+		MetricValue::Histogram(histogram) => match histogram.buckets() {
+			BucketCounts::Fixed { bounds, counts } => Data::Histogram(Histogram {
+				data_points: vec![HistogramDataPoint {
+					attributes,
+					start_time_unix_nano,
+					time_unix_nano,
+					count: histogram.count(),
+					sum: Some(histogram.sum()),
+					bucket_counts: counts.clone(),
+					explicit_bounds: bounds.to_vec(),
+					flags: 0,
+					min: histogram.min(),
+					max: histogram.max(),
+				}],
+				aggregation_temporality: AggregationTemporality::Cumulative.into(),
+			}),
+			BucketCounts::Exponential { scale, zero_count, positive, negative } => {
+				let downscale = downscale(positive, negative);
+				Data::ExponentialHistogram(proto::ExponentialHistogram {
+					data_points: vec![proto::ExponentialHistogramDataPoint {
+						attributes,
+						start_time_unix_nano,
+						time_unix_nano,
+						count: histogram.count(),
+						sum: Some(histogram.sum()),
+						scale: i32::from(*scale) - downscale as i32,
+						zero_count: *zero_count,
+						positive: dense(positive, downscale),
+						negative: dense(negative, downscale),
+						flags: 0,
+						min: histogram.min(),
+						max: histogram.max(),
+						zero_threshold: 0.0,
+					}],
+					aggregation_temporality: AggregationTemporality::Cumulative.into(),
+				})
+			},
+		},
+		MetricValue::Summary(summary) => Data::Summary(Summary {
+			data_points: vec![SummaryDataPoint {
+				attributes,
+				start_time_unix_nano,
+				time_unix_nano,
+				count: summary.count(),
+				sum: summary.sum(),
+				quantile_values: summary
+					.quantiles()
+					.iter()
+					.map(|&(quantile, value)| ValueAtQuantile { quantile, value })
+					.collect(),
+				flags: 0,
+			}],
+		}),
+		MetricValue::Info => gauge(vec![number(attributes, number_data_point::Value::AsInt(1))]),
+		MetricValue::StateSet(states) => gauge(
+			states
+				.states()
+				.iter()
+				.map(|(state, set)| {
+					let mut attributes = attributes.clone();
+					attributes.push(attribute(name, state));
+					number(attributes, number_data_point::Value::AsInt(i64::from(*set)))
+				})
+				.collect(),
+		),
+		MetricValue::String(value) => {
+			let mut attributes = attributes;
+			attributes.push(attribute("value", value));
+			gauge(vec![number(attributes, number_data_point::Value::AsInt(1))])
+		},
+	}
+}
+
+/// Most dense buckets of each sign of an exponential histogram, the default
+/// of the OpenTelemetry SDKs
+const MAX_BUCKETS: i64 = 160;
+
+/// Scale reduction fitting the sparse `positive` and `negative` counts of an
+/// exponential histogram in [`MAX_BUCKETS`] dense buckets each. Reducing the
+/// scale by one merges every two adjacent buckets.
+fn downscale(positive: &BTreeMap<i32, u64>, negative: &BTreeMap<i32, u64>) -> u32 {
+	let span = |counts: &BTreeMap<i32, u64>, downscale: u32| {
+		let (Some((first, _)), Some((last, _))) = (counts.first_key_value(), counts.last_key_value())
+		else {
+			return 0;
+		};
+		i64::from(last >> downscale) - i64::from(first >> downscale) + 1
+	};
+	(0..i32::BITS)
+		.find(|&downscale| {
+			span(positive, downscale) <= MAX_BUCKETS && span(negative, downscale) <= MAX_BUCKETS
+		})
+		.unwrap_or(i32::BITS - 1)
+}
+
+/// Dense buckets from the sparse counts of an exponential histogram, at a
+/// scale reduced by `downscale`, the missing indexes counting zero.
+fn dense(counts: &BTreeMap<i32, u64>, downscale: u32) -> Option<ExponentialBuckets> {
+	let (&first, _) = counts.first_key_value()?;
+	let offset = first >> downscale;
+	let mut bucket_counts: Vec<u64> = Vec::new();
+	for (&index, &count) in counts {
+		let bucket = ((index >> downscale) - offset) as usize;
+		if bucket >= bucket_counts.len() {
+			bucket_counts.resize(bucket + 1, 0);
+		}
+		bucket_counts[bucket] += count;
+	}
+	Some(ExponentialBuckets { offset, bucket_counts })
+}
+
+/// Adds the points of `data` to `metric` when both have the same type.
+fn merge(metric: &mut proto::Metric, data: Data) {
+	match (&mut metric.data, data) {
+		(Some(Data::Gauge(to)), Data::Gauge(from)) => to.data_points.extend(from.data_points),
+		(Some(Data::Sum(to)), Data::Sum(from)) => to.data_points.extend(from.data_points),
+		(Some(Data::Histogram(to)), Data::Histogram(from)) => {
+			to.data_points.extend(from.data_points);
+		},
+		(Some(Data::ExponentialHistogram(to)), Data::ExponentialHistogram(from)) => {
+			to.data_points.extend(from.data_points);
+		},
+		(Some(Data::Summary(to)), Data::Summary(from)) => to.data_points.extend(from.data_points),
+		_ => {},
+	}
+}
+
+/// Nanoseconds since the Unix epoch, zero before it.
+fn unix_nanos(timestamp: Timestamp) -> u64 {
+	u64::try_from(timestamp.as_nanos()).unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use prism_event::{
+		counter, gauge, histogram, info_metric,
+		metric::{
+			Buckets,
+			descriptor::{MetricDescriptor, MetricKind, Unit},
+		},
+		metric_tags, state_set, string_metric,
+	};
+
+	static READ_TIME: MetricDescriptor = MetricDescriptor::new("read_time", MetricKind::Counter)
+		.with_unit(Unit::Milliseconds)
+		.with_description("Time spent reading");
+
+	fn request(metrics: &Vec<Metric>, static_tags: &StaticTags) -> ExportMetricsServiceRequest {
+		let encoder = OtlpEncoderBuilder::new().start_time(Timestamp::from_nanos(1)).build();
+		let request = encoder.request(metrics, static_tags).unwrap();
+		// what is sent must decode to the same request
+		let bytes = request.encode_to_vec();
+		assert_eq!(ExportMetricsServiceRequest::decode(bytes.as_slice()).unwrap(), request);
+		request
+	}
+
+	fn scopes(request: &ExportMetricsServiceRequest) -> &[ScopeMetrics] {
+		&request.resource_metrics[0].scope_metrics
+	}
+
+	fn pairs(attributes: &[KeyValue]) -> Vec<(&str, &str)> {
+		attributes
+			.iter()
+			.map(|kv| match kv.value.as_ref().and_then(|value| value.value.as_ref()) {
+				Some(any_value::Value::StringValue(value)) => (kv.key.as_str(), value.as_str()),
+				other => panic!("unexpected attribute value {other:?}"),
+			})
+			.collect()
+	}
+
+	#[test]
+	fn resource_and_scopes() {
+		let mut static_tags = StaticTags::default();
+		static_tags.add_global(
+			&metric_tags!(
+				"hostname" => "web-1",
+				"machine_id" => "abc",
+				"kernel_release" => "6.1.0",
+			)
+			.unwrap(),
+		);
+		static_tags.add_namespace("disk", &metric_tags!("class" => "block").unwrap());
+		let metrics = vec![
+			counter!("read_time", 10u64, "disk", "device" => "sda").with_descriptor(&READ_TIME),
+			gauge!("MemFree", 1024u64, "meminfo"),
+			counter!("read_time", 20u64, "disk", "device" => "sdb").with_descriptor(&READ_TIME),
+		];
+		let request = request(&metrics, &static_tags);
+
+		let resource = request.resource_metrics[0].resource.as_ref().unwrap();
+		assert_eq!(
+			pairs(&resource.attributes),
+			[
+				("host.id", "abc"),
+				("host.name", "web-1"),
+				("kernel_release", "6.1.0"),
+				("service.name", "prism")
+			]
+		);
+
+		let scopes = scopes(&request);
+		let names: Vec<_> =
+			scopes.iter().map(|s| s.scope.as_ref().unwrap().name.as_str()).collect();
+		assert_eq!(names, ["disk", "meminfo"]);
+
+		let read_time = &scopes[0].metrics[0];
+		assert_eq!(
+			(read_time.unit.as_str(), read_time.description.as_str()),
+			("ms", "Time spent reading")
+		);
+		let Some(Data::Sum(sum)) = &read_time.data else { panic!("not a sum: {read_time:?}") };
+		assert!(sum.is_monotonic);
+		assert_eq!(sum.aggregation_temporality, AggregationTemporality::Cumulative as i32);
+		assert_eq!(sum.data_points.len(), 2);
+		let point = &sum.data_points[1];
+		assert_eq!(pairs(&point.attributes), [("class", "block"), ("device", "sdb")]);
+		assert_eq!(point.value, Some(number_data_point::Value::AsDouble(20.0)));
+		assert_eq!(point.start_time_unix_nano, 1);
+		assert_eq!(point.time_unix_nano, unix_nanos(metrics[2].timestamp()));
+
+		let Some(Data::Gauge(gauge)) = &scopes[1].metrics[0].data else { panic!("not a gauge") };
+		assert_eq!(gauge.data_points[0].value, Some(number_data_point::Value::AsDouble(1024.0)));
+		assert!(gauge.data_points[0].attributes.is_empty());
+	}
+
+	#[test]
+	fn histograms() {
+		let metrics = vec![
+			histogram!("latency", Buckets::fixed([0.5, 1.0]), [0.1, 0.7, 0.8, 3.0], "disk"),
+			histogram!("size", Buckets::exponential(0), [0.0, 1.5, 5.0, 6.0], "disk"),
+		];
+		let request = request(&metrics, &StaticTags::default());
+		let metrics = &scopes(&request)[0].metrics;
+
+		let Some(Data::Histogram(latency)) = &metrics[0].data else { panic!("not a histogram") };
+		assert_eq!(latency.aggregation_temporality, AggregationTemporality::Cumulative as i32);
+		let point = &latency.data_points[0];
+		assert_eq!(point.explicit_bounds, [0.5, 1.0]);
+		assert_eq!(point.bucket_counts, [1, 2, 1]);
+		assert_eq!((point.count, point.sum), (4, Some(4.6)));
+		assert_eq!((point.min, point.max), (Some(0.1), Some(3.0)));
+
+		let Some(Data::ExponentialHistogram(size)) = &metrics[1].data else {
+			panic!("not an exponential histogram")
+		};
+		let point = &size.data_points[0];
+		assert_eq!((point.scale, point.zero_count, point.count), (0, 1, 4));
+		// 1.5 falls in (1, 2], 5 and 6 in (4, 8], with an empty (2, 4] between them
+		assert_eq!(
+			point.positive,
+			Some(ExponentialBuckets { offset: 0, bucket_counts: vec![1, 0, 2] })
+		);
+		assert_eq!(point.negative, None);
+	}
+
+	#[test]
+	fn extreme_exponential_histograms() {
+		let metrics =
+			vec![histogram!("size", Buckets::exponential(20), [-1e300, 1e-300, 1.0, 1e300], "disk")];
+		let request = request(&metrics, &StaticTags::default());
+		let Some(Data::ExponentialHistogram(size)) = &scopes(&request)[0].metrics[0].data else {
+			panic!("not an exponential histogram")
+		};
+		let point = &size.data_points[0];
+		// 1e-300 and 1e300 are about 2^-997 and 2^997, buckets each growing by 2^16
+		assert_eq!(point.scale, -4);
+		let positive = point.positive.as_ref().unwrap();
+		assert!(positive.bucket_counts.len() <= 160);
+		assert_eq!(positive.bucket_counts.iter().sum::<u64>(), 3);
+		assert_eq!(positive.bucket_counts.first(), Some(&1));
+		assert_eq!(positive.bucket_counts.last(), Some(&1));
+		let negative = point.negative.as_ref().unwrap();
+		assert_eq!(negative.bucket_counts, [1]);
+	}
+
+	#[test]
+	fn info_state_sets_and_mismatched_types() {
+		let metrics = vec![
+			info_metric!("kernel_info", namespace = "host", "release" => "6.1.0"),
+			state_set!("operstate", ["up", "down"], "up", namespace = "net"),
+			string_metric!("model", "Xeon", namespace = "cpu"),
+			counter!("operstate", 1u64, "net"),
+		];
+		let request = request(&metrics, &StaticTags::default());
+		let scopes = scopes(&request);
+		let points = |scope: &ScopeMetrics| match &scope.metrics[..] {
+			[proto::Metric { data: Some(Data::Gauge(gauge)), .. }] => gauge.data_points.clone(),
+			metrics => panic!("expected a single gauge: {metrics:?}"),
+		};
+
+		let model = points(&scopes[0]);
+		assert_eq!(pairs(&model[0].attributes), [("value", "Xeon")]);
+		assert_eq!(model[0].value, Some(number_data_point::Value::AsInt(1)));
+
+		let kernel = points(&scopes[1]);
+		assert_eq!(pairs(&kernel[0].attributes), [("release", "6.1.0")]);
+
+		let operstate = points(&scopes[2]);
+		let states: Vec<_> = operstate.iter().map(|p| (pairs(&p.attributes), p.value)).collect();
+		assert_eq!(
+			states,
+			[
+				(vec![("operstate", "up")], Some(number_data_point::Value::AsInt(1))),
+				(vec![("operstate", "down")], Some(number_data_point::Value::AsInt(0))),
+			]
+		);
+	}
+
+	#[test]
+	fn length_delimited() {
+		let metrics = vec![gauge!("MemFree", 1024u64, "meminfo")];
+		let mut encoder = OtlpEncoderBuilder::new().length_delimited(true).build();
+		let mut bytes = BytesMut::new();
+		encoder.encode(&metrics, &mut bytes).unwrap();
+		encoder.encode(&metrics, &mut bytes).unwrap();
+		encoder.encode(&Vec::new(), &mut bytes).unwrap();
+
+		let mut buf = bytes.freeze();
+		for _ in 0..2 {
+			let request = ExportMetricsServiceRequest::decode_length_delimited(&mut buf).unwrap();
+			assert_eq!(scopes(&request)[0].metrics[0].name, "MemFree");
+		}
+		assert!(buf.is_empty());
+	}
+}
@@ -0,0 +1,35 @@
+use super::{ExpositionFormat, PrometheusEncoder};
+
+/// Builds a [`PrometheusEncoder`].
+#[derive(Debug, Default)]
+pub struct PrometheusEncoderBuilder {
+	format: ExpositionFormat,
+	timestamps: bool,
+}
+
+impl PrometheusEncoderBuilder {
+	pub fn new() -> Self {
+		PrometheusEncoderBuilder::default()
+	}
+
+	/// The text format to write.
+	///
+	/// The default is [`ExpositionFormat::Prometheus`].
+	pub const fn format(&mut self, format: ExpositionFormat) -> &mut Self {
+		self.format = format;
+		self
+	}
+
+	/// Whether samples are written with their timestamp.
+	///
+	/// This is disabled by default, as scrapes and the textfile collector of
+	/// the node exporter expect samples without one.
+	pub const fn timestamps(&mut self, yes: bool) -> &mut Self {
+		self.timestamps = yes;
+		self
+	}
+
+	pub const fn build(&self) -> PrometheusEncoder {
+		PrometheusEncoder { format: self.format, timestamps: self.timestamps }
+	}
+}
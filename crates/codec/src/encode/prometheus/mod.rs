@@ -0,0 +1,470 @@
+use super::{
+	CodecEncodeError, Encoder,
+	sample::{MetricSamples, Sample},
+};
+use bytes::{BufMut, BytesMut};
+use prism_event::{
+	metric::{
+		Metric, MetricBatch, MetricValue, StaticTags,
+		descriptor::{MetricDescriptor, MetricKind},
+		static_tags,
+	},
+	time::Timestamp,
+};
+use std::{
+	collections::BTreeMap,
+	fmt::{self, Display, Write},
+};
+mod builder;
+pub use builder::PrometheusEncoderBuilder;
+
+/// Text format written by a [`PrometheusEncoder`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ExpositionFormat {
+	/// Prometheus text format 0.0.4
+	#[default]
+	Prometheus,
+	/// OpenMetrics 1.0, with `# UNIT` lines and ending with `# EOF`
+	OpenMetrics,
+}
+
+impl ExpositionFormat {
+	/// Content type to serve the exposition with, e.g. to a scrape.
+	pub const fn content_type(&self) -> &'static str {
+		match self {
+			Self::Prometheus => "text/plain; version=0.0.4; charset=utf-8",
+			Self::OpenMetrics => "application/openmetrics-text; version=1.0.0; charset=utf-8",
+		}
+	}
+}
+
+/// Renders every encoded item, e.g. a batch, as one exposition in the
+/// Prometheus text format or in OpenMetrics, the building block of scrape and
+/// textfile outputs.
+///
+/// The family of a sample is named after its namespace and name, with the
+/// characters Prometheus does not allow, e.g. the parentheses of
+/// `Active(anon)`, replaced by `_` and the unit of its
+/// [descriptor](prism_event::metric::descriptor) appended. Samples are grouped
+/// under one `# HELP` and `# TYPE` per family, and a sample whose kind differs
+/// from the first one of its family is dropped. Counters get the `_total`
+/// suffix, while info, string and state set metrics, that Prometheus 0.0.4
+/// has no type for, are written as gauges in that format.
+pub struct PrometheusEncoder {
+	format: ExpositionFormat,
+	timestamps: bool,
+}
+
+impl Encoder<MetricBatch> for PrometheusEncoder {
+	type Error = CodecEncodeError;
+
+	fn encode(&mut self, item: &MetricBatch, out: &mut BytesMut) -> Result<(), Self::Error> {
+		self.write_exposition(item, out)
+	}
+}
+
+impl Encoder<Vec<Metric>> for PrometheusEncoder {
+	type Error = CodecEncodeError;
+
+	fn encode(&mut self, item: &Vec<Metric>, out: &mut BytesMut) -> Result<(), Self::Error> {
+		self.write_exposition(item, out)
+	}
+}
+
+/// Samples of one family, written together.
+struct Family<'a> {
+	kind: MetricKind,
+	descriptor: Option<&'static MetricDescriptor>,
+	samples: Vec<Sample<'a>>,
+}
+
+impl PrometheusEncoder {
+	fn write_exposition(
+		&self,
+		item: &impl MetricSamples,
+		out: &mut BytesMut,
+	) -> Result<(), CodecEncodeError> {
+		let mut families: BTreeMap<String, Family<'_>> = BTreeMap::new();
+		let mut name = String::new();
+		for sample in item.samples() {
+			let kind = sample.value.kind();
+			name.clear();
+			write_family_name(&mut name, &sample, kind)?;
+			match families.get_mut(name.as_str()) {
+				Some(family) if family.kind == kind => family.samples.push(sample),
+				Some(_) => {},
+				None => {
+					let descriptor = sample.descriptor;
+					families
+						.insert(name.clone(), Family { kind, descriptor, samples: vec![sample] });
+				},
+			}
+		}
+
+		let static_tags = static_tags();
+		for (name, family) in &families {
+			self.write_family(name, family, &static_tags, out)?;
+		}
+		if self.format == ExpositionFormat::OpenMetrics {
+			out.put_slice(b"# EOF\n");
+		}
+		Ok(())
+	}
+
+	fn write_family(
+		&self,
+		name: &str,
+		family: &Family<'_>,
+		static_tags: &StaticTags,
+		out: &mut BytesMut,
+	) -> Result<(), CodecEncodeError> {
+		let open_metrics = self.format == ExpositionFormat::OpenMetrics;
+		// Prometheus 0.0.4 names the metadata of counters after their samples
+		// and has no info nor state set types
+		let (suffix, kind) = match (family.kind, open_metrics) {
+			(MetricKind::Counter, false) => ("_total", "counter"),
+			(MetricKind::Info | MetricKind::String, false) => ("_info", "gauge"),
+			(MetricKind::StateSet, false) => ("", "gauge"),
+			(MetricKind::Info | MetricKind::String, true) => ("", "info"),
+			(kind, _) => ("", kind.as_str()),
+		};
+		let descriptor = family.descriptor;
+		if let Some(help) = descriptor.map(MetricDescriptor::description).filter(|d| !d.is_empty())
+		{
+			write!(out, "# HELP {name}{suffix} ")?;
+			write_escaped(out, help, open_metrics)?;
+			out.put_u8(b'\n');
+		}
+		let unit = descriptor.map_or("", |descriptor| descriptor.unit().as_str());
+		if open_metrics && !unit.is_empty() {
+			writeln!(out, "# UNIT {name} {unit}")?;
+		}
+		writeln!(out, "# TYPE {name}{suffix} {kind}")?;
+
+		for sample in &family.samples {
+			let tags = static_tags.resolve_tags(sample.namespace, sample.tags);
+			let timestamp = self.timestamps.then_some(sample.timestamp);
+			let mut line = SampleLine { out, name, tags: &tags, timestamp, format: self.format };
+			match sample.value {
+				MetricValue::Counter(value) => line.write("_total", None, Number::Float(*value))?,
+				MetricValue::Gauge(value) => line.write("", None, Number::Float(*value))?,
+				MetricValue::Histogram(histogram) => {
+					for (bound, count) in histogram.buckets().cumulative() {
+						let le = Some(("le", Label::Bound(bound)));
+						line.write("_bucket", le, Number::Count(count))?;
+					}
+					line.write("_sum", None, Number::Float(histogram.sum()))?;
+					line.write("_count", None, Number::Count(histogram.count()))?;
+				},
+				MetricValue::Summary(summary) => {
+					for (quantile, value) in summary.quantiles() {
+						let quantile = Some(("quantile", Label::Bound(*quantile)));
+						line.write("", quantile, Number::Float(*value))?;
+					}
+					line.write("_sum", None, Number::Float(summary.sum()))?;
+					line.write("_count", None, Number::Count(summary.count()))?;
+				},
+				MetricValue::Info => line.write("_info", None, Number::Count(1))?,
+				MetricValue::String(value) => {
+					line.write("_info", Some(("value", Label::Text(value))), Number::Count(1))?;
+				},
+				MetricValue::StateSet(states) => {
+					for (state, set) in states.states() {
+						let state = Some((name, Label::Text(state)));
+						line.write("", state, Number::Count(u64::from(*set)))?;
+					}
+				},
+			}
+		}
+		Ok(())
+	}
+}
+
+/// Writes the lines of the samples of one series.
+struct SampleLine<'a> {
+	out: &'a mut BytesMut,
+	name: &'a str,
+	tags: &'a BTreeMap<&'a str, &'a str>,
+	timestamp: Option<Timestamp>,
+	format: ExpositionFormat,
+}
+
+impl SampleLine<'_> {
+	/// Writes `value` under the name of the family followed by `suffix`, with
+	/// the tags of the series and the `extra` label, e.g. the `le` of a bucket.
+	fn write(
+		&mut self,
+		suffix: &str,
+		extra: Option<(&str, Label<'_>)>,
+		value: Number,
+	) -> Result<(), CodecEncodeError> {
+		let out = &mut *self.out;
+		write!(out, "{}{suffix}", self.name)?;
+		let mut labels = 0;
+		for (key, tag) in self.tags {
+			if extra.as_ref().is_some_and(|(extra, _)| extra == key) {
+				continue;
+			}
+			if !write_label_name(out, key, labels)? {
+				continue;
+			}
+			out.put_slice(b"=\"");
+			write_escaped(out, tag, true)?;
+			out.put_u8(b'"');
+			labels += 1;
+		}
+		if let Some((key, label)) = extra {
+			out.put_u8(if labels == 0 { b'{' } else { b',' });
+			write_sanitized(out, key, false)?;
+			out.put_slice(b"=\"");
+			match label {
+				Label::Text(text) => write_escaped(out, text, true)?,
+				Label::Bound(bound) => write!(out, "{}", Number::Bound(bound))?,
+			}
+			out.put_u8(b'"');
+			labels += 1;
+		}
+		if labels > 0 {
+			out.put_u8(b'}');
+		}
+		write!(out, " {value}")?;
+		match (self.timestamp, self.format) {
+			(Some(timestamp), ExpositionFormat::Prometheus) => {
+				write!(out, " {}", timestamp.as_nanos().div_euclid(1_000_000))?;
+			},
+			(Some(timestamp), ExpositionFormat::OpenMetrics) => {
+				let nanos = timestamp.as_nanos();
+				let (secs, nanos) =
+					(nanos.div_euclid(1_000_000_000), nanos.rem_euclid(1_000_000_000));
+				write!(out, " {secs}.{nanos:09}")?;
+			},
+			(None, _) => {},
+		}
+		out.put_u8(b'\n');
+		Ok(())
+	}
+}
+
+/// Value of the extra label of a sample.
+enum Label<'a> {
+	Text(&'a str),
+	/// Upper bound of a bucket or quantile
+	Bound(f64),
+}
+
+enum Number {
+	Float(f64),
+	Count(u64),
+	/// Written as a float even when integral, e.g. `1.0`, as OpenMetrics expects
+	/// of `le` and `quantile` labels
+	Bound(f64),
+}
+
+impl Display for Number {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Self::Float(value) | Self::Bound(value) if value.is_nan() => f.write_str("NaN"),
+			Self::Float(value) | Self::Bound(value) if value.is_infinite() => {
+				f.write_str(if *value > 0.0 { "+Inf" } else { "-Inf" })
+			},
+			Self::Float(value) => write!(f, "{value}"),
+			Self::Bound(value) => write!(f, "{value:?}"),
+			Self::Count(value) => write!(f, "{value}"),
+		}
+	}
+}
+
+/// Writes `namespace_name`, with the unit suffix and without the suffix of
+/// the kind, that samples add back.
+fn write_family_name(o: &mut String, sample: &Sample<'_>, kind: MetricKind) -> fmt::Result {
+	if let Some(namespace) = sample.namespace {
+		write_sanitized(o, namespace, true)?;
+		o.push('_');
+	}
+	write_sanitized(o, sample.name, true)?;
+	let kind_suffix = match kind {
+		MetricKind::Counter => "_total",
+		MetricKind::Info | MetricKind::String => "_info",
+		_ => "",
+	};
+	if !kind_suffix.is_empty() && o.ends_with(kind_suffix) {
+		o.truncate(o.len() - kind_suffix.len());
+	}
+	let unit = sample.descriptor.map_or("", |descriptor| descriptor.unit().as_str());
+	if !unit.is_empty() && !o.ends_with(unit) {
+		o.push('_');
+		o.push_str(unit);
+	}
+	Ok(())
+}
+
+/// Writes `name` with every run of characters not allowed in metric names,
+/// or in label names without `colons`, replaced by a single `_`. Leading and
+/// trailing runs are dropped, so that `Active(anon)` becomes `Active_anon`.
+fn write_sanitized(o: &mut impl Write, name: &str, colons: bool) -> fmt::Result {
+	let mut written = false;
+	let mut pending = false;
+	for c in name.chars() {
+		if c.is_ascii_alphanumeric() || c == '_' || (colons && c == ':') {
+			if pending {
+				o.write_char('_')?;
+				pending = false;
+			} else if !written && c.is_ascii_digit() {
+				o.write_char('_')?;
+			}
+			o.write_char(c)?;
+			written = true;
+		} else {
+			pending = written;
+		}
+	}
+	Ok(())
+}
+
+/// Writes the separator and the sanitized name of the label of a tag, unless
+/// the name would be empty, e.g. for a tag `()`, or start with `__`, which
+/// Prometheus reserves for its own labels. Such tags are left out.
+fn write_label_name(out: &mut BytesMut, key: &str, labels: usize) -> Result<bool, fmt::Error> {
+	let start = out.len();
+	out.put_u8(if labels == 0 { b'{' } else { b',' });
+	write_sanitized(out, key, false)?;
+	let name = &out[start + 1..];
+	if name.is_empty() || name.starts_with(b"__") {
+		out.truncate(start);
+		return Ok(false);
+	}
+	Ok(true)
+}
+
+/// Escapes backslashes and newlines, and double quotes if `quotes`, as
+/// label values and OpenMetrics help texts require.
+fn write_escaped(o: &mut impl Write, text: &str, quotes: bool) -> fmt::Result {
+	for c in text.chars() {
+		match c {
+			'\\' => o.write_str("\\\\")?,
+			'\n' => o.write_str("\\n")?,
+			'"' if quotes => o.write_str("\\\"")?,
+			c => o.write_char(c)?,
+		}
+	}
+	Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use prism_event::{
+		counter, gauge, histogram, info_metric,
+		metric::{Buckets, descriptor::Unit},
+		state_set,
+	};
+
+	static READ_TIME: MetricDescriptor = MetricDescriptor::new("read_time", MetricKind::Counter)
+		.with_unit(Unit::Milliseconds)
+		.with_description("Time spent reading\\sectors");
+
+	fn encode(format: ExpositionFormat, metrics: Vec<Metric>) -> String {
+		let mut encoder = PrometheusEncoderBuilder::new().format(format).build();
+		let mut bytes = BytesMut::new();
+		encoder.encode(&metrics, &mut bytes).unwrap();
+		String::from_utf8(bytes.to_vec()).unwrap()
+	}
+
+	fn metrics() -> Vec<Metric> {
+		vec![
+			counter!("read_time", 10u64, "disk", "device" => "sda").with_descriptor(&READ_TIME),
+			gauge!("Active(anon)", 1024u64, "meminfo"),
+			counter!("read_time", 20u64, "disk", "device" => "sdb").with_descriptor(&READ_TIME),
+			info_metric!("kernel_info", namespace = "host", "release" => "6.1.0"),
+			state_set!("operstate", ["up", "down"], "up", namespace = "net"),
+		]
+	}
+
+	#[test]
+	fn prometheus() {
+		assert_eq!(
+			encode(ExpositionFormat::Prometheus, metrics()),
+			r#"# HELP disk_read_time_milliseconds_total Time spent reading\\sectors
+# TYPE disk_read_time_milliseconds_total counter
+disk_read_time_milliseconds_total{device="sda"} 10
+disk_read_time_milliseconds_total{device="sdb"} 20
+# TYPE host_kernel_info gauge
+host_kernel_info{release="6.1.0"} 1
+# TYPE meminfo_Active_anon gauge
+meminfo_Active_anon 1024
+# TYPE net_operstate gauge
+net_operstate{net_operstate="up"} 1
+net_operstate{net_operstate="down"} 0
+"#
+		);
+	}
+
+	#[test]
+	fn open_metrics() {
+		assert_eq!(
+			encode(ExpositionFormat::OpenMetrics, metrics()),
+			r#"# HELP disk_read_time_milliseconds Time spent reading\\sectors
+# UNIT disk_read_time_milliseconds milliseconds
+# TYPE disk_read_time_milliseconds counter
+disk_read_time_milliseconds_total{device="sda"} 10
+disk_read_time_milliseconds_total{device="sdb"} 20
+# TYPE host_kernel info
+host_kernel_info{release="6.1.0"} 1
+# TYPE meminfo_Active_anon gauge
+meminfo_Active_anon 1024
+# TYPE net_operstate stateset
+net_operstate{net_operstate="up"} 1
+net_operstate{net_operstate="down"} 0
+# EOF
+"#
+		);
+	}
+
+	#[test]
+	fn histogram_and_labels() {
+		let metrics = vec![
+			histogram!("latency", Buckets::fixed([0.5, 1.0]), [0.1, 0.7], "disk", "path" => "a\"b\\c\nd"),
+			gauge!("temperature", 36.5, "hwmon", "1st sensor" => "cpu"),
+			gauge!("temperature", 41.0, "hwmon", "1st sensor" => "gpu"),
+			counter!("temperature", 1u64, "hwmon"),
+		];
+		assert_eq!(
+			encode(ExpositionFormat::OpenMetrics, metrics),
+			r#"# TYPE disk_latency histogram
+disk_latency_bucket{path="a\"b\\c\nd",le="0.5"} 1
+disk_latency_bucket{path="a\"b\\c\nd",le="1.0"} 2
+disk_latency_bucket{path="a\"b\\c\nd",le="+Inf"} 2
+disk_latency_sum{path="a\"b\\c\nd"} 0.7999999999999999
+disk_latency_count{path="a\"b\\c\nd"} 2
+# TYPE hwmon_temperature gauge
+hwmon_temperature{_1st_sensor="cpu"} 36.5
+hwmon_temperature{_1st_sensor="gpu"} 41
+# EOF
+"#
+		);
+	}
+
+	#[test]
+	fn invalid_labels() {
+		let metrics = vec![
+			gauge!("temperature", 36.5, "hwmon", "()" => "a", "__name__" => "b", "-_-x" => "c", "sensor" => "cpu"),
+		];
+		assert_eq!(
+			encode(ExpositionFormat::Prometheus, metrics),
+			r#"# TYPE hwmon_temperature gauge
+hwmon_temperature{sensor="cpu"} 36.5
+"#
+		);
+	}
+
+	#[test]
+	fn timestamps() {
+		let batch: MetricBatch = [gauge!("procs_running", 5u64, "host")].into_iter().collect();
+		let millis = batch.timestamp().as_nanos() / 1_000_000;
+		let mut encoder = PrometheusEncoderBuilder::new().timestamps(true).build();
+		let mut bytes = BytesMut::new();
+		encoder.encode(&batch, &mut bytes).unwrap();
+		let text = std::str::from_utf8(&bytes).unwrap();
+		assert!(text.ends_with(&format!("host_procs_running 5 {millis}\n")));
+	}
+}
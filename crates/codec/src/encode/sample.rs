@@ -0,0 +1,76 @@
+use prism_event::{
+	metric::{
+		Metric, MetricBatch, MetricTags, MetricValue, StaticTags, descriptor::MetricDescriptor,
+	},
+	time::Timestamp,
+};
+use std::collections::BTreeMap;
+
+/// A sample as seen by the metric-only encoders, whether it comes from a
+/// [`Metric`] or from a [`MetricBatch`].
+pub(crate) struct Sample<'a> {
+	pub(crate) name: &'a str,
+	pub(crate) namespace: Option<&'a str>,
+	/// Own tags of the series, before the static ones are merged
+	pub(crate) tags: Option<&'a MetricTags>,
+	pub(crate) descriptor: Option<&'static MetricDescriptor>,
+	pub(crate) value: &'a MetricValue,
+	pub(crate) timestamp: Timestamp,
+}
+
+impl Sample<'_> {
+	/// Own tags of the series then those of its namespace, without the global
+	/// ones that the formats carry apart, e.g. in an OTLP resource.
+	pub(crate) fn series_tags<'a>(
+		&'a self,
+		static_tags: &'a StaticTags,
+	) -> BTreeMap<&'a str, &'a str> {
+		let own = self.tags.into_iter().flat_map(MetricTags::iter);
+		let namespace = self
+			.namespace
+			.and_then(|namespace| static_tags.namespace(namespace))
+			.into_iter()
+			.flat_map(MetricTags::iter);
+		let mut tags = BTreeMap::new();
+		for (key, value) in own.chain(namespace) {
+			tags.entry(key).or_insert(value);
+		}
+		tags
+	}
+}
+
+pub(crate) trait MetricSamples {
+	fn samples(&self) -> impl Iterator<Item = Sample<'_>>;
+}
+
+impl MetricSamples for Metric {
+	fn samples(&self) -> impl Iterator<Item = Sample<'_>> {
+		std::iter::once(Sample {
+			name: self.name(),
+			namespace: self.namespace(),
+			tags: self.tags(),
+			descriptor: self.descriptor(),
+			value: self.value(),
+			timestamp: self.timestamp(),
+		})
+	}
+}
+
+impl MetricSamples for Vec<Metric> {
+	fn samples(&self) -> impl Iterator<Item = Sample<'_>> {
+		self.iter().flat_map(Metric::samples)
+	}
+}
+
+impl MetricSamples for MetricBatch {
+	fn samples(&self) -> impl Iterator<Item = Sample<'_>> {
+		self.iter().map(|(series, value)| Sample {
+			name: series.name(),
+			namespace: series.namespace(),
+			tags: series.tags(),
+			descriptor: series.descriptor(),
+			value,
+			timestamp: self.timestamp(),
+		})
+	}
+}
@@ -0,0 +1,26 @@
+use super::{StatsdEncoder, StatsdFlavor};
+use std::collections::BTreeMap;
+
+/// Builds a [`StatsdEncoder`].
+#[derive(Debug, Default)]
+pub struct StatsdEncoderBuilder {
+	flavor: StatsdFlavor,
+}
+
+impl StatsdEncoderBuilder {
+	pub fn new() -> Self {
+		StatsdEncoderBuilder::default()
+	}
+
+	/// The dialect to write.
+	///
+	/// The default is [`StatsdFlavor::Statsd`].
+	pub const fn flavor(&mut self, flavor: StatsdFlavor) -> &mut Self {
+		self.flavor = flavor;
+		self
+	}
+
+	pub const fn build(&self) -> StatsdEncoder {
+		StatsdEncoder { flavor: self.flavor, counters: BTreeMap::new() }
+	}
+}
@@ -0,0 +1,265 @@
+use super::{
+	CodecEncodeError, Encoder,
+	dotted::{Number, write_segment},
+	sample::{MetricSamples, Sample},
+};
+use bytes::BytesMut;
+use prism_event::metric::{Metric, MetricBatch, MetricValue, StaticTags, static_tags};
+use std::{
+	collections::BTreeMap,
+	fmt::{self, Write},
+	mem,
+};
+mod builder;
+pub use builder::StatsdEncoderBuilder;
+
+/// Dialect written by a [`StatsdEncoder`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum StatsdFlavor {
+	/// Plain StatsD, the tags of a series being levels of its name
+	#[default]
+	Statsd,
+	/// DogStatsD, as accepted by the Datadog agent, with `|#key:value` tags
+	DogStatsd,
+}
+
+/// Writes StatsD lines, e.g. `disk.read_completed:5|c|#device:sda` for
+/// DogStatsD, one per number of a sample and meant to be sent over UDP to a
+/// local agent.
+///
+/// The name of a sample is its namespace followed by its name. Plain StatsD
+/// has no tags, so the values of the own and namespace tags of the series are
+/// levels in between, e.g. `disk.sda.read_completed`, while DogStatsD carries
+/// every resolved tag. The characters StatsD reads as separators are replaced
+/// by `_`. Samples have no timestamp, the agent taking the time it receives
+/// them.
+///
+/// Counters are `|c` counts of the increase since the previous item, so that
+/// the agent can sum them over its flush interval: the first value of a
+/// series is only remembered, and a value lower than the previous one, after
+/// the counter was reset, is counted as a whole. A series missing from an item
+/// is forgotten, its next value being a first one again. Every other number is
+/// a `|g` gauge, those of histograms, summaries and state sets being written
+/// under the same paths as with [`GraphiteEncoder`](super::graphite::GraphiteEncoder),
+/// except that DogStatsD tags a state with the name of the metric, e.g.
+/// `net.operstate:1|g|#operstate:up`. String metrics, and `NaN` and infinite
+/// values, are left out.
+pub struct StatsdEncoder {
+	flavor: StatsdFlavor,
+	/// Last value of every counter, by name and tags, and whether it was seen
+	/// in the current item
+	counters: BTreeMap<String, (f64, bool)>,
+}
+
+impl Encoder<MetricBatch> for StatsdEncoder {
+	type Error = CodecEncodeError;
+
+	fn encode(&mut self, item: &MetricBatch, out: &mut BytesMut) -> Result<(), Self::Error> {
+		self.write_lines(item, out)
+	}
+}
+
+impl Encoder<Vec<Metric>> for StatsdEncoder {
+	type Error = CodecEncodeError;
+
+	fn encode(&mut self, item: &Vec<Metric>, out: &mut BytesMut) -> Result<(), Self::Error> {
+		self.write_lines(item, out)
+	}
+}
+
+impl StatsdEncoder {
+	fn write_lines(
+		&mut self,
+		item: &impl MetricSamples,
+		out: &mut BytesMut,
+	) -> Result<(), CodecEncodeError> {
+		let static_tags = static_tags();
+		let mut name = String::new();
+		let mut tags = String::new();
+		let mut key = String::new();
+		for sample in item.samples() {
+			let counter = matches!(sample.value, MetricValue::Counter(_));
+			for number in Number::of(sample.value) {
+				let value = number.value();
+				if !value.is_finite() {
+					continue;
+				}
+				name.clear();
+				tags.clear();
+				self.write_name(&mut name, &sample, &static_tags)?;
+				match (self.flavor, &number) {
+					(StatsdFlavor::DogStatsd, Number::State(state, _)) => {
+						self.write_tags(&mut tags, &sample, &static_tags, Some(state))?;
+					},
+					_ => {
+						number.write_suffix(&mut name)?;
+						self.write_tags(&mut tags, &sample, &static_tags, None)?;
+					},
+				}
+
+				if counter {
+					key.clear();
+					key.push_str(&name);
+					key.push_str(&tags);
+					let Some(value) = self.increase(&key, value) else {
+						continue;
+					};
+					writeln!(out, "{name}:{value}|c{tags}")?;
+				} else {
+					// a signed gauge is a change of the current value for StatsD
+					if value.is_sign_negative() && self.flavor == StatsdFlavor::Statsd {
+						writeln!(out, "{name}:0|g{tags}")?;
+					}
+					writeln!(out, "{name}:{value}|g{tags}")?;
+				}
+			}
+		}
+		self.counters.retain(|_, (_, seen)| mem::take(seen));
+		Ok(())
+	}
+
+	/// Writes the namespace and name of `sample`, with the values of the tags
+	/// of its series in between for plain StatsD.
+	fn write_name(
+		&self,
+		o: &mut impl Write,
+		sample: &Sample<'_>,
+		static_tags: &StaticTags,
+	) -> fmt::Result {
+		if let Some(namespace) = sample.namespace {
+			write_segment(o, namespace)?;
+			o.write_char('.')?;
+		}
+		if self.flavor == StatsdFlavor::Statsd {
+			for value in sample.series_tags(static_tags).into_values().filter(|v| !v.is_empty()) {
+				write_segment(o, value)?;
+				o.write_char('.')?;
+			}
+		}
+		write_segment(o, sample.name)
+	}
+
+	/// Writes the `|#key:value` tags of `sample` for DogStatsD, with the
+	/// `state` tagged by the name of the metric if any.
+	fn write_tags(
+		&self,
+		o: &mut impl Write,
+		sample: &Sample<'_>,
+		static_tags: &StaticTags,
+		state: Option<&str>,
+	) -> fmt::Result {
+		if self.flavor != StatsdFlavor::DogStatsd {
+			return Ok(());
+		}
+		let mut tags = static_tags.resolve_tags(sample.namespace, sample.tags);
+		if let Some(state) = state {
+			tags.insert(sample.name, state);
+		}
+		for (i, (key, value)) in tags.into_iter().enumerate() {
+			o.write_str(if i == 0 { "|#" } else { "," })?;
+			write_tag(o, key, true)?;
+			if !value.is_empty() {
+				o.write_char(':')?;
+				write_tag(o, value, false)?;
+			}
+		}
+		Ok(())
+	}
+
+	/// Increase of the counter `key` since its previous `value`, none when it
+	/// is seen for the first time.
+	fn increase(&mut self, key: &str, value: f64) -> Option<f64> {
+		let Some((previous, seen)) = self.counters.get_mut(key) else {
+			self.counters.insert(key.to_owned(), (value, true));
+			return None;
+		};
+		let previous = mem::replace(previous, value);
+		*seen = true;
+		Some(if value >= previous { value - previous } else { value })
+	}
+}
+
+/// Writes a tag key or value, replacing by `_` the characters that separate
+/// tags and fields, and the `:` of keys.
+fn write_tag(o: &mut impl Write, text: &str, key: bool) -> fmt::Result {
+	for c in text.chars() {
+		match c {
+			',' | '|' | '#' => o.write_char('_')?,
+			':' if key => o.write_char('_')?,
+			c if c.is_whitespace() => o.write_char('_')?,
+			c => o.write_char(c)?,
+		}
+	}
+	Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use prism_event::{
+		counter, gauge, histogram, metric::Buckets, shared_tags, state_set, string_metric,
+	};
+
+	fn encode(encoder: &mut StatsdEncoder, metrics: &Vec<Metric>) -> String {
+		let mut bytes = BytesMut::new();
+		encoder.encode(metrics, &mut bytes).unwrap();
+		String::from_utf8(bytes.to_vec()).unwrap()
+	}
+
+	#[test]
+	fn counter_increases() {
+		let tags = shared_tags!("device" => "sda");
+		let mut encoder = StatsdEncoderBuilder::new().build();
+		let read = |value: u64| vec![counter!("read_completed", value, "disk", tags = &tags)];
+		assert_eq!(encode(&mut encoder, &read(40)), "");
+		assert_eq!(encode(&mut encoder, &read(42)), "disk.sda.read_completed:2|c\n");
+		assert_eq!(encode(&mut encoder, &read(42)), "disk.sda.read_completed:0|c\n");
+		// reset
+		assert_eq!(encode(&mut encoder, &read(5)), "disk.sda.read_completed:5|c\n");
+		// forgotten once missing from an item
+		assert_eq!(encode(&mut encoder, &Vec::new()), "");
+		assert!(encoder.counters.is_empty());
+		assert_eq!(encode(&mut encoder, &read(7)), "");
+	}
+
+	#[test]
+	fn statsd() {
+		let metrics = vec![
+			gauge!("rx bytes", 7u64, "net", "interface" => "eth0.100"),
+			gauge!("temperature", -3.5, "hwmon"),
+			histogram!("latency", Buckets::fixed([0.5]), [0.1, 0.7], "disk"),
+			state_set!("operstate", ["up", "down"], "up", namespace = "net"),
+			string_metric!("firmware", "2B6Q", namespace = "disk"),
+			gauge!("load", f64::NAN, "cpu"),
+		];
+		assert_eq!(
+			encode(&mut StatsdEncoderBuilder::new().build(), &metrics),
+			"net.eth0_100.rx_bytes:7|g\n\
+			 hwmon.temperature:0|g\n\
+			 hwmon.temperature:-3.5|g\n\
+			 disk.latency.count:2|g\n\
+			 disk.latency.sum:0.7999999999999999|g\n\
+			 disk.latency.bucket.0_5:1|g\n\
+			 disk.latency.bucket.inf:2|g\n\
+			 net.operstate.up:1|g\n\
+			 net.operstate.down:0|g\n"
+		);
+	}
+
+	#[test]
+	fn dogstatsd() {
+		let metrics = vec![
+			gauge!("rx bytes", 7u64, "net", "interface" => "eth0", "alias" => "a,b|c", "vlan" => ""),
+			gauge!("temperature", -3.5, "hwmon"),
+			state_set!("operstate", ["up", "down"], "up", namespace = "net"),
+		];
+		let mut encoder = StatsdEncoderBuilder::new().flavor(StatsdFlavor::DogStatsd).build();
+		assert_eq!(
+			encode(&mut encoder, &metrics),
+			"net.rx_bytes:7|g|#alias:a_b_c,interface:eth0,vlan\n\
+			 hwmon.temperature:-3.5|g\n\
+			 net.operstate:1|g|#operstate:up\n\
+			 net.operstate:0|g|#operstate:down\n"
+		);
+	}
+}
@@ -1 +1,128 @@
+//! Agent configuration, read from `config/prism.toml`.
+//!
+//! ```toml
+//! # Tags added to every metric
+//! [tags]
+//! env = "production"
+//! region = "eu-west-1"
+//!
+//! # How timestamps are written, and the timezone files rotate in
+//! [time]
+//! format = "rfc3339"
+//! precision = "ms"
+//! timezone = "UTC"
+//!
+//! # Tag every metric with the host identity, e.g. `hostname` and `machine_id`
+//! [metric]
+//! identity_tags = true
+//!
+//! # Tags added to the metrics of a collector, by namespace
+//! [metric.collectors.network.tags]
+//! fabric = "storage"
+//! ```
 
+use serde::Deserialize;
+use std::{collections::BTreeMap, fs, io, path::Path};
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum ConfigError {
+	#[error("Failed to read config: {0}")]
+	Io(#[from] io::Error),
+	#[error("Failed to parse config: {0}")]
+	Parse(#[from] toml::de::Error),
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct Config {
+	/// Tags added to every metric, e.g. `host`, `env` or `region`
+	pub tags: BTreeMap<String, String>,
+	pub time: TimeConfig,
+	pub metric: MetricConfig,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(default)]
+pub struct TimeConfig {
+	/// `rfc3339`, `epoch` or a `strftime` format such as `%Y-%m-%d %H:%M:%S%.3f`
+	pub format: String,
+	/// `s`, `ms`, `us` or `ns`, for the `rfc3339` and `epoch` formats
+	pub precision: String,
+	/// `UTC`, `local` or a fixed offset such as `+08:00`, also used to rotate files
+	pub timezone: String,
+}
+
+impl Default for TimeConfig {
+	fn default() -> Self {
+		Self { format: "rfc3339".into(), precision: "ms".into(), timezone: "UTC".into() }
+	}
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(default)]
+pub struct MetricConfig {
+	/// Whether the host identity (`hostname`, `machine_id`, `boot_id`,
+	/// `kernel_release`, `os_id` and `os_version_id`) is added to the global
+	/// tags, enabled by default
+	pub identity_tags: bool,
+	/// Collector settings by namespace, e.g. `cpu` or `network`
+	pub collectors: BTreeMap<String, CollectorConfig>,
+}
+
+impl Default for MetricConfig {
+	fn default() -> Self {
+		Self { identity_tags: true, collectors: BTreeMap::new() }
+	}
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct CollectorConfig {
+	/// Tags added to the metrics of the collector, on top of the global ones
+	pub tags: BTreeMap<String, String>,
+}
+
+impl Config {
+	pub fn from_path(path: impl AsRef<Path>) -> Result<Self, ConfigError> {
+		Self::parse(&fs::read_to_string(path)?)
+	}
+
+	pub fn parse(content: &str) -> Result<Self, ConfigError> {
+		Ok(toml::from_str(content)?)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_parse() {
+		let config = Config::parse(
+			r#"
+			[tags]
+			env = "production"
+
+			[time]
+			format = "epoch"
+
+			[metric]
+			interval = 1
+			identity_tags = false
+
+			[metric.collectors.network.tags]
+			fabric = "storage"
+			"#,
+		)
+		.unwrap();
+		assert_eq!(config.tags["env"], "production");
+		assert_eq!(config.metric.collectors["network"].tags["fabric"], "storage");
+		assert_eq!(config.time.format, "epoch");
+		assert_eq!(config.time.timezone, "UTC");
+		assert!(!config.metric.identity_tags);
+		let config = Config::parse("").unwrap();
+		assert!(config.tags.is_empty());
+		assert!(config.metric.identity_tags);
+	}
+}
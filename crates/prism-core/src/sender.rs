@@ -1,5 +1,8 @@
-use bytes::BytesMut;
-use prism_event::metric::Metric;
+use bytes::{Bytes, BytesMut};
+use prism_event::{
+	event::Event,
+	metric::{Metric, MetricBatch},
+};
 
 /// A abstraction for sending data and serialize data
 pub trait Sendable: Send + 'static {
@@ -16,13 +19,17 @@ pub trait Sender<S: Sendable>: Send + 'static {
 	/// Flushes the encoder buffer and sends the data.
 	/// This is usually called when the buffer is full or when the transport is stopped.
 	async fn flush(&mut self) -> Result<(), Self::Error>;
+	/// Sets what to write at the start of every file or stream, e.g. the
+	/// header row of CSV output. Ignored by default.
+	fn set_header(&mut self, _header: Bytes) {}
 }
 
-impl Sendable for Metric {
-	fn encode(&self, o: &mut BytesMut) -> Result<(), std::io::Error> {
-		self.encode(o)
-	}
-}
+impl Sendable for Metric {}
 
-// TODO: change metric send logic to
 impl Sendable for Vec<Metric> {}
+
+impl Sendable for MetricBatch {}
+
+impl Sendable for Event {}
+
+impl Sendable for Vec<Event> {}
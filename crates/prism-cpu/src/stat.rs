@@ -2,6 +2,7 @@ use crate::{CpuMetricError, utils::clock_ticks_to_seconds};
 use prism_macros::ProcParser;
 use prism_metric_common::procfs_root;
 use prism_metric_utils::read_to_string;
+use std::fmt;
 use uom::si::{f64::Time, time::second};
 
 /// CPU statistics from /proc/stat
@@ -163,8 +164,8 @@ impl Stat {
 	}
 }
 
-impl ToString for Stat {
-	fn to_string(&self) -> String {
+impl fmt::Display for Stat {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
 		let mut result = String::new();
 		result.push_str(&format!(
 			"cpu  {} {} {} {} {} {} {} {} {} {}\n",
@@ -212,7 +213,7 @@ impl ToString for Stat {
 		result.push_str(
 			"softirq 4679053537 3 217228708 171684 2852529223 217514 0 1920121 752158546 1247 854826491",
 		);
-		result
+		f.write_str(&result)
 	}
 }
 
@@ -0,0 +1,268 @@
+//! Block device attributes
+//!
+//! Every `/sys/block/<disk>` directory describes a whole disk: `size` in
+//! 512-byte sectors, `removable`, the `queue/` settings (`rotational`,
+//! `scheduler`, `nr_requests`) and, for real hardware, a `device/` link with
+//! the `model`. Partitions are the subdirectories holding a `partition` file
+//! and share the queue of their disk.
+//!
+//! Stacked devices are linked both ways: `slaves/` of a device-mapper or md
+//! device lists the devices it is built on, `holders/` of those devices lists
+//! it back. Device-mapper devices also carry their `dm/name` (e.g.
+//! `vg0-root`) and `dm/uuid`.
+
+use crate::{DiskMetricError, diskstat::DISK_SECTOR_SIZE};
+use prism_metric_common::sysfs_root;
+use prism_metric_utils::{path_exists_lossy, read_dir, read_to_string};
+use std::{io, path::Path};
+use uom::si::{f64::Information, information::byte};
+
+/// Kind of storage behind a block device
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiskType {
+	Ssd,
+	Hdd,
+	Nvme,
+	/// Not backed by a device, e.g. device-mapper, md, loop or zram
+	Virtual,
+}
+
+impl DiskType {
+	pub const fn as_str(&self) -> &'static str {
+		match self {
+			Self::Ssd => "ssd",
+			Self::Hdd => "hdd",
+			Self::Nvme => "nvme",
+			Self::Virtual => "virtual",
+		}
+	}
+}
+
+/// A disk or partition from `/sys/block`
+pub struct BlockDevice {
+	/// Device name, as found in `/proc/diskstats`
+	name: String,
+	/// Disk holding this partition, `None` for whole disks
+	disk: Option<String>,
+	/// Devices this one is built on, e.g. the members of an md array
+	slaves: Vec<String>,
+	/// Devices built on this one, e.g. a device-mapper target
+	holders: Vec<String>,
+	size: Information,
+	/// Whether the disk spins, `None` when the queue does not say
+	rotational: Option<bool>,
+	/// Active I/O scheduler, e.g. `mq-deadline`
+	scheduler: Option<String>,
+	/// Maximum number of queued requests
+	nr_requests: Option<u64>,
+	/// Model reported by the device, e.g. `Samsung SSD 980 PRO 1TB`
+	model: Option<String>,
+	removable: bool,
+	disk_type: DiskType,
+	/// Device-mapper name, e.g. `vg0-root` for `dm-0`
+	dm_name: Option<String>,
+	/// Device-mapper uuid, e.g. `LVM-<vg uuid><lv uuid>`
+	dm_uuid: Option<String>,
+}
+
+impl BlockDevice {
+	pub fn name(&self) -> &str {
+		&self.name
+	}
+
+	pub fn disk(&self) -> Option<&str> {
+		self.disk.as_deref()
+	}
+
+	pub fn slaves(&self) -> &[String] {
+		&self.slaves
+	}
+
+	pub fn holders(&self) -> &[String] {
+		&self.holders
+	}
+
+	/// Devices this one consumes space from: the disk of a partition or the
+	/// slaves of a stacked device. Empty for a top-level disk.
+	pub fn parents(&self) -> Vec<&str> {
+		match &self.disk {
+			Some(disk) => vec![disk.as_str()],
+			None => self.slaves.iter().map(String::as_str).collect(),
+		}
+	}
+
+	pub const fn size(&self) -> Information {
+		self.size
+	}
+
+	pub const fn rotational(&self) -> Option<bool> {
+		self.rotational
+	}
+
+	pub fn scheduler(&self) -> Option<&str> {
+		self.scheduler.as_deref()
+	}
+
+	pub const fn nr_requests(&self) -> Option<u64> {
+		self.nr_requests
+	}
+
+	pub fn model(&self) -> Option<&str> {
+		self.model.as_deref()
+	}
+
+	pub const fn removable(&self) -> bool {
+		self.removable
+	}
+
+	pub const fn disk_type(&self) -> DiskType {
+		self.disk_type
+	}
+
+	pub fn dm_name(&self) -> Option<&str> {
+		self.dm_name.as_deref()
+	}
+
+	pub fn dm_uuid(&self) -> Option<&str> {
+		self.dm_uuid.as_deref()
+	}
+
+	/// Reads a whole disk and its partitions.
+	async fn read_disk(dir: &Path, name: &str) -> Result<Vec<Self>, DiskMetricError> {
+		let rotational = read_attribute(dir, "queue/rotational").await.map(|value| value == "1");
+		let scheduler = read_attribute(dir, "queue/scheduler")
+			.await
+			.and_then(|value| parse_scheduler(&value).map(String::from));
+		let nr_requests = match read_attribute(dir, "queue/nr_requests").await {
+			Some(value) => Some(value.parse()?),
+			None => None,
+		};
+		let model = read_attribute(dir, "device/model").await.filter(|model| !model.is_empty());
+		let removable = read_attribute(dir, "removable").await.is_some_and(|value| value == "1");
+		let disk_type = if name.starts_with("nvme") {
+			DiskType::Nvme
+		} else if !path_exists_lossy(dir.join("device")).await {
+			DiskType::Virtual
+		} else if rotational == Some(true) {
+			DiskType::Hdd
+		} else {
+			DiskType::Ssd
+		};
+
+		let dm_name = read_attribute(dir, "dm/name").await;
+		let dm_uuid = read_attribute(dir, "dm/uuid").await.filter(|uuid| !uuid.is_empty());
+		let slaves = read_names(dir.join("slaves")).await?;
+		let holders = read_names(dir.join("holders")).await?;
+		let size = read_size(dir).await?;
+		let mut devices = vec![Self {
+			name: name.to_string(),
+			disk: None,
+			slaves,
+			holders,
+			size,
+			rotational,
+			scheduler,
+			nr_requests,
+			model,
+			removable,
+			disk_type,
+			dm_name,
+			dm_uuid,
+		}];
+
+		for entry in read_dir(dir.to_owned()).await? {
+			if !path_exists_lossy(entry.join("partition")).await {
+				continue;
+			}
+			let partition = file_name(&entry).to_string();
+			let slaves = read_names(entry.join("slaves")).await?;
+			let holders = read_names(entry.join("holders")).await?;
+			let size = read_size(&entry).await?;
+			let disk = &devices[0];
+			devices.push(Self {
+				name: partition,
+				disk: Some(disk.name.clone()),
+				slaves,
+				holders,
+				size,
+				rotational: disk.rotational,
+				scheduler: disk.scheduler.clone(),
+				nr_requests: disk.nr_requests,
+				model: disk.model.clone(),
+				removable: disk.removable,
+				disk_type: disk.disk_type,
+				dm_name: None,
+				dm_uuid: None,
+			});
+		}
+		Ok(devices)
+	}
+}
+
+fn file_name(path: &Path) -> &str {
+	path.file_name().and_then(|name| name.to_str()).unwrap_or_default()
+}
+
+async fn read_attribute(dir: &Path, name: &str) -> Option<String> {
+	read_to_string(dir.join(name)).await.ok().map(|value| value.trim().to_string())
+}
+
+async fn read_size(dir: &Path) -> Result<Information, DiskMetricError> {
+	let sectors: u64 = read_to_string(dir.join("size")).await?.trim().parse()?;
+	Ok(Information::new::<byte>(sectors as f64 * DISK_SECTOR_SIZE as f64))
+}
+
+/// Lists the entries of `slaves/` or `holders/`, which only exist for some
+/// devices.
+async fn read_names<P>(dir: P) -> Result<Vec<String>, DiskMetricError>
+where
+	P: AsRef<Path> + Send + 'static,
+{
+	match read_dir(dir).await {
+		Ok(entries) => Ok(entries.iter().map(|entry| file_name(entry).to_string()).collect()),
+		Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Vec::new()),
+		Err(e) => Err(e.into()),
+	}
+}
+
+/// Returns the active scheduler of `queue/scheduler`, the one in brackets,
+/// e.g. `mq-deadline` for `[mq-deadline] kyber none`.
+fn parse_scheduler(content: &str) -> Option<&str> {
+	let content = content.trim();
+	match content.split_once('[') {
+		Some((_, rest)) => rest.split_once(']').map(|(active, _)| active),
+		// Queues with a single choice, e.g. `none` on some virtual devices
+		None => (!content.is_empty() && !content.contains(' ')).then_some(content),
+	}
+}
+
+/// Reads every disk under `/sys/block` and its partitions, sorted by name.
+pub async fn block_devices() -> Result<Vec<BlockDevice>, DiskMetricError> {
+	let root = sysfs_root().join("block");
+	let entries = match read_dir(root).await {
+		Ok(entries) => entries,
+		Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+		Err(e) => return Err(e.into()),
+	};
+
+	let mut devices = Vec::with_capacity(entries.len());
+	for dir in entries {
+		let name = file_name(&dir).to_string();
+		devices.extend(BlockDevice::read_disk(&dir, &name).await?);
+	}
+	devices.sort_by(|a, b| a.name.cmp(&b.name));
+	Ok(devices)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_parse_scheduler() {
+		assert_eq!(parse_scheduler("[mq-deadline] kyber bfq none\n"), Some("mq-deadline"));
+		assert_eq!(parse_scheduler("mq-deadline kyber [none]"), Some("none"));
+		assert_eq!(parse_scheduler("none\n"), Some("none"));
+		assert_eq!(parse_scheduler(""), None);
+	}
+}
@@ -26,54 +26,54 @@ fn millis_to_time(millis: u64) -> Time {
 #[derive(ProcParser)]
 #[fmt = "table"]
 pub struct DiskStat {
-	/// major number
+	/// Major device number
 	major: u64,
-	/// minor number
+	/// Minor device number
 	minor: u64,
-	/// device name
+	/// Device name
 	name: String,
-	/// reads completed successfully
+	/// Reads completed successfully
 	read_completed: u64,
-	/// reads merged
+	/// Adjacent reads merged for efficiency
 	read_merged: u64,
-	/// sectors read (converted to bytes)
+	/// Bytes read, from 512-byte sectors
 	#[arg(with = sectors_to_bytes)]
 	sectors_read: Information,
-	/// time spent reading (ms, converted to Time)
+	/// Time spent reading
 	#[arg(with = millis_to_time)]
 	read_time: Time,
-	/// writes completed
+	/// Writes completed successfully
 	write_completed: u64,
-	/// writes merged
+	/// Adjacent writes merged for efficiency
 	write_merged: u64,
-	/// sectors written (converted to bytes)
+	/// Bytes written, from 512-byte sectors
 	#[arg(with = sectors_to_bytes)]
 	sectors_written: Information,
-	/// time spent writing (ms, converted to Time)
+	/// Time spent writing
 	#[arg(with = millis_to_time)]
 	writing_time: Time,
 	/// I/Os currently in progress
 	ios_in_progress: u64,
-	/// time spent doing I/Os (ms, converted to Time)
+	/// Time spent doing I/Os
 	#[arg(with = millis_to_time)]
 	io_time: Time,
-	/// weighted time spent doing I/Os (ms, converted to Time)
+	/// Time spent doing I/Os weighted by the number of I/Os in progress
 	#[arg(with = millis_to_time)]
 	weighted_io_time: Time,
-	/// discards completed successfully (kernel 4.18+)
+	/// Discards completed successfully (kernel 4.18+)
 	discard_completed: Option<u64>,
-	/// discards merged (kernel 4.18+)
+	/// Adjacent discards merged for efficiency (kernel 4.18+)
 	discard_merged: Option<u64>,
-	/// sectors discarded (kernel 4.18+)
+	/// Bytes discarded, from 512-byte sectors (kernel 4.18+)
 	#[arg(with = sectors_to_bytes, optional)]
 	sectors_discarded: Option<Information>,
-	/// time spent discarding (kernel 4.18+, converted to Time)
+	/// Time spent discarding (kernel 4.18+)
 	#[arg(with = millis_to_time, optional)]
 	discarding_time: Option<Time>,
-	/// flush requests completed successfully (kernel 5.5+)
+	/// Flush requests completed successfully (kernel 5.5+)
 	#[arg(optional)]
 	flush_completed: Option<u64>,
-	/// time spent flushing (kernel 5.5+, converted to Time)
+	/// Time spent flushing (kernel 5.5+)
 	#[arg(with = millis_to_time, optional)]
 	flushing_time: Option<Time>,
 }
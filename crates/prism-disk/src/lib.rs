@@ -1,5 +1,7 @@
 //! Collect disk metrics.
 pub use error::DiskMetricError;
 
+pub mod block;
 pub mod diskstat;
 mod error;
+pub mod mdstat;
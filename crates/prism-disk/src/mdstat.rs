@@ -0,0 +1,356 @@
+//! Software RAID arrays
+//!
+//! `/proc/mdstat` describes every md array in a block starting with
+//! `md<N> : <state> [(read-only)] <level> <member>[<slot>][(flags)]...`. The
+//! next line holds the size in 1K blocks followed, for redundant levels, by
+//! `[<raid disks>/<active disks>] [UU_]`. A third line reports a running
+//! resync, recovery, check or reshape with its progress, estimated finish and
+//! speed.
+
+use crate::DiskMetricError;
+use prism_metric_common::procfs_root;
+use prism_metric_utils::read_to_string;
+use std::io;
+use uom::si::{
+	f64::{Information, InformationRate, Time},
+	information::kilobyte,
+	information_rate::kilobyte_per_second,
+	time::minute,
+};
+
+/// Sync operations that may be reported for an array
+const SYNC_ACTIONS: &[&str] = &["resync", "recovery", "reshape", "check", "repair"];
+
+/// Role of a member device, from its `(F)`, `(S)`, `(W)` or `(R)` flag
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MemberRole {
+	Active,
+	Failed,
+	Spare,
+	WriteMostly,
+	Replacement,
+}
+
+impl MemberRole {
+	pub const fn as_str(&self) -> &'static str {
+		match self {
+			Self::Active => "active",
+			Self::Failed => "failed",
+			Self::Spare => "spare",
+			Self::WriteMostly => "write_mostly",
+			Self::Replacement => "replacement",
+		}
+	}
+}
+
+/// A member device of an array, e.g. `sdb1[1](F)`
+pub struct MdMember {
+	/// Device name, e.g. `sdb1`
+	name: String,
+	/// Slot of the device in the array
+	slot: u32,
+	role: MemberRole,
+}
+
+impl MdMember {
+	pub fn name(&self) -> &str {
+		&self.name
+	}
+
+	pub const fn slot(&self) -> u32 {
+		self.slot
+	}
+
+	pub const fn role(&self) -> MemberRole {
+		self.role
+	}
+}
+
+/// A running or pending sync operation
+pub struct MdSync {
+	/// `resync`, `recovery`, `reshape`, `check` or `repair`
+	action: String,
+	/// Completed fraction, `0` while the operation is `DELAYED` or `PENDING`
+	progress: f64,
+	/// Estimated time left
+	finish: Option<Time>,
+	speed: Option<InformationRate>,
+}
+
+impl MdSync {
+	pub fn action(&self) -> &str {
+		&self.action
+	}
+
+	pub const fn progress(&self) -> f64 {
+		self.progress
+	}
+
+	pub const fn finish(&self) -> Option<Time> {
+		self.finish
+	}
+
+	pub const fn speed(&self) -> Option<InformationRate> {
+		self.speed
+	}
+}
+
+/// An md array from `/proc/mdstat`
+pub struct MdArray {
+	/// Array name, e.g. `md0`
+	name: String,
+	/// `active` or `inactive`
+	state: String,
+	/// Whether the array is `(read-only)` or `(auto-read-only)`
+	read_only: bool,
+	/// RAID level, e.g. `raid1`, `None` for inactive arrays
+	level: Option<String>,
+	members: Vec<MdMember>,
+	size: Information,
+	/// Number of devices the array is made of, for redundant levels
+	raid_disks: Option<u32>,
+	/// Number of devices currently in use, for redundant levels
+	active_disks: Option<u32>,
+	sync: Option<MdSync>,
+}
+
+impl MdArray {
+	pub fn name(&self) -> &str {
+		&self.name
+	}
+
+	pub fn state(&self) -> &str {
+		&self.state
+	}
+
+	pub fn is_active(&self) -> bool {
+		self.state == "active"
+	}
+
+	pub const fn read_only(&self) -> bool {
+		self.read_only
+	}
+
+	pub fn level(&self) -> Option<&str> {
+		self.level.as_deref()
+	}
+
+	pub fn members(&self) -> &[MdMember] {
+		&self.members
+	}
+
+	pub const fn size(&self) -> Information {
+		self.size
+	}
+
+	pub const fn raid_disks(&self) -> Option<u32> {
+		self.raid_disks
+	}
+
+	pub const fn active_disks(&self) -> Option<u32> {
+		self.active_disks
+	}
+
+	pub const fn sync(&self) -> Option<&MdSync> {
+		self.sync.as_ref()
+	}
+
+	/// Whether fewer devices are in use than the array is made of.
+	pub const fn is_degraded(&self) -> bool {
+		matches!((self.raid_disks, self.active_disks), (Some(raid), Some(active)) if active < raid)
+	}
+
+	/// Number of members with the given role.
+	pub fn count(&self, role: MemberRole) -> usize {
+		self.members.iter().filter(|member| member.role == role).count()
+	}
+}
+
+fn invalid(line: &str) -> DiskMetricError {
+	io::Error::new(io::ErrorKind::InvalidData, format!("invalid mdstat line: {line}")).into()
+}
+
+/// Parses a member such as `sdb1[1](F)`.
+fn parse_member(token: &str) -> Option<MdMember> {
+	let (name, rest) = token.split_once('[')?;
+	let (slot, flags) = rest.split_once(']')?;
+	let role = if flags.contains("(F)") {
+		MemberRole::Failed
+	} else if flags.contains("(S)") {
+		MemberRole::Spare
+	} else if flags.contains("(R)") {
+		MemberRole::Replacement
+	} else if flags.contains("(W)") {
+		MemberRole::WriteMostly
+	} else {
+		MemberRole::Active
+	};
+	Some(MdMember { name: name.to_string(), slot: slot.parse().ok()?, role })
+}
+
+/// Parses `md0 : active raid1 sdb1[1] sda1[0]`.
+fn parse_header(line: &str) -> Result<MdArray, DiskMetricError> {
+	let (name, rest) = line.split_once(" : ").ok_or_else(|| invalid(line))?;
+	let mut tokens = rest.split_whitespace().peekable();
+	let state = tokens.next().ok_or_else(|| invalid(line))?.to_string();
+	let read_only = tokens.next_if(|token| token.ends_with("read-only)")).is_some();
+	let level = tokens.next_if(|token| !token.contains('[')).map(String::from);
+	let members = tokens
+		.map(|token| parse_member(token).ok_or_else(|| invalid(line)))
+		.collect::<Result<_, _>>()?;
+
+	Ok(MdArray {
+		name: name.trim().to_string(),
+		state,
+		read_only,
+		level,
+		members,
+		size: Information::new::<kilobyte>(0.0),
+		raid_disks: None,
+		active_disks: None,
+		sync: None,
+	})
+}
+
+/// Parses `1047552 blocks super 1.2 [2/1] [U_]` into `array`.
+fn parse_status(array: &mut MdArray, line: &str) -> Result<(), DiskMetricError> {
+	let blocks: u64 = line.split_whitespace().next().ok_or_else(|| invalid(line))?.parse()?;
+	array.size = Information::new::<kilobyte>(blocks as f64);
+
+	let disks = line
+		.split_whitespace()
+		.find_map(|token| token.strip_prefix('[')?.strip_suffix(']')?.split_once('/'));
+	if let Some((raid, active)) = disks {
+		array.raid_disks = Some(raid.parse()?);
+		array.active_disks = Some(active.parse()?);
+	}
+	Ok(())
+}
+
+/// Parses `[=>...]  recovery =  8.5% (166504448/1953423360) finish=144.0min speed=206548K/sec`
+/// or `resync=DELAYED`.
+fn parse_sync(line: &str) -> Option<MdSync> {
+	let (action, rest) = SYNC_ACTIONS.iter().find_map(|action| {
+		let (_, rest) = line.split_once(action)?;
+		Some((*action, rest.trim_start().strip_prefix('=')?.trim_start()))
+	})?;
+
+	let progress = rest
+		.split_once('%')
+		.and_then(|(percent, _)| percent.trim().parse::<f64>().ok())
+		.map_or(0.0, |percent| percent / 100.0);
+	let field = |key: &str, suffix: &str| {
+		rest.split_whitespace()
+			.find_map(|token| token.strip_prefix(key)?.strip_suffix(suffix)?.parse::<f64>().ok())
+	};
+
+	Some(MdSync {
+		action: action.to_string(),
+		progress,
+		finish: field("finish=", "min").map(Time::new::<minute>),
+		speed: field("speed=", "K/sec").map(InformationRate::new::<kilobyte_per_second>),
+	})
+}
+
+/// Parses the content of `/proc/mdstat`.
+pub fn parse(content: &str) -> Result<Vec<MdArray>, DiskMetricError> {
+	let mut arrays: Vec<MdArray> = Vec::new();
+	// Whether the current array still waits for its status line
+	let mut pending_status = false;
+	for line in content.lines() {
+		if line.starts_with("Personalities") || line.starts_with("unused devices") {
+			continue;
+		}
+		if !line.starts_with(char::is_whitespace) && line.contains(" : ") {
+			arrays.push(parse_header(line)?);
+			pending_status = true;
+			continue;
+		}
+		let Some(array) = arrays.last_mut() else {
+			continue;
+		};
+		let line = line.trim();
+		if line.is_empty() {
+			continue;
+		}
+		if pending_status {
+			parse_status(array, line)?;
+			pending_status = false;
+		} else if array.sync.is_none() && !line.starts_with("bitmap") {
+			array.sync = parse_sync(line);
+		}
+	}
+	Ok(arrays)
+}
+
+/// Reads every md array, kernels without the md driver yield an empty list.
+pub async fn mdstat() -> Result<Vec<MdArray>, DiskMetricError> {
+	let content = match read_to_string(procfs_root().join("mdstat")).await {
+		Ok(content) => content,
+		Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+		Err(e) => return Err(e.into()),
+	};
+	parse(&content)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_parse() {
+		let content = "Personalities : [raid1] [raid6] [raid5] [raid4]
+md1 : active raid1 sdb2[1] sda2[0]
+      1047552 blocks super 1.2 [2/2] [UU]
+      bitmap: 0/1 pages [0KB], 65536KB chunk
+
+md0 : active raid5 sdd1[4] sdc1[2] sdb1[1](F) sda1[0] sde1[5](S)
+      5860270080 blocks super 1.2 level 5, 512k chunk, algorithm 2 [4/3] [UUU_]
+      [=>...................]  recovery =  8.5% (166504448/1953423360) finish=144.0min speed=206548K/sec
+
+md127 : active (auto-read-only) raid1 sdg[0] sdh[1]
+      976630464 blocks super 1.2 [2/2] [UU]
+      \tresync=DELAYED
+
+md2 : inactive sdf1[1](S)
+      1953260976 blocks super 1.2
+
+unused devices: <none>
+";
+		let arrays = parse(content).unwrap();
+		assert_eq!(arrays.len(), 4);
+
+		let md1 = &arrays[0];
+		assert_eq!((md1.name(), md1.state(), md1.level()), ("md1", "active", Some("raid1")));
+		assert_eq!(md1.size().get::<kilobyte>(), 1047552.0);
+		assert_eq!((md1.raid_disks(), md1.active_disks()), (Some(2), Some(2)));
+		assert!(!md1.is_degraded());
+		assert!(md1.sync().is_none());
+
+		let md0 = &arrays[1];
+		assert!(md0.is_degraded());
+		assert_eq!(md0.members().len(), 5);
+		assert_eq!((md0.members()[2].name(), md0.members()[2].slot()), ("sdb1", 1));
+		assert_eq!(md0.count(MemberRole::Failed), 1);
+		assert_eq!(md0.count(MemberRole::Spare), 1);
+		assert_eq!(md0.count(MemberRole::Active), 3);
+		let sync = md0.sync().unwrap();
+		assert_eq!(sync.action(), "recovery");
+		assert!((sync.progress() - 0.085).abs() < 1e-9);
+		assert_eq!(sync.finish().map(|finish| finish.get::<minute>()), Some(144.0));
+		assert_eq!(sync.speed().map(|speed| speed.get::<kilobyte_per_second>()), Some(206548.0));
+
+		let md127 = &arrays[2];
+		assert!(md127.read_only());
+		assert_eq!(md127.level(), Some("raid1"));
+		let sync = md127.sync().unwrap();
+		assert_eq!((sync.action(), sync.progress()), ("resync", 0.0));
+		assert!(sync.finish().is_none());
+
+		let md2 = &arrays[3];
+		assert!(!md2.is_active());
+		assert_eq!(md2.level(), None);
+		assert_eq!(md2.raid_disks(), None);
+		assert_eq!(md2.members()[0].role(), MemberRole::Spare);
+	}
+}
@@ -0,0 +1,102 @@
+//! Allocations of the metric hot path
+//!
+//! Run with `cargo bench -p prism-event`, the allocation profiler reports the
+//! number of allocations and bytes per iteration next to the timings.
+
+use divan::{AllocProfiler, Bencher, black_box};
+use prism_event::{
+	counter, gauge,
+	metric::{Gauge, Metric, MetricValue},
+	shared_tags,
+};
+
+#[global_allocator]
+static ALLOC: AllocProfiler = AllocProfiler::system();
+
+/// Samples of one disk, as emitted by the disk collector
+const SAMPLES: u64 = 20;
+
+fn main() {
+	divan::main();
+}
+
+mod value {
+	use super::*;
+
+	/// A live handle per sample, as gauges were built before
+	#[divan::bench]
+	fn handle() -> Gauge {
+		let gauge = Gauge::new();
+		gauge.set(black_box(42u64));
+		gauge
+	}
+
+	#[divan::bench]
+	fn plain() -> MetricValue {
+		MetricValue::Gauge(black_box(42.0))
+	}
+}
+
+mod tags {
+	use super::*;
+
+	#[divan::bench]
+	fn per_sample(bencher: Bencher) {
+		let device = String::from("nvme0n1");
+		let mut buffer = Vec::with_capacity(SAMPLES as usize);
+		bencher.bench_local(|| {
+			buffer.clear();
+			for value in 0..SAMPLES {
+				buffer.push(counter!("read_completed", value, "device" => device.clone(), "disk_type" => "disk", "parent" => ""));
+			}
+			black_box(&buffer);
+		});
+	}
+
+	#[divan::bench]
+	fn shared(bencher: Bencher) {
+		let device = String::from("nvme0n1");
+		let mut buffer = Vec::with_capacity(SAMPLES as usize);
+		bencher.bench_local(|| {
+			buffer.clear();
+			let tags =
+				shared_tags!("device" => device.clone(), "disk_type" => "disk", "parent" => "");
+			for value in 0..SAMPLES {
+				buffer.push(counter!("read_completed", value, tags = &tags));
+			}
+			black_box(&buffer);
+		});
+	}
+}
+
+mod buffer {
+	use super::*;
+
+	fn collect(buffer: &mut Vec<Metric>) {
+		let tags = shared_tags!("device" => "nvme0n1");
+		for value in 0..SAMPLES {
+			buffer.push(gauge!("ios_in_progress", value, tags = &tags));
+		}
+	}
+
+	/// A new buffer per collection, as with `mem::take`
+	#[divan::bench]
+	fn taken(bencher: Bencher) {
+		let mut buffer = Vec::new();
+		bencher.bench_local(|| {
+			collect(&mut buffer);
+			black_box(std::mem::take(&mut buffer));
+		});
+	}
+
+	/// The buffer handed back by the transport
+	#[divan::bench]
+	fn reused(bencher: Bencher) {
+		let mut buffer = Vec::new();
+		bencher.bench_local(|| {
+			buffer.clear();
+			collect(&mut buffer);
+			black_box(&buffer);
+		});
+	}
+}
@@ -0,0 +1,80 @@
+use crate::metric::String;
+use serde::{Serialize, Serializer, ser::SerializeMap};
+use std::collections::BTreeMap;
+
+/// Value of an event field
+#[derive(Serialize, Clone, Debug, PartialEq)]
+#[serde(untagged)]
+pub enum EventValue {
+	Int(i64),
+	UInt(u64),
+	Float(f64),
+	String(String),
+	Bool(bool),
+}
+
+macro_rules! impl_from {
+	($($ty:ty => $variant:ident),* $(,)?) => {
+		$(
+			impl From<$ty> for EventValue {
+				fn from(value: $ty) -> Self {
+					Self::$variant(value.into())
+				}
+			}
+		)*
+	};
+}
+
+impl_from!(
+	i32 => Int,
+	i64 => Int,
+	u8 => UInt,
+	u32 => UInt,
+	u64 => UInt,
+	f64 => Float,
+	bool => Bool,
+	&'static str => String,
+	std::string::String => String,
+	String => String,
+);
+
+/// Structured payload of an event
+#[derive(Default, Clone, Debug)]
+pub struct EventFields(pub(crate) BTreeMap<String, EventValue>);
+
+impl FromIterator<(String, EventValue)> for EventFields {
+	fn from_iter<I: IntoIterator<Item = (String, EventValue)>>(iter: I) -> Self {
+		EventFields(iter.into_iter().collect())
+	}
+}
+
+impl EventFields {
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	pub fn insert<K: Into<String>, V: Into<EventValue>>(&mut self, k: K, v: V) {
+		self.0.insert(k.into(), v.into());
+	}
+
+	pub fn get(&self, k: &str) -> Option<&EventValue> {
+		self.0.get(k)
+	}
+
+	pub fn is_empty(&self) -> bool {
+		self.0.is_empty()
+	}
+}
+
+impl Serialize for EventFields {
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		S: Serializer,
+	{
+		let mut map_serializer = serializer.serialize_map(Some(self.0.len()))?;
+		for (k, v) in &self.0 {
+			map_serializer.serialize_entry(&k, &v)?;
+		}
+		map_serializer.end()
+	}
+}
@@ -0,0 +1,125 @@
+use crate::metric::String;
+use crate::time::Timestamp;
+pub use fields::{EventFields, EventValue};
+use serde::Serialize;
+mod fields;
+
+/// Severity of an event, following the syslog levels.
+#[derive(Serialize, Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+	Emergency,
+	Alert,
+	Critical,
+	Error,
+	Warning,
+	Notice,
+	Info,
+	Debug,
+}
+
+impl Severity {
+	/// Maps a syslog level, `0` being the most severe. Levels above `7` are
+	/// treated as [`Severity::Debug`].
+	pub const fn from_level(level: u8) -> Self {
+		match level {
+			0 => Self::Emergency,
+			1 => Self::Alert,
+			2 => Self::Critical,
+			3 => Self::Error,
+			4 => Self::Warning,
+			5 => Self::Notice,
+			6 => Self::Info,
+			_ => Self::Debug,
+		}
+	}
+
+	pub const fn as_str(&self) -> &'static str {
+		match self {
+			Self::Emergency => "emergency",
+			Self::Alert => "alert",
+			Self::Critical => "critical",
+			Self::Error => "error",
+			Self::Warning => "warning",
+			Self::Notice => "notice",
+			Self::Info => "info",
+			Self::Debug => "debug",
+		}
+	}
+}
+
+/// A discrete occurrence, e.g. an OOM kill, as opposed to a sampled [`Metric`](crate::metric::Metric).
+#[derive(Serialize)]
+pub struct Event {
+	name: String,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	namespace: Option<String>,
+	severity: Severity,
+	message: String,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	fields: Option<EventFields>,
+	timestamp: Timestamp,
+}
+
+impl Event {
+	#[inline]
+	pub fn new<T: Into<String>, M: Into<String>>(name: T, severity: Severity, message: M) -> Self {
+		Self {
+			name: name.into(),
+			namespace: None,
+			severity,
+			message: message.into(),
+			fields: None,
+			timestamp: Timestamp::now(),
+		}
+	}
+
+	/// Consumes this event, returning it with the given `namespace`.
+	#[inline]
+	#[must_use]
+	pub fn with_namespace<T: Into<String>>(mut self, namespace: Option<T>) -> Self {
+		self.namespace = namespace.map(Into::into);
+		self
+	}
+
+	/// Consumes this event, returning it with the given `fields`.
+	#[inline]
+	#[must_use]
+	pub fn with_fields(mut self, fields: Option<EventFields>) -> Self {
+		self.fields = fields;
+		self
+	}
+
+	/// Consumes this event, returning it with the time it occurred at instead
+	/// of the time it was created.
+	#[inline]
+	#[must_use]
+	pub const fn with_timestamp(mut self, timestamp: Timestamp) -> Self {
+		self.timestamp = timestamp;
+		self
+	}
+
+	pub fn name(&self) -> &str {
+		&self.name
+	}
+
+	pub fn namespace(&self) -> Option<&str> {
+		self.namespace.as_deref()
+	}
+
+	pub const fn severity(&self) -> Severity {
+		self.severity
+	}
+
+	pub fn message(&self) -> &str {
+		&self.message
+	}
+
+	pub const fn fields(&self) -> Option<&EventFields> {
+		self.fields.as_ref()
+	}
+
+	pub const fn timestamp(&self) -> Timestamp {
+		self.timestamp
+	}
+}
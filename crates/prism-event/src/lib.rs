@@ -1,4 +1,6 @@
 mod atomics;
 mod macros;
 
+pub mod event;
 pub mod metric;
+pub mod time;
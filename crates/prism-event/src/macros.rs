@@ -15,43 +15,255 @@ macro_rules! metric_tags {
     };
 }
 
-/// Gauges represent a single value that can go up or down over time, and always starts out with an
-/// initial value of zero.
+/// Tags shared by every sample of a series, e.g. the device of a disk, built once per collection
+/// and passed to [`gauge!`] and [`counter!`] as `tags = &shared`.
+#[macro_export]
+macro_rules! shared_tags {
+    ($($key:expr => $value:expr),* $(,)?) => {
+        ::std::sync::Arc::new(
+            [$( ($key.into(), $crate::metric::String::from($value)), )*]
+            .into_iter().collect::<$crate::metric::MetricTags>()
+        )
+    };
+}
+
+/// Gauges represent a single value that can go up or down over time.
 ///
-/// Metrics can be registered, which provides a handle to directly update that metric.  For gauges,
-/// [`Gauge`](crate::metric::Gauge) is provided which can be incremented, decremented, or set to an absolute value.
+/// The value is converted to a plain `f64` when the sample is taken, live handles that can be
+/// updated between collections are provided by the [`Registry`](crate::metric::registry::Registry).
+///
+/// Tags are either given inline as `"key" => value` pairs or, when many samples share them, as
+/// `tags = &shared` with a set built once by [`shared_tags!`].
 ///
 /// Metric names are shown below using string literals, but they can also be owned `String` values,
 /// which includes using macros such as `format!` directly at the callsite. String literals are
 /// preferred for performance where possible.
 #[macro_export]
 macro_rules! gauge {
-    ($name:expr, $value:expr, $namespace:expr $(, $key:expr => $val:expr)* $(,)?) => {{
-        let tags = $crate::metric_tags!($($key => $val),*);
-        let mut gauge = $crate::metric::Gauge::new();
-        gauge.set($value);
+    ($name:expr, $value:expr, tags = $tags:expr) => {
+        $crate::gauge!($name, $value, ::std::path::Path::new(file!()).file_stem().and_then(|s| s.to_str()).unwrap_or("unknown"), tags = $tags)
+    };
+
+    ($name:expr, $value:expr, $namespace:expr, tags = $tags:expr) => {
         $crate::metric::Metric::new(
             $name,
-            $crate::metric::MetricValue::Gauge(gauge)
+            $crate::metric::MetricValue::Gauge($crate::metric::__into_f64($value))
         )
         .with_namespace($namespace.into())
-        .with_tags(tags)
-    }};
+        .with_shared_tags(::std::sync::Arc::clone($tags))
+    };
+
+    ($name:expr, $value:expr, $namespace:expr $(, $key:expr => $val:expr)* $(,)?) => {
+        $crate::metric::Metric::new(
+            $name,
+            $crate::metric::MetricValue::Gauge($crate::metric::__into_f64($value))
+        )
+        .with_namespace($namespace.into())
+        .with_tags($crate::metric_tags!($($key => $val),*))
+    };
 
     ($name:expr, $value:expr) => {
         $crate::gauge!($name, $value, ::std::path::Path::new(file!()).file_stem().and_then(|s| s.to_str()).unwrap_or("unknown"))
     };
 
-    ($name:expr, $value:expr, $namespace:expr) => {{
-        let mut gauge = $crate::metric::Gauge::new();
-        gauge.set($value);
+    ($name:expr, $value:expr $(, $key:expr => $val:expr)* $(,)?) => {
+        $crate::gauge!($name, $value, ::std::path::Path::new(file!()).file_stem().and_then(|s| s.to_str()).unwrap_or("unknown") $(, $key => $val)*)
+    };
+}
+
+/// Counters represent a single monotonic value, which means the value can only be incremented, not
+/// decremented.
+///
+/// The given value is the current total of the source, e.g. a cumulative kernel counter.  Encoders
+/// tell counters from gauges through [`MetricValue::kind`](crate::metric::MetricValue::kind).
+///
+/// The namespace defaults to the file stem of the callsite and tags are given like for [`gauge!`].
+#[macro_export]
+macro_rules! counter {
+    ($name:expr, $value:expr, tags = $tags:expr) => {
+        $crate::counter!($name, $value, ::std::path::Path::new(file!()).file_stem().and_then(|s| s.to_str()).unwrap_or("unknown"), tags = $tags)
+    };
+
+    ($name:expr, $value:expr, $namespace:expr, tags = $tags:expr) => {
         $crate::metric::Metric::new(
             $name,
-            $crate::metric::MetricValue::Gauge(gauge)
-        ).with_namespace($namespace.into())
+            $crate::metric::MetricValue::Counter($crate::metric::__into_f64($value))
+        )
+        .with_namespace($namespace.into())
+        .with_shared_tags(::std::sync::Arc::clone($tags))
+    };
+
+    ($name:expr, $value:expr, $namespace:expr $(, $key:expr => $val:expr)* $(,)?) => {
+        $crate::metric::Metric::new(
+            $name,
+            $crate::metric::MetricValue::Counter($crate::metric::__into_f64($value))
+        )
+        .with_namespace($namespace.into())
+        .with_tags($crate::metric_tags!($($key => $val),*))
+    };
+
+    ($name:expr, $value:expr) => {
+        $crate::counter!($name, $value, ::std::path::Path::new(file!()).file_stem().and_then(|s| s.to_str()).unwrap_or("unknown"))
+    };
+
+    ($name:expr, $value:expr $(, $key:expr => $val:expr)* $(,)?) => {
+        $crate::counter!($name, $value, ::std::path::Path::new(file!()).file_stem().and_then(|s| s.to_str()).unwrap_or("unknown") $(, $key => $val)*)
+    };
+}
+
+/// Histograms count observations, e.g. latencies, in buckets described by
+/// [`Buckets`](crate::metric::Buckets), either fixed upper bounds or base-2 exponential buckets.
+///
+/// Every item of `values` is recorded, use [`HistogramSnapshot::record_many`](crate::metric::HistogramSnapshot::record_many)
+/// directly for pre-aggregated counts.  The namespace defaults to the file stem of the callsite,
+/// like for [`gauge!`].
+#[macro_export]
+macro_rules! histogram {
+    ($name:expr, $buckets:expr, $values:expr, $namespace:expr $(, $key:expr => $val:expr)* $(,)?) => {{
+        let mut snapshot = $crate::metric::HistogramSnapshot::new(&$buckets);
+        for value in $values {
+            snapshot.record(value);
+        }
+        $crate::metric::Metric::new(
+            $name,
+            $crate::metric::MetricValue::Histogram(snapshot)
+        )
+        .with_namespace($namespace.into())
+        .with_tags($crate::metric_tags!($($key => $val),*))
     }};
 
+    ($name:expr, $buckets:expr, $values:expr) => {
+        $crate::histogram!($name, $buckets, $values, ::std::path::Path::new(file!()).file_stem().and_then(|s| s.to_str()).unwrap_or("unknown"))
+    };
+
+    ($name:expr, $buckets:expr, $values:expr $(, $key:expr => $val:expr)* $(,)?) => {
+        $crate::histogram!($name, $buckets, $values, ::std::path::Path::new(file!()).file_stem().and_then(|s| s.to_str()).unwrap_or("unknown") $(, $key => $val)*)
+    };
+}
+
+/// Summaries report the given quantiles, e.g. `&[0.5, 0.9, 0.99]`, of the recorded `values`
+/// together with their count and sum.
+///
+/// The namespace defaults to the file stem of the callsite, like for [`gauge!`].
+#[macro_export]
+macro_rules! summary {
+    ($name:expr, $quantiles:expr, $values:expr, $namespace:expr $(, $key:expr => $val:expr)* $(,)?) => {
+        $crate::metric::Metric::new(
+            $name,
+            $crate::metric::MetricValue::Summary($crate::metric::SummarySnapshot::from_values($quantiles, $values))
+        )
+        .with_namespace($namespace.into())
+        .with_tags($crate::metric_tags!($($key => $val),*))
+    };
+
+    ($name:expr, $quantiles:expr, $values:expr) => {
+        $crate::summary!($name, $quantiles, $values, ::std::path::Path::new(file!()).file_stem().and_then(|s| s.to_str()).unwrap_or("unknown"))
+    };
+
+    ($name:expr, $quantiles:expr, $values:expr $(, $key:expr => $val:expr)* $(,)?) => {
+        $crate::summary!($name, $quantiles, $values, ::std::path::Path::new(file!()).file_stem().and_then(|s| s.to_str()).unwrap_or("unknown") $(, $key => $val)*)
+    };
+}
+
+/// Info metrics are a constant `1` whose information is carried by the tags, e.g. the kernel
+/// version or the CPU model of the host.
+///
+/// The namespace defaults to the file stem of the callsite, like for [`gauge!`].
+#[macro_export]
+macro_rules! info_metric {
+    ($name:expr, namespace = $namespace:expr $(, $key:expr => $val:expr)* $(,)?) => {
+        $crate::metric::Metric::new($name, $crate::metric::MetricValue::Info)
+            .with_namespace(::std::option::Option::Some($namespace))
+            .with_tags($crate::metric_tags!($($key => $val),*))
+    };
+
+    ($name:expr $(, $key:expr => $val:expr)* $(,)?) => {
+        $crate::info_metric!($name, namespace = ::std::path::Path::new(file!()).file_stem().and_then(|s| s.to_str()).unwrap_or("unknown") $(, $key => $val)*)
+    };
+}
+
+/// State sets report which of the given `states` is the `current` one, e.g. the operational
+/// state of an interface.
+///
+/// States that can be set together, e.g. an array both degraded and syncing, are given as
+/// `(state, set)` pairs instead, with the tags shared through `tags = &shared`.
+///
+/// The namespace defaults to the file stem of the callsite, like for [`gauge!`].
+#[macro_export]
+macro_rules! state_set {
+    ($name:expr, $states:expr, tags = $tags:expr) => {
+        $crate::state_set!($name, $states, namespace = ::std::path::Path::new(file!()).file_stem().and_then(|s| s.to_str()).unwrap_or("unknown"), tags = $tags)
+    };
+
+    ($name:expr, $states:expr, namespace = $namespace:expr, tags = $tags:expr) => {
+        $crate::metric::Metric::new(
+            $name,
+            $crate::metric::MetricValue::StateSet($crate::metric::StateSet::new($states))
+        )
+        .with_namespace(::std::option::Option::Some($namespace))
+        .with_shared_tags(::std::sync::Arc::clone($tags))
+    };
+
+    ($name:expr, $states:expr, $current:expr, namespace = $namespace:expr $(, $key:expr => $val:expr)* $(,)?) => {
+        $crate::metric::Metric::new(
+            $name,
+            $crate::metric::MetricValue::StateSet($crate::metric::StateSet::one_of($states, $current))
+        )
+        .with_namespace(::std::option::Option::Some($namespace))
+        .with_tags($crate::metric_tags!($($key => $val),*))
+    };
+
+    ($name:expr, $states:expr, $current:expr $(, $key:expr => $val:expr)* $(,)?) => {
+        $crate::state_set!($name, $states, $current, namespace = ::std::path::Path::new(file!()).file_stem().and_then(|s| s.to_str()).unwrap_or("unknown") $(, $key => $val)*)
+    };
+}
+
+/// String metrics carry a plain text value, e.g. a firmware version that is not worth a tag.
+///
+/// The namespace defaults to the file stem of the callsite, like for [`gauge!`].
+#[macro_export]
+macro_rules! string_metric {
+    ($name:expr, $value:expr, namespace = $namespace:expr $(, $key:expr => $val:expr)* $(,)?) => {
+        $crate::metric::Metric::new(
+            $name,
+            $crate::metric::MetricValue::String($crate::metric::String::from($value))
+        )
+        .with_namespace(::std::option::Option::Some($namespace))
+        .with_tags($crate::metric_tags!($($key => $val),*))
+    };
+
     ($name:expr, $value:expr $(, $key:expr => $val:expr)* $(,)?) => {
-        $crate::gauge!($name, $value, ::std::path::Path::new(file!()).file_stem().and_then(|s| s.to_str()).unwrap_or("unknown") $(, $key => $val)*)
+        $crate::string_metric!($name, $value, namespace = ::std::path::Path::new(file!()).file_stem().and_then(|s| s.to_str()).unwrap_or("unknown") $(, $key => $val)*)
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! event_fields {
+    () => {
+        None
+    };
+    ($($key:expr => $value:expr),* $(,)?) => {
+        Some(
+            [$( ($crate::metric::String::from($key), $crate::event::EventValue::from($value)), )*]
+            .into_iter().collect::<$crate::event::EventFields>()
+        )
+    };
+}
+
+/// Events record something that happened at a point in time, e.g. a process killed by the OOM
+/// killer, with a severity, a human readable message and structured fields.
+///
+/// The namespace defaults to the file stem of the callsite, like for [`gauge!`].
+#[macro_export]
+macro_rules! event {
+    ($name:expr, $severity:expr, $message:expr, namespace = $namespace:expr $(, $key:expr => $val:expr)* $(,)?) => {
+        $crate::event::Event::new($name, $severity, $message)
+            .with_namespace(::std::option::Option::Some($namespace))
+            .with_fields($crate::event_fields!($($key => $val),*))
+    };
+
+    ($name:expr, $severity:expr, $message:expr $(, $key:expr => $val:expr)* $(,)?) => {
+        $crate::event!($name, $severity, $message, namespace = ::std::path::Path::new(file!()).file_stem().and_then(|s| s.to_str()).unwrap_or("unknown") $(, $key => $val)*)
     };
 }
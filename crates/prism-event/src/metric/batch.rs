@@ -0,0 +1,237 @@
+//! Columnar batch of metrics
+//!
+//! A [`MetricBatch`] holds the samples of one collection. Every sample shares
+//! the timestamp of the batch, the name, namespace and tags of a series are
+//! stored once in the series table and samples only refer to it by index.
+//! Values are kept in their own column, next to the series indexes, which is
+//! what columnar formats and delta encoding of successive batches expect.
+//!
+//! A batch is meant to be [cleared](MetricBatch::clear) and filled again by
+//! each collection. The series table outlives the samples, so that a series
+//! keeps its index from one collection to the next and is only looked up,
+//! not copied, once known.
+
+use super::{Metric, MetricTags, MetricValue, String, descriptor::MetricDescriptor};
+use crate::time::Timestamp;
+use serde::Serialize;
+use std::{collections::BTreeMap, sync::Arc};
+
+/// Name, namespace and tags identifying a series within a batch.
+#[derive(Clone, Debug, Serialize)]
+pub struct Series {
+	name: String,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	namespace: Option<String>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	tags: Option<Arc<MetricTags>>,
+	#[serde(skip)]
+	descriptor: Option<&'static MetricDescriptor>,
+}
+
+impl Series {
+	pub fn name(&self) -> &str {
+		&self.name
+	}
+
+	pub fn namespace(&self) -> Option<&str> {
+		self.namespace.as_deref()
+	}
+
+	pub fn tags(&self) -> Option<&MetricTags> {
+		self.tags.as_deref()
+	}
+
+	/// Descriptor of the first sample of the series.
+	pub const fn descriptor(&self) -> Option<&'static MetricDescriptor> {
+		self.descriptor
+	}
+}
+
+type SeriesKey = (String, Option<String>, Option<Arc<MetricTags>>);
+
+/// Samples of one collection, see the [module](self) documentation.
+#[derive(Clone, Debug, Serialize)]
+pub struct MetricBatch {
+	timestamp: Timestamp,
+	series: Vec<Series>,
+	#[serde(skip)]
+	index: BTreeMap<SeriesKey, u32>,
+	/// Series of each sample, as an index into `series`
+	series_ids: Vec<u32>,
+	/// Value of each sample
+	values: Vec<MetricValue>,
+}
+
+impl Default for MetricBatch {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl MetricBatch {
+	/// Creates an empty batch timestamped now.
+	pub fn new() -> Self {
+		Self {
+			timestamp: Timestamp::now(),
+			series: Vec::new(),
+			index: BTreeMap::new(),
+			series_ids: Vec::new(),
+			values: Vec::new(),
+		}
+	}
+
+	/// Removes every sample and timestamps the batch now, keeping the
+	/// allocated columns to be filled again. The series of the removed samples
+	/// stay in the table with their index, while those that had no sample are
+	/// dropped, shifting the index of the series after them.
+	pub fn clear(&mut self) {
+		self.timestamp = Timestamp::now();
+		let mut used = vec![false; self.series.len()];
+		for id in &self.series_ids {
+			used[*id as usize] = true;
+		}
+		if used.contains(&false) {
+			// New index of each kept series
+			let ids: Vec<u32> = used
+				.iter()
+				.scan(0, |next, used| {
+					let id = *next;
+					*next += u32::from(*used);
+					Some(id)
+				})
+				.collect();
+			let mut kept = used.iter();
+			self.series.retain(|_| kept.next().copied().unwrap_or_default());
+			self.index.retain(|_, id| used[*id as usize]);
+			for id in self.index.values_mut() {
+				*id = ids[*id as usize];
+			}
+		}
+		self.series_ids.clear();
+		self.values.clear();
+	}
+
+	/// Adds the sample of `metric`, whose own timestamp is replaced by the one
+	/// of the batch.
+	pub fn push(&mut self, metric: Metric) {
+		let Metric { name, namespace, tags, value, descriptor, .. } = metric;
+		let key = (name, namespace, tags);
+		let id = match self.index.get(&key) {
+			Some(id) => *id,
+			None => {
+				let id = self.series.len() as u32;
+				let (name, namespace, tags) = key.clone();
+				self.series.push(Series { name, namespace, tags, descriptor });
+				self.index.insert(key, id);
+				id
+			},
+		};
+		self.series_ids.push(id);
+		self.values.push(value);
+	}
+
+	pub const fn timestamp(&self) -> Timestamp {
+		self.timestamp
+	}
+
+	/// Number of samples.
+	pub fn len(&self) -> usize {
+		self.values.len()
+	}
+
+	pub fn is_empty(&self) -> bool {
+		self.values.is_empty()
+	}
+
+	/// Series table, each series appearing once however many samples it has.
+	/// After a [`clear`](Self::clear), it also holds the series of the
+	/// previous collection that have no sample yet.
+	pub fn series(&self) -> &[Series] {
+		&self.series
+	}
+
+	/// Series of each sample, as an index into [`series`](Self::series).
+	pub fn series_ids(&self) -> &[u32] {
+		&self.series_ids
+	}
+
+	/// Value of each sample.
+	pub fn values(&self) -> &[MetricValue] {
+		&self.values
+	}
+
+	/// Samples in the order they were pushed.
+	pub fn iter(&self) -> impl Iterator<Item = (&Series, &MetricValue)> {
+		self.series_ids.iter().map(|id| &self.series[*id as usize]).zip(&self.values)
+	}
+}
+
+impl Extend<Metric> for MetricBatch {
+	fn extend<I: IntoIterator<Item = Metric>>(&mut self, iter: I) {
+		for metric in iter {
+			self.push(metric);
+		}
+	}
+}
+
+impl FromIterator<Metric> for MetricBatch {
+	fn from_iter<I: IntoIterator<Item = Metric>>(iter: I) -> Self {
+		let mut batch = Self::new();
+		batch.extend(iter);
+		batch
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{counter, gauge, shared_tags};
+
+	#[test]
+	fn test_series() {
+		let tags = shared_tags!("device" => "sda");
+		let batch: MetricBatch = [
+			counter!("read_completed", 1u64, tags = &tags),
+			counter!("write_completed", 2u64, tags = &tags),
+			counter!("read_completed", 3u64, "device" => "sda"),
+			counter!("read_completed", 4u64, "device" => "sdb"),
+			gauge!("procs_running", 5u64),
+		]
+		.into_iter()
+		.collect();
+
+		assert_eq!(batch.len(), 5);
+		assert_eq!(batch.series().len(), 4);
+		assert_eq!(batch.series_ids(), &[0, 1, 0, 2, 3]);
+		let (series, value) = batch.iter().nth(3).unwrap();
+		assert_eq!(series.tags().and_then(|tags| tags.get("device")), Some("sdb"));
+		assert_eq!(value.as_f64(), Some(4.0));
+		assert_eq!(batch.series()[3].namespace(), Some("batch"));
+	}
+
+	#[test]
+	fn test_clear() {
+		let tags = shared_tags!("device" => "sda");
+		let mut batch = MetricBatch::new();
+		batch.push(gauge!("procs_running", 5u64));
+		batch.push(counter!("read_completed", 1u64, tags = &tags));
+		batch.push(counter!("write_completed", 2u64, tags = &tags));
+		assert_eq!(batch.series_ids(), &[0, 1, 2]);
+
+		// A series keeps its index from one collection to the next
+		batch.clear();
+		assert!(batch.is_empty());
+		batch.push(counter!("write_completed", 3u64, "device" => "sda"));
+		batch.push(counter!("read_completed", 4u64, tags = &tags));
+		assert_eq!(batch.series_ids(), &[2, 1]);
+		assert_eq!(batch.series().len(), 3);
+
+		// and is dropped once a collection went without it
+		batch.clear();
+		assert_eq!(batch.series().len(), 2);
+		batch.push(counter!("read_completed", 5u64, tags = &tags));
+		batch.push(gauge!("procs_running", 6u64));
+		assert_eq!(batch.series_ids(), &[0, 2]);
+		assert_eq!(batch.series()[2].name(), "procs_running");
+	}
+}
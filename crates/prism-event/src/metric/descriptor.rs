@@ -0,0 +1,260 @@
+//! Static description of a series
+//!
+//! A [`MetricDescriptor`] carries what does not change from one sample to the
+//! next, the unit, kind, description and stability of a series. Collectors
+//! declare them once as `static`s and attach them to their own samples with
+//! [`Metric::with_descriptor`](super::Metric::with_descriptor), [`Descriptors`]
+//! looking them up by name. Encoders then use them to emit `# HELP`, `# UNIT`
+//! and `# TYPE` lines or the OTLP unit and description fields.
+//!
+//! Descriptors are not looked up globally: a series of the same name from
+//! another collector or from an application is not described by them.
+
+use super::Metric;
+use std::collections::BTreeMap;
+
+/// Unit of the values of a series, after the conversion done at the call site,
+/// e.g. `get::<millisecond>()`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Unit {
+	/// Plain count or identifier
+	#[default]
+	None,
+	Seconds,
+	Milliseconds,
+	Bytes,
+	/// 1024 bytes, as reported by `/proc/meminfo`
+	Kibibytes,
+	BytesPerSecond,
+	MegabitsPerSecond,
+	Pages,
+	/// Fraction between 0 and 1
+	Ratio,
+	Percent,
+	Hertz,
+	Celsius,
+	Volts,
+	Watts,
+	/// Fan speed
+	RevolutionsPerMinute,
+}
+
+impl Unit {
+	/// Name of the unit as used in OpenMetrics `# UNIT` lines and metric name suffixes,
+	/// empty for [`Unit::None`].
+	pub const fn as_str(&self) -> &'static str {
+		match self {
+			Self::None => "",
+			Self::Seconds => "seconds",
+			Self::Milliseconds => "milliseconds",
+			Self::Bytes => "bytes",
+			Self::Kibibytes => "kibibytes",
+			Self::BytesPerSecond => "bytes_per_second",
+			Self::MegabitsPerSecond => "megabits_per_second",
+			Self::Pages => "pages",
+			Self::Ratio => "ratio",
+			Self::Percent => "percent",
+			Self::Hertz => "hertz",
+			Self::Celsius => "celsius",
+			Self::Volts => "volts",
+			Self::Watts => "watts",
+			Self::RevolutionsPerMinute => "revolutions_per_minute",
+		}
+	}
+
+	/// [UCUM](https://ucum.org) code of the unit, as expected by OpenTelemetry.
+	pub const fn ucum(&self) -> &'static str {
+		match self {
+			Self::None | Self::Ratio => "1",
+			Self::Seconds => "s",
+			Self::Milliseconds => "ms",
+			Self::Bytes => "By",
+			Self::Kibibytes => "KiBy",
+			Self::BytesPerSecond => "By/s",
+			Self::MegabitsPerSecond => "Mbit/s",
+			Self::Pages => "{page}",
+			Self::Percent => "%",
+			Self::Hertz => "Hz",
+			Self::Celsius => "Cel",
+			Self::Volts => "V",
+			Self::Watts => "W",
+			Self::RevolutionsPerMinute => "{revolution}/min",
+		}
+	}
+}
+
+/// Kind of a series, matching the [`MetricValue`](super::MetricValue) variants.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MetricKind {
+	Counter,
+	Gauge,
+	Histogram,
+	Summary,
+	Info,
+	StateSet,
+	String,
+}
+
+impl MetricKind {
+	/// Name of the kind, e.g. for the `# TYPE` line of Prometheus.
+	pub const fn as_str(&self) -> &'static str {
+		match self {
+			Self::Counter => "counter",
+			Self::Gauge => "gauge",
+			Self::Histogram => "histogram",
+			Self::Summary => "summary",
+			Self::Info => "info",
+			Self::StateSet => "stateset",
+			Self::String => "string",
+		}
+	}
+}
+
+/// Whether dashboards and alerts can rely on a series.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Stability {
+	/// Name, unit and tags may still change
+	#[default]
+	Experimental,
+	/// Name, unit and tags only change with a major version
+	Stable,
+	/// Kept for compatibility, to be removed
+	Deprecated,
+}
+
+impl Stability {
+	pub const fn as_str(&self) -> &'static str {
+		match self {
+			Self::Experimental => "experimental",
+			Self::Stable => "stable",
+			Self::Deprecated => "deprecated",
+		}
+	}
+}
+
+/// Static description of a series, see the [module](self) documentation.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MetricDescriptor {
+	name: &'static str,
+	kind: MetricKind,
+	unit: Unit,
+	description: &'static str,
+	stability: Stability,
+}
+
+impl MetricDescriptor {
+	/// Creates an experimental descriptor without unit nor description.
+	pub const fn new(name: &'static str, kind: MetricKind) -> Self {
+		Self { name, kind, unit: Unit::None, description: "", stability: Stability::Experimental }
+	}
+
+	#[must_use]
+	pub const fn with_unit(mut self, unit: Unit) -> Self {
+		self.unit = unit;
+		self
+	}
+
+	#[must_use]
+	pub const fn with_description(mut self, description: &'static str) -> Self {
+		self.description = description;
+		self
+	}
+
+	#[must_use]
+	pub const fn with_stability(mut self, stability: Stability) -> Self {
+		self.stability = stability;
+		self
+	}
+
+	pub const fn name(&self) -> &'static str {
+		self.name
+	}
+
+	pub const fn kind(&self) -> MetricKind {
+		self.kind
+	}
+
+	pub const fn unit(&self) -> Unit {
+		self.unit
+	}
+
+	pub const fn description(&self) -> &'static str {
+		self.description
+	}
+
+	pub const fn stability(&self) -> Stability {
+		self.stability
+	}
+}
+
+/// Descriptors of the series of one collector, by metric name. A name keeps
+/// the first descriptor given for it.
+#[derive(Clone, Debug, Default)]
+pub struct Descriptors(BTreeMap<&'static str, &'static MetricDescriptor>);
+
+impl Descriptors {
+	pub fn new(descriptors: &'static [MetricDescriptor]) -> Self {
+		let mut map = BTreeMap::new();
+		for descriptor in descriptors {
+			map.entry(descriptor.name).or_insert(descriptor);
+		}
+		Self(map)
+	}
+
+	/// Descriptor of the metric `name`.
+	pub fn get(&self, name: &str) -> Option<&'static MetricDescriptor> {
+		self.0.get(name).copied()
+	}
+
+	/// Describes `metric` by the descriptor of its name, unless it already
+	/// has a descriptor.
+	pub fn describe(&self, metric: &mut Metric) {
+		if metric.descriptor.is_none() {
+			metric.descriptor = self.get(&metric.name);
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	static DESCRIPTORS: [MetricDescriptor; 2] = [
+		MetricDescriptor::new("test_read_time", MetricKind::Counter)
+			.with_unit(Unit::Milliseconds)
+			.with_description("Time spent reading")
+			.with_stability(Stability::Stable),
+		MetricDescriptor::new("test_procs_running", MetricKind::Gauge),
+	];
+
+	#[test]
+	fn test_descriptors() {
+		let descriptors = Descriptors::new(&DESCRIPTORS);
+		let descriptor = descriptors.get("test_read_time").unwrap();
+		assert_eq!(descriptor.unit().ucum(), "ms");
+		assert_eq!(descriptor.kind().as_str(), "counter");
+		assert_eq!(descriptor.description(), "Time spent reading");
+		assert_eq!(
+			descriptors.get("test_procs_running").unwrap().stability(),
+			Stability::Experimental
+		);
+		assert!(descriptors.get("test_unknown").is_none());
+
+		let mut metric = crate::gauge!("test_read_time", 1.0);
+		// only described samples carry a descriptor
+		assert!(metric.descriptor().is_none());
+		descriptors.describe(&mut metric);
+		assert_eq!(metric.descriptor(), Some(&DESCRIPTORS[0]));
+
+		static OWN: MetricDescriptor = MetricDescriptor::new("test_read_time", MetricKind::Gauge);
+		let mut metric = crate::gauge!("test_read_time", 1.0).with_descriptor(&OWN);
+		descriptors.describe(&mut metric);
+		assert_eq!(metric.descriptor(), Some(&OWN));
+
+		static TWICE: [MetricDescriptor; 2] = [
+			MetricDescriptor::new("test_read_time", MetricKind::Counter),
+			MetricDescriptor::new("test_read_time", MetricKind::Gauge),
+		];
+		assert_eq!(Descriptors::new(&TWICE).get("test_read_time"), Some(&TWICE[0]));
+	}
+}
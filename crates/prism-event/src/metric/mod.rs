@@ -1,12 +1,21 @@
+mod batch;
+pub mod descriptor;
+pub mod registry;
+mod static_tags;
 mod tags;
 mod value;
-use chrono::{DateTime, Local};
+use crate::time::Timestamp;
+pub use batch::{MetricBatch, Series};
 use cow::Cow;
+use descriptor::MetricDescriptor;
 use serde::Serialize;
+pub use static_tags::{StaticTags, add_global_tags, add_namespace_tags, intern, static_tags};
+use std::sync::Arc;
 pub use tags::MetricTags;
 pub use value::*;
 mod common;
-use bytes::BytesMut;
+#[doc(hidden)]
+pub use common::__into_f64;
 pub(in crate::metric) use common::IntoF64;
 
 pub type String = Cow<'static, str>;
@@ -17,15 +26,24 @@ pub struct Metric {
 	#[serde(skip_serializing_if = "Option::is_none")]
 	namespace: Option<String>,
 	#[serde(skip_serializing_if = "Option::is_none")]
-	tags: Option<MetricTags>,
-	timestamp: DateTime<Local>,
+	tags: Option<Arc<MetricTags>>,
+	timestamp: Timestamp,
 	value: MetricValue,
+	#[serde(skip)]
+	descriptor: Option<&'static MetricDescriptor>,
 }
 
 impl Metric {
 	#[inline]
 	pub fn new<T: Into<String>>(name: T, value: MetricValue) -> Self {
-		Self { name: name.into(), namespace: None, tags: None, timestamp: Local::now(), value }
+		Self {
+			name: name.into(),
+			namespace: None,
+			tags: None,
+			timestamp: Timestamp::now(),
+			value,
+			descriptor: None,
+		}
 	}
 
 	/// Consumes this metric, returning it with an updated series based on the given `namespace`.
@@ -40,30 +58,57 @@ impl Metric {
 	#[inline]
 	#[must_use]
 	pub fn with_tags(mut self, tags: Option<MetricTags>) -> Self {
-		self.tags = tags;
+		self.tags = tags.map(Arc::new);
 		self
 	}
-}
 
-impl Metric {
+	/// Consumes this metric, returning it with tags shared with the other samples of the series,
+	/// see [`shared_tags!`](crate::shared_tags).
 	#[inline]
-	pub fn encode(&self, o: &mut BytesMut) -> Result<(), std::io::Error> {
-		let value = serde_json::to_string(&self.value)
-			.map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))?;
+	#[must_use]
+	pub fn with_shared_tags(mut self, tags: Arc<MetricTags>) -> Self {
+		self.tags = Some(tags);
+		self
+	}
 
-		let tag = self
-			.tags
-			.as_ref()
-			.map(|t| serde_json::to_string(t).unwrap_or_else(|_| std::string::String::new()))
-			.unwrap_or_else(|| std::string::String::new())
-			.replace(',', ";");
+	/// Consumes this metric, returning it described by `descriptor`.
+	#[inline]
+	#[must_use]
+	pub const fn with_descriptor(mut self, descriptor: &'static MetricDescriptor) -> Self {
+		self.descriptor = Some(descriptor);
+		self
+	}
 
-		let namespace = self.namespace.as_ref().map(|s| s.as_ref()).unwrap_or("");
-		let timestamp = self.timestamp.format("%Y-%m-%d %H:%M:%S%.3f").to_string();
+	pub fn name(&self) -> &str {
+		&self.name
+	}
 
-		let csv_line = format!("{},{},{},{},{}", self.name, namespace, value, tag, timestamp);
+	pub fn namespace(&self) -> Option<&str> {
+		self.namespace.as_deref()
+	}
+
+	pub fn tags(&self) -> Option<&MetricTags> {
+		self.tags.as_deref()
+	}
+
+	pub const fn timestamp(&self) -> Timestamp {
+		self.timestamp
+	}
+
+	pub const fn value(&self) -> &MetricValue {
+		&self.value
+	}
+
+	/// Unit, description and kind of the series, from [`with_descriptor`](Self::with_descriptor).
+	pub const fn descriptor(&self) -> Option<&'static MetricDescriptor> {
+		self.descriptor
+	}
 
-		o.extend_from_slice(csv_line.as_bytes());
-		Ok(())
+	/// Adds the given `tags` to this metric, keeping the value of the tags it already has.
+	pub fn merge_tags(&mut self, tags: &MetricTags) {
+		let own = Arc::make_mut(self.tags.get_or_insert_with(Default::default));
+		for (k, v) in &tags.0 {
+			own.0.entry(k.clone()).or_insert_with(|| v.clone());
+		}
 	}
 }
@@ -0,0 +1,348 @@
+//! Application metrics
+//!
+//! Services embedding prism register their own instruments in a [`Registry`]
+//! by name and tags, then update them from anywhere through the returned
+//! handles. [`Registry::snapshot`] copies the current values into [`Metric`]s,
+//! which the metric collector ships along with the host metrics.
+//!
+//! The [global](global) registry is always collected, scoped registries are
+//! added to the collector by their owner. A registry also implements
+//! [`metrics::Recorder`], so that code instrumented with the `metrics` macros
+//! only needs `metrics::set_global_recorder(registry::global())`.
+
+use super::{
+	Buckets, Counter, CounterFn, Gauge, GaugeFn, Histogram, HistogramFn, HistogramSnapshot, Metric,
+	MetricTags, MetricValue, String,
+	descriptor::{MetricDescriptor, MetricKind, Unit},
+	intern,
+};
+use crate::atomics::AtomicU64;
+use std::{
+	collections::BTreeMap,
+	sync::{Arc, LazyLock, Mutex},
+};
+
+/// Buckets of the histograms registered through [`metrics::Recorder`], each
+/// one about 9% wider than the previous one
+const DEFAULT_BUCKETS: Buckets = Buckets::Exponential { scale: 3 };
+
+static GLOBAL: LazyLock<Arc<Registry>> = LazyLock::new(|| Arc::new(Registry::new("app")));
+
+/// Registry collected by default, with the `app` namespace.
+pub fn global() -> Arc<Registry> {
+	Arc::clone(&GLOBAL)
+}
+
+#[derive(Clone)]
+enum Instrument {
+	Counter(Arc<AtomicU64>),
+	Gauge(Arc<AtomicU64>),
+	Histogram(Arc<Mutex<HistogramSnapshot>>),
+}
+
+impl Instrument {
+	const fn kind(&self) -> MetricKind {
+		match self {
+			Self::Counter(_) => MetricKind::Counter,
+			Self::Gauge(_) => MetricKind::Gauge,
+			Self::Histogram(_) => MetricKind::Histogram,
+		}
+	}
+
+	fn value(&self) -> MetricValue {
+		match self {
+			Self::Counter(counter) => MetricValue::Counter(CounterFn::value(&**counter)),
+			Self::Gauge(gauge) => MetricValue::Gauge(GaugeFn::value(&**gauge)),
+			Self::Histogram(histogram) => MetricValue::Histogram(histogram.snapshot()),
+		}
+	}
+}
+
+/// Series key, tags are sorted by name. Its strings are shared with the
+/// samples of the series, not interned, so that the values of tags with many
+/// values, e.g. request paths, are only kept by their series.
+type Key = (String, Vec<(String, String)>);
+
+/// Instruments registered by name and tags, see the [module](self) documentation.
+pub struct Registry {
+	namespace: String,
+	instruments: Mutex<BTreeMap<Key, Instrument>>,
+	/// Descriptors of the series described through [`metrics::Recorder`], by
+	/// name
+	descriptors: Mutex<BTreeMap<String, &'static MetricDescriptor>>,
+}
+
+impl Registry {
+	/// Creates an empty registry whose metrics have the given `namespace`.
+	pub fn new(namespace: &str) -> Self {
+		Self {
+			namespace: intern(namespace),
+			instruments: Mutex::new(BTreeMap::new()),
+			descriptors: Mutex::new(BTreeMap::new()),
+		}
+	}
+
+	fn key<'a>(name: &str, tags: impl IntoIterator<Item = (&'a str, &'a str)>) -> Key {
+		let tags: BTreeMap<_, _> = tags.into_iter().collect();
+		let shared = |value: &str| String::from(Arc::<str>::from(value));
+		(shared(name), tags.into_iter().map(|(k, v)| (shared(k), shared(v))).collect())
+	}
+
+	/// Instrument of `key`, created by `create` on first use. `None` when the
+	/// series is already registered with another kind.
+	fn register(
+		&self,
+		key: Key,
+		kind: MetricKind,
+		create: impl FnOnce() -> Instrument,
+	) -> Option<Instrument> {
+		let mut instruments = self.instruments.lock().unwrap_or_else(|e| e.into_inner());
+		let instrument = instruments.entry(key).or_insert_with(create);
+		(instrument.kind() == kind).then(|| instrument.clone())
+	}
+
+	/// Counter of the series `name` and `tags`, registered on first use. A
+	/// series already registered with another kind gets a counter that does
+	/// nothing.
+	pub fn counter(&self, name: &str, tags: &[(&str, &str)]) -> Counter {
+		match self.register(Self::key(name, tags.iter().copied()), MetricKind::Counter, || {
+			Instrument::Counter(Arc::new(AtomicU64::new(0)))
+		}) {
+			Some(Instrument::Counter(counter)) => Counter::from_arc(counter),
+			_ => Counter::default(),
+		}
+	}
+
+	/// Gauge of the series `name` and `tags`, see [`counter`](Self::counter).
+	pub fn gauge(&self, name: &str, tags: &[(&str, &str)]) -> Gauge {
+		match self.register(Self::key(name, tags.iter().copied()), MetricKind::Gauge, || {
+			Instrument::Gauge(Arc::new(AtomicU64::new(0)))
+		}) {
+			Some(Instrument::Gauge(gauge)) => Gauge::from_arc(gauge),
+			_ => Gauge::default(),
+		}
+	}
+
+	/// Histogram of the series `name` and `tags`, see [`counter`](Self::counter).
+	/// `buckets` only apply when the series is created.
+	pub fn histogram(&self, name: &str, buckets: Buckets, tags: &[(&str, &str)]) -> Histogram {
+		match self.register(Self::key(name, tags.iter().copied()), MetricKind::Histogram, || {
+			Instrument::Histogram(Arc::new(Mutex::new(HistogramSnapshot::new(&buckets))))
+		}) {
+			Some(Instrument::Histogram(histogram)) => Histogram::from_arc(histogram),
+			_ => Histogram::default(),
+		}
+	}
+
+	/// Current value of every registered series.
+	pub fn snapshot(&self) -> Vec<Metric> {
+		let instruments = self.instruments.lock().unwrap_or_else(|e| e.into_inner());
+		let descriptors = self.descriptors.lock().unwrap_or_else(|e| e.into_inner());
+		instruments
+			.iter()
+			.map(|((name, tags), instrument)| {
+				let tags = (!tags.is_empty()).then(|| tags.iter().cloned().collect::<MetricTags>());
+				let metric = Metric::new(name.clone(), instrument.value())
+					.with_namespace(Some(self.namespace.clone()))
+					.with_tags(tags);
+				match descriptors.get(name) {
+					Some(descriptor) => metric.with_descriptor(descriptor),
+					None => metric,
+				}
+			})
+			.collect()
+	}
+
+	/// Describes the series `name` through the `metrics` macros. Descriptors
+	/// are static, so the first description of a series is leaked and kept,
+	/// later ones are ignored. They only describe the series of this registry,
+	/// not a host series of the same name.
+	fn describe(&self, name: &metrics::KeyName, kind: MetricKind, unit: Unit, description: &str) {
+		let mut descriptors = self.descriptors.lock().unwrap_or_else(|e| e.into_inner());
+		if descriptors.contains_key(name.as_str()) {
+			return;
+		}
+		let name: &'static str = Box::leak(name.as_str().to_owned().into_boxed_str());
+		let description = Box::leak(description.to_owned().into_boxed_str());
+		let descriptor =
+			MetricDescriptor::new(name, kind).with_unit(unit).with_description(description);
+		descriptors.insert(String::from(name), Box::leak(Box::new(descriptor)));
+	}
+}
+
+struct RecorderCounter(Arc<AtomicU64>);
+
+impl metrics::CounterFn for RecorderCounter {
+	fn increment(&self, value: u64) {
+		CounterFn::increment(&*self.0, value as f64);
+	}
+
+	fn absolute(&self, value: u64) {
+		CounterFn::absolute(&*self.0, value as f64);
+	}
+}
+
+struct RecorderGauge(Arc<AtomicU64>);
+
+impl metrics::GaugeFn for RecorderGauge {
+	fn increment(&self, value: f64) {
+		GaugeFn::increment(&*self.0, value);
+	}
+
+	fn decrement(&self, value: f64) {
+		GaugeFn::decrement(&*self.0, value);
+	}
+
+	fn set(&self, value: f64) {
+		GaugeFn::set(&*self.0, value);
+	}
+}
+
+struct RecorderHistogram(Arc<Mutex<HistogramSnapshot>>);
+
+impl metrics::HistogramFn for RecorderHistogram {
+	fn record(&self, value: f64) {
+		self.0.record_many(value, 1);
+	}
+
+	fn record_many(&self, value: f64, count: usize) {
+		self.0.record_many(value, count as u64);
+	}
+}
+
+const fn unit(unit: Option<metrics::Unit>) -> Unit {
+	match unit {
+		Some(metrics::Unit::Seconds) => Unit::Seconds,
+		Some(metrics::Unit::Milliseconds) => Unit::Milliseconds,
+		Some(metrics::Unit::Bytes) => Unit::Bytes,
+		Some(metrics::Unit::Kibibytes) => Unit::Kibibytes,
+		Some(metrics::Unit::Percent) => Unit::Percent,
+		Some(metrics::Unit::MegabitsPerSecond) => Unit::MegabitsPerSecond,
+		_ => Unit::None,
+	}
+}
+
+fn labels(key: &metrics::Key) -> impl Iterator<Item = (&str, &str)> {
+	key.labels().map(|label| (label.key(), label.value()))
+}
+
+impl metrics::Recorder for Registry {
+	fn describe_counter(
+		&self,
+		key: metrics::KeyName,
+		unit: Option<metrics::Unit>,
+		description: metrics::SharedString,
+	) {
+		self.describe(&key, MetricKind::Counter, self::unit(unit), &description);
+	}
+
+	fn describe_gauge(
+		&self,
+		key: metrics::KeyName,
+		unit: Option<metrics::Unit>,
+		description: metrics::SharedString,
+	) {
+		self.describe(&key, MetricKind::Gauge, self::unit(unit), &description);
+	}
+
+	fn describe_histogram(
+		&self,
+		key: metrics::KeyName,
+		unit: Option<metrics::Unit>,
+		description: metrics::SharedString,
+	) {
+		self.describe(&key, MetricKind::Histogram, self::unit(unit), &description);
+	}
+
+	fn register_counter(&self, key: &metrics::Key, _: &metrics::Metadata<'_>) -> metrics::Counter {
+		match self.register(Self::key(key.name(), labels(key)), MetricKind::Counter, || {
+			Instrument::Counter(Arc::new(AtomicU64::new(0)))
+		}) {
+			Some(Instrument::Counter(counter)) => {
+				metrics::Counter::from_arc(Arc::new(RecorderCounter(counter)))
+			},
+			_ => metrics::Counter::noop(),
+		}
+	}
+
+	fn register_gauge(&self, key: &metrics::Key, _: &metrics::Metadata<'_>) -> metrics::Gauge {
+		match self.register(Self::key(key.name(), labels(key)), MetricKind::Gauge, || {
+			Instrument::Gauge(Arc::new(AtomicU64::new(0)))
+		}) {
+			Some(Instrument::Gauge(gauge)) => {
+				metrics::Gauge::from_arc(Arc::new(RecorderGauge(gauge)))
+			},
+			_ => metrics::Gauge::noop(),
+		}
+	}
+
+	fn register_histogram(
+		&self,
+		key: &metrics::Key,
+		_: &metrics::Metadata<'_>,
+	) -> metrics::Histogram {
+		match self.register(Self::key(key.name(), labels(key)), MetricKind::Histogram, || {
+			Instrument::Histogram(Arc::new(Mutex::new(HistogramSnapshot::new(&DEFAULT_BUCKETS))))
+		}) {
+			Some(Instrument::Histogram(histogram)) => {
+				metrics::Histogram::from_arc(Arc::new(RecorderHistogram(histogram)))
+			},
+			_ => metrics::Histogram::noop(),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_registry() {
+		let registry = Registry::new("service");
+		registry.counter("requests", &[("route", "/"), ("code", "200")]).increment(2);
+		registry.counter("requests", &[("code", "200"), ("route", "/")]).increment(1);
+		registry.gauge("queue_depth", &[]).set(5);
+		registry.histogram("latency", Buckets::fixed([0.1, 1.0]), &[]).record(0.5);
+		// Already a counter
+		registry.gauge("requests", &[("route", "/"), ("code", "200")]).set(100);
+
+		let metrics = registry.snapshot();
+		assert_eq!(metrics.len(), 3);
+		let requests = metrics.iter().find(|m| m.name() == "requests").unwrap();
+		assert_eq!(requests.namespace(), Some("service"));
+		assert_eq!(requests.tags().unwrap().get("route"), Some("/"));
+		assert_eq!(serde_json::to_string(requests.value()).unwrap(), "3.0");
+	}
+
+	#[test]
+	fn test_recorder() {
+		let registry = Registry::new("recorder");
+		metrics::with_local_recorder(&registry, || {
+			metrics::describe_gauge!("test_connections", metrics::Unit::Count, "Open connections");
+			metrics::gauge!("test_connections", "listener" => "http").increment(3.0);
+			metrics::counter!("test_bytes").absolute(1024);
+			metrics::histogram!("test_duration").record(0.25);
+		});
+		let metrics = registry.snapshot();
+		assert_eq!(metrics.len(), 3);
+		let connections = metrics.iter().find(|m| m.name() == "test_connections").unwrap();
+		assert_eq!(connections.value().kind(), MetricKind::Gauge);
+		assert_eq!(connections.descriptor().unwrap().description(), "Open connections");
+		let duration = metrics.iter().find(|m| m.name() == "test_duration").unwrap();
+		assert_eq!(duration.value().kind(), MetricKind::Histogram);
+	}
+
+	#[test]
+	fn test_describe() {
+		let registry = Registry::new("described");
+		metrics::with_local_recorder(&registry, || {
+			metrics::describe_gauge!("test_described", metrics::Unit::Bytes, "App series");
+			metrics::describe_gauge!("test_described", "Described again");
+			metrics::gauge!("test_described").set(1.0);
+		});
+		let metrics = registry.snapshot();
+		let descriptor = metrics[0].descriptor().unwrap();
+		assert_eq!(descriptor.description(), "App series");
+		assert_eq!(descriptor.unit(), Unit::Bytes);
+	}
+}
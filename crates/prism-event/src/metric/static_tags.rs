@@ -0,0 +1,153 @@
+//! Tags shared by many series
+//!
+//! Global tags such as `host`, `env` or `region` and the extra tags of a
+//! collector are the same for every metric it emits. Rather than being
+//! cloned into each [`Metric`], they are stored once here, keyed by the
+//! namespace of the collector, and merged by encoders through
+//! [`StaticTags::resolve`].
+
+use super::{Metric, MetricTags, String};
+use cow::Cow;
+use std::{
+	collections::{BTreeMap, BTreeSet},
+	sync::{Arc, LazyLock, Mutex, RwLock},
+};
+
+static INTERNED: Mutex<BTreeSet<Arc<str>>> = Mutex::new(BTreeSet::new());
+static STATIC_TAGS: LazyLock<RwLock<Arc<StaticTags>>> = LazyLock::new(Default::default);
+
+/// Returns `value` as a shared string, equal values are only stored once.
+///
+/// Interned strings are never freed, so this is only meant for strings with
+/// few distinct values, such as static tags and namespaces.
+pub fn intern(value: &str) -> String {
+	let mut interned = INTERNED.lock().unwrap_or_else(|e| e.into_inner());
+	let shared = match interned.get(value) {
+		Some(shared) => Arc::clone(shared),
+		None => {
+			let shared: Arc<str> = Arc::from(value);
+			interned.insert(Arc::clone(&shared));
+			shared
+		},
+	};
+	Cow::from_shared(shared)
+}
+
+/// Global and per-namespace tags, see the [module](self) documentation.
+#[derive(Default, Clone)]
+pub struct StaticTags {
+	global: MetricTags,
+	namespaces: BTreeMap<String, MetricTags>,
+}
+
+impl StaticTags {
+	pub const fn global(&self) -> &MetricTags {
+		&self.global
+	}
+
+	pub fn namespace(&self, namespace: &str) -> Option<&MetricTags> {
+		self.namespaces.get(namespace)
+	}
+
+	/// Adds `tags` to every metric, keeping the value of the global tags already set.
+	pub fn add_global(&mut self, tags: &MetricTags) {
+		add(&mut self.global, tags);
+	}
+
+	/// Adds `tags` to the metrics of `namespace`, e.g. `cpu` for the host CPU
+	/// collector, keeping the value of the tags already set.
+	pub fn add_namespace(&mut self, namespace: &str, tags: &MetricTags) {
+		add(self.namespaces.entry(intern(namespace)).or_default(), tags);
+	}
+
+	/// Tags of `metric`, then those of its namespace, then the global ones. A
+	/// tag is taken from the first of them that has it.
+	pub fn resolve<'a>(&'a self, metric: &'a Metric) -> BTreeMap<&'a str, &'a str> {
+		self.resolve_tags(metric.namespace(), metric.tags())
+	}
+
+	/// Like [`resolve`](Self::resolve), for the `namespace` and `tags` of a
+	/// series of a [`MetricBatch`](super::MetricBatch).
+	pub fn resolve_tags<'a>(
+		&'a self,
+		namespace: Option<&str>,
+		tags: Option<&'a MetricTags>,
+	) -> BTreeMap<&'a str, &'a str> {
+		let own = tags.into_iter().flat_map(MetricTags::iter);
+		let namespace = namespace
+			.and_then(|ns| self.namespace(ns))
+			.into_iter()
+			.flat_map(MetricTags::iter);
+		let mut tags = BTreeMap::new();
+		for (k, v) in own.chain(namespace).chain(self.global.iter()) {
+			tags.entry(k).or_insert(v);
+		}
+		tags
+	}
+}
+
+fn add(to: &mut MetricTags, tags: &MetricTags) {
+	for (k, v) in tags.iter() {
+		to.0.entry(intern(k)).or_insert_with(|| intern(v));
+	}
+}
+
+fn update(f: impl FnOnce(&mut StaticTags)) {
+	let mut current = STATIC_TAGS.write().unwrap_or_else(|e| e.into_inner());
+	let mut tags = StaticTags::clone(&current);
+	f(&mut tags);
+	*current = Arc::new(tags);
+}
+
+/// Current static tags, cheap to call once per batch of metrics.
+pub fn static_tags() -> Arc<StaticTags> {
+	Arc::clone(&STATIC_TAGS.read().unwrap_or_else(|e| e.into_inner()))
+}
+
+/// Adds `tags` to every metric, see [`StaticTags::add_global`].
+pub fn add_global_tags(tags: &MetricTags) {
+	update(|static_tags| static_tags.add_global(tags));
+}
+
+/// Adds `tags` to the metrics of `namespace`, see [`StaticTags::add_namespace`].
+pub fn add_namespace_tags(namespace: &str, tags: &MetricTags) {
+	update(|static_tags| static_tags.add_namespace(namespace, tags));
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_resolve() {
+		let mut static_tags = StaticTags::default();
+		let mut tags = MetricTags::new();
+		tags.insert("host", "node-1");
+		tags.insert("cpu", "global");
+		static_tags.add_global(&tags);
+		let mut tags = MetricTags::new();
+		tags.insert("env", "production");
+		tags.insert("host", "ignored");
+		static_tags.add_global(&tags);
+		let mut tags = MetricTags::new();
+		tags.insert("team", "kernel");
+		static_tags.add_namespace("static_tags", &tags);
+
+		let metric = crate::gauge!("test_static", 1, "cpu" => "0");
+		let resolved = static_tags.resolve(&metric);
+		assert_eq!(resolved.get("host"), Some(&"node-1"));
+		assert_eq!(resolved.get("env"), Some(&"production"));
+		assert_eq!(resolved.get("cpu"), Some(&"0"));
+		assert_eq!(resolved.get("team"), Some(&"kernel"));
+
+		let other = crate::gauge!("test_static", 1, "other");
+		assert!(!static_tags.resolve(&other).contains_key("team"));
+	}
+
+	#[test]
+	fn test_intern() {
+		let a = intern("eu-west-1");
+		let b = intern("eu-west-1");
+		assert_eq!(a.as_ptr(), b.as_ptr());
+	}
+}
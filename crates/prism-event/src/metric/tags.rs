@@ -2,7 +2,7 @@ use super::String;
 use serde::{Serialize, Serializer, ser::SerializeMap};
 use std::collections::BTreeMap;
 
-#[derive(Default, Clone)]
+#[derive(Default, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
 pub struct MetricTags(pub(crate) BTreeMap<String, String>);
 
 impl FromIterator<(String, String)> for MetricTags {
@@ -20,13 +20,17 @@ impl MetricTags {
 		self.0.insert(k.into(), v.into());
 	}
 
-	// pub fn get(&self, k: &str) -> Option<&Value> {
-	// 	self.0.get(k)
-	// }
+	pub fn get(&self, k: &str) -> Option<&str> {
+		self.0.get(k).map(|v| v.as_ref())
+	}
+
+	pub fn is_empty(&self) -> bool {
+		self.0.is_empty()
+	}
 
-	// pub fn is_empty(&self) -> bool {
-	// 	self.0.is_empty()
-	// }
+	pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+		self.0.iter().map(|(k, v)| (k.as_ref(), v.as_ref()))
+	}
 
 	/// Replace all the values of a tag with a single value.
 	pub fn replace<K: Into<String>, V: Into<String>>(&mut self, k: K, v: V) -> Option<String> {
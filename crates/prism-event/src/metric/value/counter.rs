@@ -1,48 +1,111 @@
-use std::sync::Arc;
-
-// use serde::Serialize;
-
-// pub struct Counter {
-// 	inner: Option<Arc<dyn CounterFn + Send + Sync>>,
-// }
-
-// impl Serialize for Counter {
-//     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
-//     where
-//         S: serde::Serializer,
-//     {
-//         match &self.inner {
-//             Some(a) => serializer.serialize_u64(atomic_load(a)),
-//             None => serializer.serialize_u64(0),
-//         }
-//     }
-// }
+use crate::{atomics::AtomicU64, metric::IntoF64};
+use serde::Serialize;
+use std::sync::{Arc, atomic::Ordering};
+
+/// A counter.
+///
+/// Counters only go up, a decrease of the source, e.g. after a reboot or an
+/// interface being recreated, is a reset that consumers detect on their own.
+#[derive(Default)]
+#[must_use = "counters do nothing unless you use them"]
+pub struct Counter {
+	inner: Option<Arc<dyn CounterFn + Send + Sync>>,
+}
+
+impl Counter {
+	/// Creates a new `Counter`.
+	pub fn new() -> Self {
+		Self { inner: Some(Arc::new(AtomicU64::new(0))) }
+	}
+
+	/// Creates a `Counter` based on a shared handler.
+	pub fn from_arc<F: CounterFn + Send + Sync + 'static>(a: Arc<F>) -> Self {
+		Self { inner: Some(a) }
+	}
+
+	/// Increments the counter.
+	pub fn increment<T: IntoF64>(&self, value: T) {
+		if let Some(c) = &self.inner {
+			c.increment(value.into_f64())
+		}
+	}
+
+	/// Sets the counter to at least the given value.
+	pub fn absolute<T: IntoF64>(&self, value: T) {
+		if let Some(c) = &self.inner {
+			c.absolute(value.into_f64())
+		}
+	}
+}
+
 /// A counter handler.
-// pub trait CounterFn {
-// 	/// Increments the counter by the given amount.
-// 	fn increment(&self, value: u64);
-
-// 	/// Sets the counter to at least the given amount.
-// 	///
-// 	/// This is intended to support use cases where multiple callers are attempting to synchronize
-// 	/// this counter with an external counter that they have no control over.  As multiple callers
-// 	/// may read that external counter, and attempt to set it here, there could be reordering issues
-// 	/// where a caller attempts to set an older (smaller) value after the counter has been updated to
-// 	/// the latest (larger) value.
-// 	///
-// 	/// This method must cope with those cases.  An example of doing so atomically can be found in
-// 	/// `AtomicCounter`.
-// 	fn absolute(&self, value: u64);
-// }
-
-// use crate::metric::;
-
-// impl CounterFn for AtomicU64 {
-// 	fn increment(&self, value: u64) {
-// 		let _ = self.fetch_add(value, Ordering::Release);
-// 	}
-
-// 	fn absolute(&self, value: u64) {
-// 		let _ = self.fetch_max(value, Ordering::AcqRel);
-// 	}
-// }
+pub trait CounterFn {
+	/// Increments the counter by the given amount, negative amounts are ignored.
+	fn increment(&self, value: f64);
+
+	/// Sets the counter to at least the given amount.
+	///
+	/// This is intended to support use cases where multiple callers are attempting to synchronize
+	/// this counter with an external counter that they have no control over, e.g. the cumulative
+	/// counters of the kernel.  As multiple callers may read that external counter, and attempt to
+	/// set it here, there could be reordering issues where a caller attempts to set an older
+	/// (smaller) value after the counter has been updated to the latest (larger) value.
+	///
+	/// This method must cope with those cases.
+	fn absolute(&self, value: f64);
+
+	/// Returns the current value of the counter.
+	fn value(&self) -> f64;
+}
+
+impl CounterFn for AtomicU64 {
+	fn increment(&self, value: f64) {
+		if value.is_nan() || value <= 0.0 {
+			return;
+		}
+		let _ = self.fetch_update(Ordering::AcqRel, Ordering::Relaxed, |curr| {
+			Some((f64::from_bits(curr) + value).to_bits())
+		});
+	}
+
+	fn absolute(&self, value: f64) {
+		let _ = self.fetch_update(Ordering::AcqRel, Ordering::Relaxed, |curr| {
+			(value > f64::from_bits(curr)).then_some(value.to_bits())
+		});
+	}
+
+	fn value(&self) -> f64 {
+		f64::from_bits(self.load(Ordering::Acquire))
+	}
+}
+
+impl Serialize for Counter {
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		match &self.inner {
+			Some(inner) => serializer.serialize_f64(inner.as_ref().value()),
+			None => serializer.serialize_none(),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_monotonic() {
+		let counter = Counter::new();
+		counter.increment(2u64);
+		counter.increment(-1.0);
+		assert_eq!(serde_json::to_string(&counter).unwrap(), "2.0");
+
+		counter.absolute(10u64);
+		counter.absolute(5u64);
+		assert_eq!(serde_json::to_string(&counter).unwrap(), "10.0");
+
+		assert_eq!(serde_json::to_string(&Counter::default()).unwrap(), "null");
+	}
+}
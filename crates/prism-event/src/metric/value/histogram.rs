@@ -1 +1,339 @@
+use crate::metric::IntoF64;
+use serde::{Serialize, ser::SerializeMap};
+use std::{
+	collections::BTreeMap,
+	sync::{Arc, Mutex},
+};
 
+/// Bucket layout of a histogram.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Buckets {
+	/// Explicit upper bounds, sorted, with an implicit `+Inf` overflow bucket
+	Fixed(Arc<[f64]>),
+	/// Base-2 exponential buckets as used by OpenTelemetry and Prometheus
+	/// native histograms. Bucket `i` covers `(base^i, base^(i+1)]` with
+	/// `base = 2^(2^-scale)`, so each increment of the scale halves the width
+	/// of the buckets.
+	Exponential { scale: i8 },
+}
+
+impl Buckets {
+	/// Fixed buckets with the given upper bounds, which are sorted and deduplicated.
+	pub fn fixed(bounds: impl IntoIterator<Item = f64>) -> Self {
+		let mut bounds: Vec<_> = bounds.into_iter().filter(|b| !b.is_nan()).collect();
+		bounds.sort_by(f64::total_cmp);
+		bounds.dedup();
+		Self::Fixed(bounds.into())
+	}
+
+	/// `count` fixed buckets, the first one ending at `start` and each next
+	/// one `factor` times as large.
+	pub fn exponential_fixed(start: f64, factor: f64, count: usize) -> Self {
+		Self::fixed((0..count).scan(start, |bound, _| {
+			let current = *bound;
+			*bound *= factor;
+			Some(current)
+		}))
+	}
+
+	/// Base-2 exponential buckets, `scale` is clamped to the `-10..=20` range
+	/// supported by OpenTelemetry.
+	pub fn exponential(scale: i8) -> Self {
+		Self::Exponential { scale: scale.clamp(-10, 20) }
+	}
+}
+
+/// Per bucket counts of a histogram.
+#[derive(Clone, Debug, PartialEq)]
+pub enum BucketCounts {
+	/// `counts[i]` observations fell in `(bounds[i-1], bounds[i]]`, the last
+	/// count being the `+Inf` bucket
+	Fixed { bounds: Arc<[f64]>, counts: Vec<u64> },
+	/// Sparse counts by bucket index, observations equal to zero are counted apart
+	Exponential {
+		scale: i8,
+		zero_count: u64,
+		positive: BTreeMap<i32, u64>,
+		negative: BTreeMap<i32, u64>,
+	},
+}
+
+impl BucketCounts {
+	fn new(buckets: &Buckets) -> Self {
+		match buckets {
+			Buckets::Fixed(bounds) => {
+				Self::Fixed { bounds: Arc::clone(bounds), counts: vec![0; bounds.len() + 1] }
+			},
+			Buckets::Exponential { scale } => Self::Exponential {
+				scale: *scale,
+				zero_count: 0,
+				positive: BTreeMap::new(),
+				negative: BTreeMap::new(),
+			},
+		}
+	}
+
+	fn record(&mut self, value: f64, count: u64) {
+		match self {
+			Self::Fixed { bounds, counts } => {
+				let index = bounds.partition_point(|bound| *bound < value);
+				counts[index] += count;
+			},
+			Self::Exponential { scale, zero_count, positive, negative } => {
+				if value == 0.0 {
+					*zero_count += count;
+					return;
+				}
+				let index = exponential_index(value.abs(), *scale);
+				let side = if value > 0.0 { positive } else { negative };
+				*side.entry(index).or_default() += count;
+			},
+		}
+	}
+
+	/// Cumulative counts by upper bound, ending with `+Inf`, as expected by
+	/// Prometheus. Negative observations of exponential histograms all fall
+	/// in the first bucket.
+	pub fn cumulative(&self) -> Vec<(f64, u64)> {
+		let mut total = 0;
+		let mut buckets = Vec::new();
+		match self {
+			Self::Fixed { bounds, counts } => {
+				for (bound, count) in bounds.iter().chain([&f64::INFINITY]).zip(counts) {
+					total += count;
+					buckets.push((*bound, total));
+				}
+			},
+			Self::Exponential { scale, zero_count, positive, negative } => {
+				total = zero_count + negative.values().sum::<u64>();
+				buckets.push((0.0, total));
+				for (index, count) in positive {
+					total += count;
+					buckets.push((exponential_bound(index + 1, *scale), total));
+				}
+				buckets.push((f64::INFINITY, total));
+			},
+		}
+		buckets
+	}
+}
+
+/// Index of the exponential bucket holding the positive `value`.
+pub fn exponential_index(value: f64, scale: i8) -> i32 {
+	let index = (value.log2() * 2f64.powi(i32::from(scale))).ceil() - 1.0;
+	index.clamp(f64::from(i32::MIN), f64::from(i32::MAX)) as i32
+}
+
+/// Lower bound of the exponential bucket `index`, i.e. the upper bound of `index - 1`.
+pub fn exponential_bound(index: i32, scale: i8) -> f64 {
+	(f64::from(index) * 2f64.powi(-i32::from(scale))).exp2()
+}
+
+/// State of a histogram at a point in time.
+#[derive(Clone, Debug, PartialEq)]
+pub struct HistogramSnapshot {
+	count: u64,
+	sum: f64,
+	min: f64,
+	max: f64,
+	buckets: BucketCounts,
+}
+
+impl HistogramSnapshot {
+	pub fn new(buckets: &Buckets) -> Self {
+		Self {
+			count: 0,
+			sum: 0.0,
+			min: f64::INFINITY,
+			max: f64::NEG_INFINITY,
+			buckets: BucketCounts::new(buckets),
+		}
+	}
+
+	/// Records a value.
+	pub fn record<T: IntoF64>(&mut self, value: T) {
+		self.record_many(value.into_f64(), 1);
+	}
+
+	/// Records a value observed `count` times, e.g. from a pre-aggregated
+	/// eBPF map.
+	pub fn record_many(&mut self, value: f64, count: u64) {
+		if value.is_nan() || count == 0 {
+			return;
+		}
+		self.count += count;
+		self.sum += value * count as f64;
+		self.min = self.min.min(value);
+		self.max = self.max.max(value);
+		self.buckets.record(value, count);
+	}
+
+	pub const fn count(&self) -> u64 {
+		self.count
+	}
+
+	pub const fn sum(&self) -> f64 {
+		self.sum
+	}
+
+	/// Smallest observation, `None` when empty.
+	pub fn min(&self) -> Option<f64> {
+		(self.count > 0).then_some(self.min)
+	}
+
+	/// Largest observation, `None` when empty.
+	pub fn max(&self) -> Option<f64> {
+		(self.count > 0).then_some(self.max)
+	}
+
+	pub const fn buckets(&self) -> &BucketCounts {
+		&self.buckets
+	}
+}
+
+/// A histogram.
+#[derive(Default, Clone)]
+#[must_use = "histograms do nothing unless you use them"]
+pub struct Histogram {
+	inner: Option<Arc<dyn HistogramFn + Send + Sync>>,
+}
+
+impl Histogram {
+	/// Creates a new `Histogram` with the given bucket layout.
+	pub fn new(buckets: Buckets) -> Self {
+		Self { inner: Some(Arc::new(Mutex::new(HistogramSnapshot::new(&buckets)))) }
+	}
+
+	/// Creates a `Histogram` based on a shared handler.
+	pub fn from_arc<F: HistogramFn + Send + Sync + 'static>(a: Arc<F>) -> Self {
+		Self { inner: Some(a) }
+	}
+
+	/// Records a value.
+	pub fn record<T: IntoF64>(&self, value: T) {
+		self.record_many(value, 1);
+	}
+
+	/// Records a value observed `count` times, e.g. from a pre-aggregated
+	/// eBPF map.
+	pub fn record_many<T: IntoF64>(&self, value: T, count: u64) {
+		if let Some(h) = &self.inner {
+			h.record_many(value.into_f64(), count)
+		}
+	}
+
+	/// Current state, `None` for a histogram without handler.
+	pub fn snapshot(&self) -> Option<HistogramSnapshot> {
+		self.inner.as_ref().map(|h| h.snapshot())
+	}
+}
+
+/// A histogram handler.
+pub trait HistogramFn {
+	/// Records a value observed `count` times.
+	fn record_many(&self, value: f64, count: u64);
+
+	/// Returns the current state of the histogram.
+	fn snapshot(&self) -> HistogramSnapshot;
+}
+
+impl HistogramFn for Mutex<HistogramSnapshot> {
+	fn record_many(&self, value: f64, count: u64) {
+		self.lock().unwrap_or_else(|e| e.into_inner()).record_many(value, count);
+	}
+
+	fn snapshot(&self) -> HistogramSnapshot {
+		self.lock().unwrap_or_else(|e| e.into_inner()).clone()
+	}
+}
+
+impl Serialize for HistogramSnapshot {
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		let mut map = serializer.serialize_map(None)?;
+		map.serialize_entry("count", &self.count)?;
+		map.serialize_entry("sum", &self.sum)?;
+		map.serialize_entry("min", &self.min())?;
+		map.serialize_entry("max", &self.max())?;
+		// `+Inf` is not valid JSON, the overflow bucket is the count itself
+		let buckets: Vec<_> = self
+			.buckets
+			.cumulative()
+			.into_iter()
+			.filter(|(bound, _)| bound.is_finite())
+			.collect();
+		map.serialize_entry("buckets", &buckets)?;
+		if let BucketCounts::Exponential { scale, .. } = &self.buckets {
+			map.serialize_entry("scale", scale)?;
+		}
+		map.end()
+	}
+}
+
+impl Serialize for Histogram {
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		match self.snapshot() {
+			Some(snapshot) => snapshot.serialize(serializer),
+			None => serializer.serialize_none(),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_fixed() {
+		let histogram = Histogram::new(Buckets::fixed([1.0, 0.1, 0.5]));
+		for value in [0.05, 0.1, 0.2, 2.0] {
+			histogram.record(value);
+		}
+		let snapshot = histogram.snapshot().unwrap();
+		assert_eq!(snapshot.count(), 4);
+		assert_eq!(snapshot.min(), Some(0.05));
+		assert_eq!(snapshot.max(), Some(2.0));
+		assert_eq!(
+			snapshot.buckets().cumulative(),
+			vec![(0.1, 2), (0.5, 3), (1.0, 3), (f64::INFINITY, 4)]
+		);
+	}
+
+	#[test]
+	fn test_exponential() {
+		assert_eq!(exponential_index(1.0, 0), -1);
+		assert_eq!(exponential_index(1.5, 0), 0);
+		assert_eq!(exponential_index(4.0, 0), 1);
+		assert_eq!(exponential_index(3.0, 1), 3);
+		assert_eq!(exponential_bound(2, 0), 4.0);
+
+		let histogram = Histogram::new(Buckets::exponential(0));
+		histogram.record_many(3.0, 2);
+		histogram.record(0.0);
+		let snapshot = histogram.snapshot().unwrap();
+		assert_eq!(snapshot.sum(), 6.0);
+		assert_eq!(snapshot.buckets().cumulative(), vec![(0.0, 1), (4.0, 3), (f64::INFINITY, 3)]);
+	}
+
+	#[test]
+	fn test_macro() {
+		let metric =
+			crate::histogram!("latency", Buckets::fixed([0.5]), [0.1, 1.0], "disk" => "sda");
+		assert_eq!(metric.value().kind().as_str(), "histogram");
+		assert_eq!(metric.namespace(), Some("histogram"));
+		assert_eq!(
+			serde_json::to_string(metric.value()).unwrap(),
+			r#"{"count":2,"sum":1.1,"min":0.1,"max":1.0,"buckets":[[0.5,1]]}"#
+		);
+	}
+
+	#[test]
+	fn test_exponential_fixed() {
+		assert_eq!(Buckets::exponential_fixed(1.0, 2.0, 3), Buckets::fixed([1.0, 2.0, 4.0]));
+	}
+}
@@ -1,20 +1,86 @@
-use serde::Serialize;
+use super::{String, descriptor::MetricKind};
+use serde::{Serialize, Serializer};
 
-// mod counter;
+mod counter;
 mod gauge;
 mod histogram;
+mod state_set;
 mod summary;
-// pub use counter::Counter;
-pub use gauge::Gauge;
-// use metrics::*;
-/// Metric value
-#[derive(Serialize)]
-#[serde(untagged)]
+pub use counter::{Counter, CounterFn};
+pub use gauge::{Gauge, GaugeFn};
+pub use histogram::{
+	BucketCounts, Buckets, Histogram, HistogramFn, HistogramSnapshot, exponential_bound,
+	exponential_index,
+};
+pub use state_set::StateSet;
+pub use summary::{Samples, Summary, SummaryFn, SummarySnapshot};
+/// Metric value at the time of the sample.
+///
+/// Values are plain snapshots so that building a sample does not allocate, the
+/// live [`Counter`], [`Gauge`], [`Histogram`] and [`Summary`] handles are read
+/// when the sample is taken.
+#[derive(Clone, Debug, PartialEq)]
 pub enum MetricValue {
-	// / A cumulative numerical value that can only increase or be reset to zero.
-	// Counter(counter::Counter),
+	/// A cumulative numerical value that can only increase or be reset to zero.
+	Counter(f64),
 	/// A single numerical value that can arbitrarily go up and down.
-	Gauge(Gauge),
-	// /// String value
-	// String(String),
+	Gauge(f64),
+	/// Observations counted in buckets, e.g. latencies.
+	Histogram(HistogramSnapshot),
+	/// Observations summarized by quantiles.
+	Summary(SummarySnapshot),
+	/// Constant `1` whose information is carried by the tags, e.g. the kernel version.
+	Info,
+	/// Named boolean states, e.g. the operational state of an interface.
+	StateSet(StateSet),
+	/// String value
+	String(String),
+}
+
+impl MetricValue {
+	pub const fn kind(&self) -> MetricKind {
+		match self {
+			Self::Counter(_) => MetricKind::Counter,
+			Self::Gauge(_) => MetricKind::Gauge,
+			Self::Histogram(_) => MetricKind::Histogram,
+			Self::Summary(_) => MetricKind::Summary,
+			Self::Info => MetricKind::Info,
+			Self::StateSet(_) => MetricKind::StateSet,
+			Self::String(_) => MetricKind::String,
+		}
+	}
+
+	pub const fn is_counter(&self) -> bool {
+		matches!(self, Self::Counter(_))
+	}
+
+	/// The value of a counter or a gauge, `1` for an info.
+	pub const fn as_f64(&self) -> Option<f64> {
+		match self {
+			Self::Counter(value) | Self::Gauge(value) => Some(*value),
+			Self::Info => Some(1.0),
+			_ => None,
+		}
+	}
+
+	/// Whether the value is a single number, i.e. a counter, a gauge or an info.
+	pub const fn is_numeric(&self) -> bool {
+		matches!(self, Self::Counter(_) | Self::Gauge(_) | Self::Info)
+	}
+}
+
+impl Serialize for MetricValue {
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		S: Serializer,
+	{
+		match self {
+			Self::Counter(value) | Self::Gauge(value) => serializer.serialize_f64(*value),
+			Self::Histogram(histogram) => histogram.serialize(serializer),
+			Self::Summary(summary) => summary.serialize(serializer),
+			Self::Info => serializer.serialize_u8(1),
+			Self::StateSet(states) => states.serialize(serializer),
+			Self::String(value) => serializer.serialize_str(value),
+		}
+	}
 }
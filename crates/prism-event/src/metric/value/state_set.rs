@@ -0,0 +1,107 @@
+use crate::metric::String;
+use serde::{Serialize, ser::SerializeMap};
+
+/// A set of named boolean states, e.g. the operational state of an interface.
+///
+/// Enum-like values have exactly one state set, while flags such as
+/// `degraded` and `read_only` of a RAID array can be set together.
+#[derive(Default, Clone, Debug, PartialEq)]
+#[must_use = "state sets do nothing unless you use them"]
+pub struct StateSet {
+	states: Vec<(String, bool)>,
+}
+
+impl StateSet {
+	/// Creates a `StateSet` from `(state, enabled)` pairs.
+	pub fn new<S: Into<String>>(states: impl IntoIterator<Item = (S, bool)>) -> Self {
+		Self { states: states.into_iter().map(|(state, set)| (state.into(), set)).collect() }
+	}
+
+	/// Creates an enum-like `StateSet` where only `current` is set. A
+	/// `current` missing from `states` is appended, so that an unexpected
+	/// value is not lost.
+	pub fn one_of<S: Into<String>, C: Into<String>>(
+		states: impl IntoIterator<Item = S>,
+		current: C,
+	) -> Self {
+		let current = current.into();
+		let mut states: Vec<_> = states
+			.into_iter()
+			.map(Into::into)
+			.map(|state: String| {
+				let set = state == current;
+				(state, set)
+			})
+			.collect();
+		if !states.iter().any(|(_, set)| *set) {
+			states.push((current, true));
+		}
+		Self { states }
+	}
+
+	pub fn states(&self) -> &[(String, bool)] {
+		&self.states
+	}
+
+	/// Whether `state` is set.
+	pub fn is_set(&self, state: &str) -> bool {
+		self.states.iter().any(|(name, set)| *set && name.as_ref() == state)
+	}
+}
+
+impl Serialize for StateSet {
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		let mut map = serializer.serialize_map(Some(self.states.len()))?;
+		for (state, set) in &self.states {
+			map.serialize_entry(state, set)?;
+		}
+		map.end()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_one_of() {
+		let states = StateSet::one_of(["up", "down"], "down");
+		assert!(states.is_set("down"));
+		assert!(!states.is_set("up"));
+		assert_eq!(serde_json::to_string(&states).unwrap(), r#"{"up":false,"down":true}"#);
+
+		let states = StateSet::one_of(["up", "down"], "dormant");
+		assert_eq!(states.states().len(), 3);
+		assert!(states.is_set("dormant"));
+	}
+
+	#[test]
+	fn test_macros() {
+		let info = crate::info_metric!("kernel_info", "release" => "6.1.0");
+		assert_eq!(info.namespace(), Some("state_set"));
+		assert_eq!(info.tags().and_then(|tags| tags.get("release")), Some("6.1.0"));
+		assert_eq!(serde_json::to_string(info.value()).unwrap(), "1");
+
+		let operstate = crate::state_set!("operstate", ["up", "down"], "up");
+		assert_eq!(
+			serde_json::to_string(operstate.value()).unwrap(),
+			r#"{"up":true,"down":false}"#
+		);
+
+		let tags = crate::shared_tags!("array" => "md0");
+		let raid =
+			crate::state_set!("state", [("degraded", true), ("syncing", true)], tags = &tags);
+		assert_eq!(raid.namespace(), Some("state_set"));
+		assert_eq!(raid.tags().and_then(|tags| tags.get("array")), Some("md0"));
+		assert_eq!(
+			serde_json::to_string(raid.value()).unwrap(),
+			r#"{"degraded":true,"syncing":true}"#
+		);
+
+		let firmware = crate::string_metric!("firmware", "1.2");
+		assert_eq!(serde_json::to_string(firmware.value()).unwrap(), r#""1.2""#);
+	}
+}
@@ -1 +1,237 @@
+use crate::metric::IntoF64;
+use serde::{Serialize, ser::SerializeMap};
+use std::{
+	collections::VecDeque,
+	sync::{Arc, Mutex},
+	time::{Duration, Instant},
+};
 
+/// Most observations a summary keeps to compute its quantiles
+const WINDOW_SIZE: usize = 1024;
+/// Age after which an observation no longer counts towards the quantiles, as
+/// for the summaries of the Prometheus clients
+const WINDOW_AGE: Duration = Duration::from_secs(600);
+
+/// State of a summary at a point in time.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SummarySnapshot {
+	count: u64,
+	sum: f64,
+	/// `(quantile, value)` pairs sorted by quantile
+	quantiles: Vec<(f64, f64)>,
+}
+
+impl SummarySnapshot {
+	/// Creates a snapshot from already computed quantiles.
+	pub const fn new(count: u64, sum: f64, quantiles: Vec<(f64, f64)>) -> Self {
+		Self { count, sum, quantiles }
+	}
+
+	/// Computes the given `quantiles` of all of `values`, quantiles outside of
+	/// `0.0..=1.0` and `NaN` values are ignored.
+	pub fn from_values<T: IntoF64>(quantiles: &[f64], values: impl IntoIterator<Item = T>) -> Self {
+		let mut values: Vec<_> =
+			values.into_iter().map(IntoF64::into_f64).filter(|v| !v.is_nan()).collect();
+		let sum = values.iter().sum();
+		values.sort_by(f64::total_cmp);
+		let quantiles = quantiles_of(&valid_quantiles(quantiles), &values);
+		Self::new(values.len() as u64, sum, quantiles)
+	}
+
+	pub const fn count(&self) -> u64 {
+		self.count
+	}
+
+	pub const fn sum(&self) -> f64 {
+		self.sum
+	}
+
+	pub fn quantiles(&self) -> &[(f64, f64)] {
+		&self.quantiles
+	}
+}
+
+/// Observations of a summary. The count and sum cover every observation, the
+/// quantiles, computed when taking a snapshot, only the last 1024 ones of the
+/// last 10 minutes, so that memory and snapshot costs stay bounded for
+/// long-lived summaries.
+pub struct Samples {
+	quantiles: Arc<[f64]>,
+	window: VecDeque<(Instant, f64)>,
+	count: u64,
+	sum: f64,
+}
+
+impl Samples {
+	fn new(quantiles: &[f64]) -> Self {
+		Self {
+			quantiles: valid_quantiles(quantiles).into(),
+			window: VecDeque::with_capacity(WINDOW_SIZE),
+			count: 0,
+			sum: 0.0,
+		}
+	}
+
+	fn record(&mut self, value: f64) {
+		if value.is_nan() {
+			return;
+		}
+		if self.window.len() == WINDOW_SIZE {
+			self.window.pop_front();
+		}
+		self.window.push_back((Instant::now(), value));
+		self.count += 1;
+		self.sum += value;
+	}
+
+	fn snapshot(&mut self) -> SummarySnapshot {
+		while self.window.front().is_some_and(|(at, _)| at.elapsed() > WINDOW_AGE) {
+			self.window.pop_front();
+		}
+		let mut values: Vec<_> = self.window.iter().map(|(_, value)| *value).collect();
+		values.sort_by(f64::total_cmp);
+		SummarySnapshot::new(self.count, self.sum, quantiles_of(&self.quantiles, &values))
+	}
+}
+
+/// Sorted and deduplicated quantiles within `0.0..=1.0`.
+fn valid_quantiles(quantiles: &[f64]) -> Vec<f64> {
+	let mut quantiles: Vec<_> =
+		quantiles.iter().copied().filter(|q| (0.0..=1.0).contains(q)).collect();
+	quantiles.sort_by(f64::total_cmp);
+	quantiles.dedup();
+	quantiles
+}
+
+/// `(quantile, value)` pairs of sorted `values`, none when there is no value.
+fn quantiles_of(quantiles: &[f64], values: &[f64]) -> Vec<(f64, f64)> {
+	if values.is_empty() {
+		return Vec::new();
+	}
+	quantiles.iter().map(|q| (*q, quantile(values, *q))).collect()
+}
+
+/// Nearest-rank quantile of sorted `values`.
+fn quantile(values: &[f64], q: f64) -> f64 {
+	let rank = (q * values.len() as f64).ceil() as usize;
+	values[rank.clamp(1, values.len()) - 1]
+}
+
+/// A summary.
+#[derive(Default, Clone)]
+#[must_use = "summaries do nothing unless you use them"]
+pub struct Summary {
+	inner: Option<Arc<dyn SummaryFn + Send + Sync>>,
+}
+
+impl Summary {
+	/// Creates a new `Summary` reporting the given quantiles, values outside
+	/// of `0.0..=1.0` are ignored.
+	pub fn new(quantiles: &[f64]) -> Self {
+		Self { inner: Some(Arc::new(Mutex::new(Samples::new(quantiles)))) }
+	}
+
+	/// Creates a `Summary` based on a shared handler.
+	pub fn from_arc<F: SummaryFn + Send + Sync + 'static>(a: Arc<F>) -> Self {
+		Self { inner: Some(a) }
+	}
+
+	/// Records a value.
+	pub fn record<T: IntoF64>(&self, value: T) {
+		if let Some(s) = &self.inner {
+			s.record(value.into_f64())
+		}
+	}
+
+	/// Current state, `None` for a summary without handler.
+	pub fn snapshot(&self) -> Option<SummarySnapshot> {
+		self.inner.as_ref().map(|s| s.snapshot())
+	}
+}
+
+/// A summary handler.
+pub trait SummaryFn {
+	/// Records a value.
+	fn record(&self, value: f64);
+
+	/// Returns the current state of the summary.
+	fn snapshot(&self) -> SummarySnapshot;
+}
+
+impl SummaryFn for Mutex<Samples> {
+	fn record(&self, value: f64) {
+		self.lock().unwrap_or_else(|e| e.into_inner()).record(value);
+	}
+
+	fn snapshot(&self) -> SummarySnapshot {
+		self.lock().unwrap_or_else(|e| e.into_inner()).snapshot()
+	}
+}
+
+impl SummaryFn for SummarySnapshot {
+	fn record(&self, _: f64) {}
+
+	fn snapshot(&self) -> SummarySnapshot {
+		self.clone()
+	}
+}
+
+impl Serialize for SummarySnapshot {
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		let mut map = serializer.serialize_map(Some(3))?;
+		map.serialize_entry("count", &self.count)?;
+		map.serialize_entry("sum", &self.sum)?;
+		map.serialize_entry("quantiles", &self.quantiles)?;
+		map.end()
+	}
+}
+
+impl Serialize for Summary {
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		match self.snapshot() {
+			Some(snapshot) => snapshot.serialize(serializer),
+			None => serializer.serialize_none(),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_quantiles() {
+		let summary = Summary::new(&[0.99, 0.5, 1.5]);
+		for value in 1..=100u32 {
+			summary.record(value);
+		}
+		let snapshot = summary.snapshot().unwrap();
+		assert_eq!(snapshot.count(), 100);
+		assert_eq!(snapshot.sum(), 5050.0);
+		assert_eq!(snapshot.quantiles(), &[(0.5, 50.0), (0.99, 99.0)]);
+
+		assert_eq!(Summary::new(&[0.5]).snapshot().unwrap().quantiles(), &[]);
+	}
+
+	#[test]
+	fn test_bounded_window() {
+		let summary = Summary::new(&[0.0, 0.5]);
+		for value in 1..=10_000u32 {
+			summary.record(value);
+		}
+		let snapshot = summary.snapshot().unwrap();
+		// count and sum cover every observation, quantiles the last ones
+		assert_eq!(snapshot.count(), 10_000);
+		assert_eq!(snapshot.sum(), 50_005_000.0);
+		assert_eq!(snapshot.quantiles(), &[(0.0, 8977.0), (0.5, 9488.0)]);
+
+		let snapshot = SummarySnapshot::from_values(&[0.0, 0.5], 1..=10_000u32);
+		assert_eq!(snapshot.quantiles(), &[(0.0, 1.0), (0.5, 5000.0)]);
+	}
+}
@@ -0,0 +1,310 @@
+//! Timestamps and their output format
+//!
+//! Samples and events are timestamped with a [`Timestamp`], nanoseconds since
+//! the Unix epoch in UTC, so that hosts in different regions write comparable
+//! values. How encoders write them, RFC3339, epoch seconds to nanoseconds or a
+//! custom `strftime` format, and in which timezone, is set once for the agent
+//! with [`set_settings`]. File rotation uses the same timezone.
+
+use chrono::{
+	DateTime, Days, FixedOffset, NaiveTime, TimeZone, Utc,
+	format::{Item, StrftimeItems},
+};
+use serde::Serialize;
+use std::{
+	fmt::{self, Write},
+	str::FromStr,
+	sync::{Arc, LazyLock, RwLock},
+	time::{SystemTime, UNIX_EPOCH},
+};
+
+static SETTINGS: LazyLock<RwLock<Arc<TimeSettings>>> = LazyLock::new(Default::default);
+
+/// Nanoseconds since the Unix epoch, in UTC.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
+pub struct Timestamp(i64);
+
+impl Timestamp {
+	pub fn now() -> Self {
+		let nanos = match SystemTime::now().duration_since(UNIX_EPOCH) {
+			Ok(elapsed) => i64::try_from(elapsed.as_nanos()).unwrap_or(i64::MAX),
+			Err(e) => -i64::try_from(e.duration().as_nanos()).unwrap_or(i64::MAX),
+		};
+		Self(nanos)
+	}
+
+	pub const fn from_nanos(nanos: i64) -> Self {
+		Self(nanos)
+	}
+
+	pub const fn as_nanos(&self) -> i64 {
+		self.0
+	}
+
+	pub const fn to_utc(&self) -> DateTime<Utc> {
+		DateTime::from_timestamp_nanos(self.0)
+	}
+}
+
+impl<Tz: TimeZone> From<DateTime<Tz>> for Timestamp {
+	/// Saturates outside of the years 1677 to 2262 that nanoseconds can represent.
+	fn from(datetime: DateTime<Tz>) -> Self {
+		let nanos = datetime.timestamp_nanos_opt().unwrap_or(if datetime.timestamp() < 0 {
+			i64::MIN
+		} else {
+			i64::MAX
+		});
+		Self(nanos)
+	}
+}
+
+/// Invalid format, precision or timezone.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TimeSettingError(std::string::String);
+
+impl fmt::Display for TimeSettingError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "invalid time setting: {}", self.0)
+	}
+}
+
+impl std::error::Error for TimeSettingError {}
+
+/// How timestamps are written.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum TimestampFormat {
+	/// `2025-01-31T08:00:00.000+00:00`
+	#[default]
+	Rfc3339,
+	/// Integer number of [`Precision`] units since the Unix epoch, independent of the timezone
+	Epoch,
+	/// A `strftime` format, e.g. `%Y-%m-%d %H:%M:%S%.3f`, the precision being part of the format
+	Custom(std::string::String),
+}
+
+impl FromStr for TimestampFormat {
+	type Err = TimeSettingError;
+
+	/// `rfc3339`, `epoch` or else a custom `strftime` format.
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		match s {
+			"rfc3339" => Ok(Self::Rfc3339),
+			"epoch" => Ok(Self::Epoch),
+			"" => Err(TimeSettingError("empty format".into())),
+			custom if StrftimeItems::new(custom).any(|item| item == Item::Error) => {
+				Err(TimeSettingError(format!("format `{custom}`")))
+			},
+			custom => Ok(Self::Custom(custom.to_string())),
+		}
+	}
+}
+
+/// Smallest unit written by the RFC3339 and epoch formats.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Precision {
+	Seconds,
+	#[default]
+	Millis,
+	Micros,
+	Nanos,
+}
+
+impl Precision {
+	const fn nanos(self) -> i64 {
+		match self {
+			Self::Seconds => 1_000_000_000,
+			Self::Millis => 1_000_000,
+			Self::Micros => 1_000,
+			Self::Nanos => 1,
+		}
+	}
+
+	const fn rfc3339(self) -> &'static str {
+		match self {
+			Self::Seconds => "%Y-%m-%dT%H:%M:%S%:z",
+			Self::Millis => "%Y-%m-%dT%H:%M:%S%.3f%:z",
+			Self::Micros => "%Y-%m-%dT%H:%M:%S%.6f%:z",
+			Self::Nanos => "%Y-%m-%dT%H:%M:%S%.9f%:z",
+		}
+	}
+}
+
+impl FromStr for Precision {
+	type Err = TimeSettingError;
+
+	/// `s`, `ms`, `us` or `ns`.
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		match s {
+			"s" => Ok(Self::Seconds),
+			"ms" => Ok(Self::Millis),
+			"us" => Ok(Self::Micros),
+			"ns" => Ok(Self::Nanos),
+			other => Err(TimeSettingError(format!("precision `{other}`"))),
+		}
+	}
+}
+
+/// Timezone of the written timestamps and of file rotation.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Timezone {
+	#[default]
+	Utc,
+	/// Timezone of the host
+	Local,
+	Fixed(FixedOffset),
+}
+
+impl Timezone {
+	/// `utc` as seen in this timezone.
+	pub fn convert(&self, utc: DateTime<Utc>) -> DateTime<FixedOffset> {
+		match self {
+			Self::Utc => utc.fixed_offset(),
+			Self::Local => utc.with_timezone(&chrono::Local).fixed_offset(),
+			Self::Fixed(offset) => utc.with_timezone(offset),
+		}
+	}
+
+	/// Current time in this timezone.
+	pub fn now(&self) -> DateTime<FixedOffset> {
+		self.convert(Utc::now())
+	}
+
+	/// Start of the day following `utc` in this timezone, e.g. to rotate files.
+	pub fn next_midnight(&self, utc: DateTime<Utc>) -> DateTime<Utc> {
+		let local = self.convert(utc);
+		let midnight = (local.date_naive() + Days::new(1)).and_time(NaiveTime::MIN);
+		let next = match self {
+			Self::Local => {
+				chrono::Local.from_local_datetime(&midnight).earliest().map(|t| t.to_utc())
+			},
+			_ => local.offset().from_local_datetime(&midnight).earliest().map(|t| t.to_utc()),
+		};
+		next.unwrap_or(utc + Days::new(1))
+	}
+}
+
+impl FromStr for Timezone {
+	type Err = TimeSettingError;
+
+	/// `UTC`, `local` or a fixed offset such as `+08:00`.
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		match s {
+			"UTC" | "utc" | "Z" => Ok(Self::Utc),
+			"local" => Ok(Self::Local),
+			offset => offset
+				.parse::<FixedOffset>()
+				.map(Self::Fixed)
+				.map_err(|_| TimeSettingError(format!("timezone `{offset}`"))),
+		}
+	}
+}
+
+/// Output format of timestamps, see the [module](self) documentation.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct TimeSettings {
+	format: TimestampFormat,
+	precision: Precision,
+	timezone: Timezone,
+}
+
+impl TimeSettings {
+	pub const fn new(format: TimestampFormat, precision: Precision, timezone: Timezone) -> Self {
+		Self { format, precision, timezone }
+	}
+
+	pub const fn format(&self) -> &TimestampFormat {
+		&self.format
+	}
+
+	pub const fn precision(&self) -> Precision {
+		self.precision
+	}
+
+	pub const fn timezone(&self) -> Timezone {
+		self.timezone
+	}
+
+	/// Integer number of [`Precision`] units since the Unix epoch, when
+	/// timestamps are written in the epoch format.
+	pub fn epoch(&self, timestamp: Timestamp) -> Option<i64> {
+		(self.format == TimestampFormat::Epoch)
+			.then(|| timestamp.as_nanos().div_euclid(self.precision.nanos()))
+	}
+
+	/// Writes `timestamp` to `o` as configured.
+	pub fn write(&self, timestamp: Timestamp, o: &mut impl Write) -> fmt::Result {
+		match &self.format {
+			TimestampFormat::Epoch => {
+				write!(o, "{}", timestamp.as_nanos().div_euclid(self.precision.nanos()))
+			},
+			TimestampFormat::Rfc3339 => {
+				let datetime = self.timezone.convert(timestamp.to_utc());
+				write!(o, "{}", datetime.format(self.precision.rfc3339()))
+			},
+			TimestampFormat::Custom(format) => {
+				let datetime = self.timezone.convert(timestamp.to_utc());
+				write!(o, "{}", datetime.format(format))
+			},
+		}
+	}
+}
+
+/// Current settings, cheap to call once per batch of metrics.
+pub fn settings() -> Arc<TimeSettings> {
+	Arc::clone(&SETTINGS.read().unwrap_or_else(|e| e.into_inner()))
+}
+
+/// Sets how every encoder writes timestamps, to be called before senders are created.
+pub fn set_settings(settings: TimeSettings) {
+	*SETTINGS.write().unwrap_or_else(|e| e.into_inner()) = Arc::new(settings);
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn format(settings: &TimeSettings, timestamp: Timestamp) -> std::string::String {
+		let mut s = std::string::String::new();
+		settings.write(timestamp, &mut s).unwrap();
+		s
+	}
+
+	#[test]
+	fn test_formats() {
+		let timestamp = Timestamp::from_nanos(1_738_310_400_123_456_789);
+		let utc = TimeSettings::default();
+		assert_eq!(format(&utc, timestamp), "2025-01-31T08:00:00.123+00:00");
+
+		let cst = TimeSettings::new(
+			TimestampFormat::Rfc3339,
+			Precision::Nanos,
+			"+08:00".parse().unwrap(),
+		);
+		assert_eq!(format(&cst, timestamp), "2025-01-31T16:00:00.123456789+08:00");
+
+		let epoch = TimeSettings::new(TimestampFormat::Epoch, Precision::Micros, Timezone::Local);
+		assert_eq!(format(&epoch, timestamp), "1738310400123456");
+		assert_eq!(epoch.epoch(timestamp), Some(1_738_310_400_123_456));
+		assert_eq!(utc.epoch(timestamp), None);
+
+		let custom = TimeSettings::new(
+			"%Y-%m-%d %H:%M:%S%.3f".parse().unwrap(),
+			Precision::Seconds,
+			"-05:00".parse().unwrap(),
+		);
+		assert_eq!(format(&custom, timestamp), "2025-01-31 03:00:00.123");
+
+		assert!("%Q".parse::<TimestampFormat>().is_err());
+		assert!("PST".parse::<Timezone>().is_err());
+		assert!("min".parse::<Precision>().is_err());
+	}
+
+	#[test]
+	fn test_next_midnight() {
+		let cst: Timezone = "+08:00".parse().unwrap();
+		let utc = Timestamp::from_nanos(1_738_310_400_000_000_000).to_utc();
+		// 16:00 in UTC+8, the next day starts 8 hours later
+		assert_eq!(cst.next_midnight(utc), utc + chrono::Duration::hours(8));
+		assert_eq!(Timezone::Utc.next_midnight(utc), utc + chrono::Duration::hours(16));
+	}
+}
@@ -0,0 +1,122 @@
+//! Processor identity
+//!
+//! `/proc/cpuinfo` repeats a `key : value` block for every logical CPU. The
+//! identity is taken from the first block: the model name, the microcode
+//! revision and the feature flags, which are reduced to a short hash so that
+//! hosts with different CPU features can be told apart without shipping the
+//! whole list. x86 names them `model name` and `flags`, arm64 `CPU part` and
+//! `Features`.
+
+use crate::HostMetricError;
+use prism_metric_common::procfs_root;
+use prism_metric_utils::read_to_string;
+
+/// Identity of the processors from `/proc/cpuinfo`
+pub struct CpuInfo {
+	/// Model name, e.g. `Intel(R) Xeon(R) Gold 6338 CPU @ 2.00GHz`
+	model: Option<String>,
+	/// Microcode revision, e.g. `0xd0003a5`, x86 only
+	microcode: Option<String>,
+	/// FNV-1a hash of the sorted feature flags, as 16 hex digits
+	flags_hash: String,
+	/// Number of logical CPUs
+	cpus: usize,
+}
+
+impl CpuInfo {
+	pub fn model(&self) -> Option<&str> {
+		self.model.as_deref()
+	}
+
+	pub fn microcode(&self) -> Option<&str> {
+		self.microcode.as_deref()
+	}
+
+	pub fn flags_hash(&self) -> &str {
+		&self.flags_hash
+	}
+
+	pub const fn cpus(&self) -> usize {
+		self.cpus
+	}
+}
+
+/// 64-bit FNV-1a, which unlike the std hashers is stable across releases.
+pub fn fnv1a(bytes: &[u8]) -> u64 {
+	bytes.iter().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+		(hash ^ u64::from(*byte)).wrapping_mul(0x0000_0100_0000_01b3)
+	})
+}
+
+/// Hashes the feature flags, sorted so that the kernel ordering does not matter.
+pub fn hash_flags(flags: &str) -> String {
+	let mut flags: Vec<_> = flags.split_whitespace().collect();
+	flags.sort_unstable();
+	format!("{:016x}", fnv1a(flags.join(" ").as_bytes()))
+}
+
+/// Parses the content of `/proc/cpuinfo`.
+pub fn parse(content: &str) -> CpuInfo {
+	let mut model = None;
+	let mut microcode = None;
+	let mut flags = "";
+	let mut cpus = 0;
+	for line in content.lines() {
+		let Some((key, value)) = line.split_once(':') else {
+			continue;
+		};
+		let (key, value) = (key.trim(), value.trim());
+		if key == "processor" {
+			cpus += 1;
+		}
+		// Only the first block describes the identity.
+		if cpus > 1 {
+			continue;
+		}
+		match key {
+			"model name" | "CPU part" if model.is_none() => model = Some(value.to_string()),
+			"microcode" => microcode = Some(value.to_string()),
+			"flags" | "Features" => flags = value,
+			_ => {},
+		}
+	}
+	CpuInfo { model, microcode, flags_hash: hash_flags(flags), cpus }
+}
+
+pub async fn cpuinfo() -> Result<CpuInfo, HostMetricError> {
+	let content = read_to_string(procfs_root().join("cpuinfo")).await?;
+	Ok(parse(&content))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_parse() {
+		let content = "processor\t: 0
+vendor_id\t: GenuineIntel
+model name\t: Intel(R) Xeon(R) Processor
+microcode\t: 0x1
+flags\t\t: fpu vme de pse
+
+processor\t: 1
+vendor_id\t: GenuineIntel
+model name\t: Intel(R) Xeon(R) Processor
+microcode\t: 0x2
+flags\t\t: fpu vme de pse
+";
+		let info = parse(content);
+		assert_eq!(info.model(), Some("Intel(R) Xeon(R) Processor"));
+		assert_eq!(info.microcode(), Some("0x1"));
+		assert_eq!(info.cpus(), 2);
+		assert_eq!(info.flags_hash(), hash_flags("pse de vme fpu"));
+		assert_ne!(info.flags_hash(), hash_flags("fpu vme de"));
+	}
+
+	#[test]
+	fn test_fnv1a() {
+		assert_eq!(fnv1a(b""), 0xcbf2_9ce4_8422_2325);
+		assert_eq!(fnv1a(b"a"), 0xaf63_dc4c_8601_ec8c);
+	}
+}
@@ -0,0 +1,107 @@
+//! DMI/SMBIOS identification
+//!
+//! `/sys/class/dmi/id` exposes the firmware provided system, board, BIOS and
+//! chassis strings, one per file. Serial numbers and the product uuid are
+//! only readable by root and most arm64 hosts have no DMI table at all, so
+//! every field is optional.
+
+use crate::HostMetricError;
+use prism_metric_common::sysfs_root;
+use prism_metric_utils::{path_exists_lossy, read_to_string};
+use std::path::Path;
+
+/// System identification from `/sys/class/dmi/id`
+#[derive(Default)]
+pub struct Dmi {
+	/// e.g. `Dell Inc.`
+	sys_vendor: Option<String>,
+	/// e.g. `PowerEdge R650`
+	product_name: Option<String>,
+	product_version: Option<String>,
+	/// Root only
+	product_serial: Option<String>,
+	/// Root only
+	product_uuid: Option<String>,
+	board_vendor: Option<String>,
+	board_name: Option<String>,
+	bios_vendor: Option<String>,
+	bios_version: Option<String>,
+	bios_date: Option<String>,
+	/// SMBIOS chassis type, e.g. `23` for a rack mount chassis
+	chassis_type: Option<String>,
+}
+
+impl Dmi {
+	pub fn sys_vendor(&self) -> Option<&str> {
+		self.sys_vendor.as_deref()
+	}
+
+	pub fn product_name(&self) -> Option<&str> {
+		self.product_name.as_deref()
+	}
+
+	pub fn product_version(&self) -> Option<&str> {
+		self.product_version.as_deref()
+	}
+
+	pub fn product_serial(&self) -> Option<&str> {
+		self.product_serial.as_deref()
+	}
+
+	pub fn product_uuid(&self) -> Option<&str> {
+		self.product_uuid.as_deref()
+	}
+
+	pub fn board_vendor(&self) -> Option<&str> {
+		self.board_vendor.as_deref()
+	}
+
+	pub fn board_name(&self) -> Option<&str> {
+		self.board_name.as_deref()
+	}
+
+	pub fn bios_vendor(&self) -> Option<&str> {
+		self.bios_vendor.as_deref()
+	}
+
+	pub fn bios_version(&self) -> Option<&str> {
+		self.bios_version.as_deref()
+	}
+
+	pub fn bios_date(&self) -> Option<&str> {
+		self.bios_date.as_deref()
+	}
+
+	pub fn chassis_type(&self) -> Option<&str> {
+		self.chassis_type.as_deref()
+	}
+}
+
+/// Reads a DMI string, `None` when it is missing, unreadable or empty.
+async fn read_field(dir: &Path, name: &str) -> Option<String> {
+	let value = read_to_string(dir.join(name)).await.ok()?;
+	let value = value.trim();
+	(!value.is_empty()).then(|| value.to_string())
+}
+
+/// Reads `/sys/class/dmi/id`, hosts without a DMI table yield an empty [`Dmi`].
+pub async fn dmi() -> Result<Dmi, HostMetricError> {
+	let dir = sysfs_root().join("class/dmi/id");
+	if !path_exists_lossy(dir.clone()).await {
+		return Ok(Dmi::default());
+	}
+
+	Ok(Dmi {
+		sys_vendor: read_field(&dir, "sys_vendor").await,
+		product_name: read_field(&dir, "product_name").await,
+		product_version: read_field(&dir, "product_version").await,
+		product_serial: read_field(&dir, "product_serial").await,
+		product_uuid: read_field(&dir, "product_uuid").await,
+		board_vendor: read_field(&dir, "board_vendor").await,
+		board_name: read_field(&dir, "board_name").await,
+		bios_vendor: read_field(&dir, "bios_vendor").await,
+		bios_version: read_field(&dir, "bios_version").await,
+		bios_date: read_field(&dir, "bios_date").await,
+		chassis_type: read_field(&dir, "chassis_type").await,
+	})
+}
@@ -0,0 +1,8 @@
+use std::io;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum HostMetricError {
+	#[error("IO operated error: {0}")]
+	IOError(#[from] io::Error),
+}
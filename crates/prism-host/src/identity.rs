@@ -0,0 +1,68 @@
+//! Host identity
+//!
+//! Stable identifiers used to join metrics with inventory systems:
+//! `/etc/machine-id` is generated once at install time, while
+//! `/proc/sys/kernel/random/boot_id` changes on every boot. The hostname and
+//! the kernel build come from `/proc/sys/kernel/hostname`,
+//! `/proc/sys/kernel/osrelease` and `/proc/version`.
+
+use crate::HostMetricError;
+use prism_metric_common::{etc_root, procfs_root};
+use prism_metric_utils::read_to_string;
+use std::{io, path::PathBuf};
+
+/// Identity of the host and of the running kernel
+pub struct Identity {
+	hostname: String,
+	/// 32 hex digits, `None` when `/etc/machine-id` is missing (e.g. in some
+	/// containers)
+	machine_id: Option<String>,
+	/// Random uuid generated by the kernel at boot
+	boot_id: String,
+	/// Kernel release, e.g. `6.1.0-18-amd64`
+	kernel_release: String,
+	/// Full `/proc/version` banner with the compiler and build date
+	kernel_version: String,
+}
+
+impl Identity {
+	pub fn hostname(&self) -> &str {
+		&self.hostname
+	}
+
+	pub fn machine_id(&self) -> Option<&str> {
+		self.machine_id.as_deref()
+	}
+
+	pub fn boot_id(&self) -> &str {
+		&self.boot_id
+	}
+
+	pub fn kernel_release(&self) -> &str {
+		&self.kernel_release
+	}
+
+	pub fn kernel_version(&self) -> &str {
+		&self.kernel_version
+	}
+}
+
+async fn read_trimmed(path: PathBuf) -> io::Result<String> {
+	Ok(read_to_string(path).await?.trim().to_string())
+}
+
+pub async fn identity() -> Result<Identity, HostMetricError> {
+	let proc = procfs_root();
+	let hostname = read_trimmed(proc.join("sys/kernel/hostname")).await?;
+	let boot_id = read_trimmed(proc.join("sys/kernel/random/boot_id")).await?;
+	let kernel_release = read_trimmed(proc.join("sys/kernel/osrelease")).await?;
+	let kernel_version = read_trimmed(proc.join("version")).await?;
+	let machine_id = match read_trimmed(etc_root().join("machine-id")).await {
+		Ok(id) if !id.is_empty() => Some(id),
+		Ok(_) => None,
+		Err(e) if e.kind() == io::ErrorKind::NotFound => None,
+		Err(e) => return Err(e.into()),
+	};
+
+	Ok(Identity { hostname, machine_id, boot_id, kernel_release, kernel_version })
+}
@@ -0,0 +1,8 @@
+//! Collect host identity and inventory.
+pub use error::HostMetricError;
+
+pub mod cpuinfo;
+pub mod dmi;
+mod error;
+pub mod identity;
+pub mod os_release;
@@ -0,0 +1,114 @@
+//! Operating system identification
+//!
+//! `/etc/os-release` is a list of shell-style `KEY=value` assignments whose
+//! values may be quoted. Distributions that ship it as a symlink into `/usr`
+//! are handled transparently; when it is missing, `/usr/lib/os-release` is
+//! read instead as mandated by os-release(5).
+
+use crate::HostMetricError;
+use prism_metric_common::etc_root;
+use prism_metric_utils::read_to_string;
+use std::io;
+
+/// Operating system from `/etc/os-release`
+#[derive(Default)]
+pub struct OsRelease {
+	/// Lower-case identifier, e.g. `debian`
+	id: Option<String>,
+	/// Version identifier, e.g. `12`
+	version_id: Option<String>,
+	/// Human readable name, e.g. `Debian GNU/Linux 12 (bookworm)`
+	pretty_name: Option<String>,
+}
+
+impl OsRelease {
+	pub fn id(&self) -> Option<&str> {
+		self.id.as_deref()
+	}
+
+	pub fn version_id(&self) -> Option<&str> {
+		self.version_id.as_deref()
+	}
+
+	pub fn pretty_name(&self) -> Option<&str> {
+		self.pretty_name.as_deref()
+	}
+}
+
+/// Removes the quotes around a value and the backslash escapes inside it.
+fn unquote(value: &str) -> String {
+	let value = value.trim();
+	let value = ['"', '\'']
+		.iter()
+		.find_map(|quote| value.strip_prefix(*quote)?.strip_suffix(*quote))
+		.unwrap_or(value);
+	let mut unquoted = String::with_capacity(value.len());
+	let mut chars = value.chars();
+	while let Some(c) = chars.next() {
+		match c {
+			'\\' => unquoted.extend(chars.next()),
+			c => unquoted.push(c),
+		}
+	}
+	unquoted
+}
+
+/// Parses the content of `/etc/os-release`.
+pub fn parse(content: &str) -> OsRelease {
+	let mut release = OsRelease::default();
+	for line in content.lines() {
+		let Some((key, value)) = line.trim().split_once('=') else {
+			continue;
+		};
+		let field = match key {
+			"ID" => &mut release.id,
+			"VERSION_ID" => &mut release.version_id,
+			"PRETTY_NAME" => &mut release.pretty_name,
+			_ => continue,
+		};
+		*field = Some(unquote(value));
+	}
+	release
+}
+
+/// Reads `/etc/os-release`, falling back to `/usr/lib/os-release`. Hosts
+/// providing neither yield an empty [`OsRelease`].
+pub async fn os_release() -> Result<OsRelease, HostMetricError> {
+	let root = etc_root();
+	let fallback = root.parent().unwrap_or(root).join("usr/lib/os-release");
+	for path in [root.join("os-release"), fallback] {
+		match read_to_string(path).await {
+			Ok(content) => return Ok(parse(&content)),
+			Err(e) if e.kind() == io::ErrorKind::NotFound => continue,
+			Err(e) => return Err(e.into()),
+		}
+	}
+	Ok(OsRelease::default())
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_parse() {
+		let content = r#"PRETTY_NAME="Debian GNU/Linux 12 (bookworm)"
+NAME="Debian GNU/Linux"
+VERSION_ID="12"
+VERSION_CODENAME=bookworm
+ID=debian
+# comment
+"#;
+		let release = parse(content);
+		assert_eq!(release.id(), Some("debian"));
+		assert_eq!(release.version_id(), Some("12"));
+		assert_eq!(release.pretty_name(), Some("Debian GNU/Linux 12 (bookworm)"));
+	}
+
+	#[test]
+	fn test_unquote() {
+		assert_eq!(unquote("'Fedora Linux 40'"), "Fedora Linux 40");
+		assert_eq!(unquote(r#""say \"hi\"""#), r#"say "hi""#);
+		assert_eq!(unquote("rhel"), "rhel");
+	}
+}
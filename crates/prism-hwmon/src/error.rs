@@ -0,0 +1,10 @@
+use std::{io, num};
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum HwmonMetricError {
+	#[error("IO operated error: {0}")]
+	IOError(#[from] io::Error),
+	#[error("Parse int error: {0}")]
+	ParseIntError(#[from] num::ParseIntError),
+}
@@ -0,0 +1,306 @@
+//! Hardware monitoring chips
+//!
+//! Every `/sys/class/hwmon/hwmon*` directory exposes one sensor chip. Channel
+//! files are named `<type><index>_<item>` and hold integers in fixed units:
+//! millidegree Celsius for `temp*`, RPM for `fan*`, millivolt for `in*` and
+//! microwatt for `power*`. An optional `<type><index>_label` file carries the
+//! human readable channel name.
+
+use crate::HwmonMetricError;
+use log::warn;
+use prism_metric_common::sysfs_root;
+use prism_metric_utils::{read_dir, read_to_string};
+use std::{io, path::Path};
+use uom::si::{
+	angular_velocity::revolution_per_minute,
+	electric_potential::millivolt,
+	f64::{AngularVelocity, ElectricPotential, Power, ThermodynamicTemperature},
+	power::microwatt,
+	thermodynamic_temperature::degree_celsius,
+};
+
+/// Temperature channel (`temp*`).
+pub struct TemperatureSensor {
+	/// Channel label, or `temp<index>` when the chip provides none
+	label: String,
+	/// Current temperature
+	input: ThermodynamicTemperature,
+	/// Critical temperature threshold, if the chip exposes one
+	crit: Option<ThermodynamicTemperature>,
+}
+
+impl TemperatureSensor {
+	pub fn label(&self) -> &str {
+		&self.label
+	}
+
+	pub const fn input(&self) -> ThermodynamicTemperature {
+		self.input
+	}
+
+	pub const fn crit(&self) -> Option<ThermodynamicTemperature> {
+		self.crit
+	}
+}
+
+/// Fan channel (`fan*`).
+pub struct FanSensor {
+	/// Channel label, or `fan<index>` when the chip provides none
+	label: String,
+	/// Current fan speed
+	input: AngularVelocity,
+}
+
+impl FanSensor {
+	pub fn label(&self) -> &str {
+		&self.label
+	}
+
+	pub const fn input(&self) -> AngularVelocity {
+		self.input
+	}
+}
+
+/// Voltage channel (`in*`).
+pub struct VoltageSensor {
+	/// Channel label, or `in<index>` when the chip provides none
+	label: String,
+	/// Current voltage
+	input: ElectricPotential,
+}
+
+impl VoltageSensor {
+	pub fn label(&self) -> &str {
+		&self.label
+	}
+
+	pub const fn input(&self) -> ElectricPotential {
+		self.input
+	}
+}
+
+/// Power channel (`power*`).
+pub struct PowerSensor {
+	/// Channel label, or `power<index>` when the chip provides none
+	label: String,
+	/// Current power usage
+	input: Power,
+}
+
+impl PowerSensor {
+	pub fn label(&self) -> &str {
+		&self.label
+	}
+
+	pub const fn input(&self) -> Power {
+		self.input
+	}
+}
+
+/// A sensor chip from `/sys/class/hwmon/hwmon*`
+pub struct Chip {
+	/// hwmon device name, e.g. `hwmon0`
+	device: String,
+	/// Chip name from the `name` file, e.g. `coretemp`
+	name: String,
+	temperatures: Vec<TemperatureSensor>,
+	fans: Vec<FanSensor>,
+	voltages: Vec<VoltageSensor>,
+	powers: Vec<PowerSensor>,
+}
+
+impl Chip {
+	pub fn device(&self) -> &str {
+		&self.device
+	}
+
+	pub fn name(&self) -> &str {
+		&self.name
+	}
+
+	pub fn temperatures(&self) -> &[TemperatureSensor] {
+		&self.temperatures
+	}
+
+	pub fn fans(&self) -> &[FanSensor] {
+		&self.fans
+	}
+
+	pub fn voltages(&self) -> &[VoltageSensor] {
+		&self.voltages
+	}
+
+	pub fn powers(&self) -> &[PowerSensor] {
+		&self.powers
+	}
+
+	async fn read(dir: &Path) -> Result<Self, HwmonMetricError> {
+		let device = file_name(dir).to_string();
+		let name = read_to_string(dir.join("name")).await?.trim().to_string();
+		let mut chip = Self {
+			device,
+			name,
+			temperatures: Vec::new(),
+			fans: Vec::new(),
+			voltages: Vec::new(),
+			powers: Vec::new(),
+		};
+
+		// Older drivers expose their channels under `device/` instead.
+		let mut channels = channels(dir).await?;
+		if channels.is_empty() {
+			channels = channels_or_empty(dir.join("device")).await?;
+		}
+
+		for (base, kind) in channels {
+			let label = read_label(&base).await;
+			// Faulty channels fail with EIO or ENODATA, skip them instead of dropping the chip.
+			let Ok(input) = read_value(&base, "input").await else {
+				continue;
+			};
+			match kind {
+				Kind::Temperature => chip.temperatures.push(TemperatureSensor {
+					label,
+					input: millidegree_to_temperature(input),
+					crit: read_value(&base, "crit").await.ok().map(millidegree_to_temperature),
+				}),
+				Kind::Fan => chip.fans.push(FanSensor {
+					label,
+					input: AngularVelocity::new::<revolution_per_minute>(input as f64),
+				}),
+				Kind::Voltage => chip.voltages.push(VoltageSensor {
+					label,
+					input: ElectricPotential::new::<millivolt>(input as f64),
+				}),
+				Kind::Power => chip
+					.powers
+					.push(PowerSensor { label, input: Power::new::<microwatt>(input as f64) }),
+			}
+		}
+
+		Ok(chip)
+	}
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Kind {
+	Temperature,
+	Fan,
+	Voltage,
+	Power,
+}
+
+impl Kind {
+	fn from_prefix(prefix: &str) -> Option<Self> {
+		match prefix {
+			"temp" => Some(Self::Temperature),
+			"fan" => Some(Self::Fan),
+			"in" => Some(Self::Voltage),
+			"power" => Some(Self::Power),
+			_ => None,
+		}
+	}
+}
+
+/// Splits a channel input file name such as `temp1_input` into its type and
+/// channel base name (`temp1`).
+fn parse_channel(file_name: &str) -> Option<(&str, Kind)> {
+	let base = file_name.strip_suffix("_input")?;
+	let digits = base.find(|c: char| c.is_ascii_digit())?;
+	let (prefix, index) = base.split_at(digits);
+	if !index.bytes().all(|b| b.is_ascii_digit()) {
+		return None;
+	}
+	Kind::from_prefix(prefix).map(|kind| (base, kind))
+}
+
+fn file_name(path: &Path) -> &str {
+	path.file_name().and_then(|name| name.to_str()).unwrap_or_default()
+}
+
+/// Returns the base path (e.g. `.../hwmon0/temp1`) and type of every channel
+/// with an `_input` file in `dir`.
+async fn channels(dir: &Path) -> Result<Vec<(String, Kind)>, HwmonMetricError> {
+	let mut channels = Vec::new();
+	for entry in read_dir(dir.to_owned()).await? {
+		if let Some((base, kind)) = parse_channel(file_name(&entry)) {
+			channels.push((dir.join(base).to_string_lossy().into_owned(), kind));
+		}
+	}
+	Ok(channels)
+}
+
+async fn channels_or_empty<P: AsRef<Path>>(
+	dir: P,
+) -> Result<Vec<(String, Kind)>, HwmonMetricError> {
+	match channels(dir.as_ref()).await {
+		Err(HwmonMetricError::IOError(e)) if e.kind() == io::ErrorKind::NotFound => Ok(Vec::new()),
+		result => result,
+	}
+}
+
+async fn read_value(base: &str, item: &str) -> Result<i64, HwmonMetricError> {
+	let content = read_to_string(format!("{base}_{item}")).await?;
+	content.trim().parse().map_err(Into::into)
+}
+
+async fn read_label(base: &str) -> String {
+	match read_to_string(format!("{base}_label")).await {
+		Ok(label) if !label.trim().is_empty() => label.trim().to_string(),
+		_ => file_name(Path::new(base)).to_string(),
+	}
+}
+
+fn millidegree_to_temperature(millidegree: i64) -> ThermodynamicTemperature {
+	ThermodynamicTemperature::new::<degree_celsius>(millidegree as f64 / 1000.0)
+}
+
+/// Reads every chip under `/sys/class/hwmon`.
+///
+/// Hosts without hardware monitoring (most virtual machines) have no such
+/// directory, which yields an empty list rather than an error. A chip that
+/// cannot be read is skipped with a warning.
+pub async fn chips() -> Result<Vec<Chip>, HwmonMetricError> {
+	let root = sysfs_root().join("class/hwmon");
+	let entries = match read_dir(root).await {
+		Ok(entries) => entries,
+		Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+		Err(e) => return Err(e.into()),
+	};
+
+	let mut chips = Vec::with_capacity(entries.len());
+	for dir in entries {
+		if file_name(&dir).starts_with("hwmon") {
+			match Chip::read(&dir).await {
+				Ok(chip) => chips.push(chip),
+				Err(error) => warn!("Failed to read hwmon chip {}: {error}", dir.display()),
+			}
+		}
+	}
+	Ok(chips)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_parse_channel() {
+		assert!(matches!(parse_channel("temp1_input"), Some(("temp1", Kind::Temperature))));
+		assert!(matches!(parse_channel("fan12_input"), Some(("fan12", Kind::Fan))));
+		assert!(matches!(parse_channel("in0_input"), Some(("in0", Kind::Voltage))));
+		assert!(matches!(parse_channel("power1_input"), Some(("power1", Kind::Power))));
+
+		assert!(parse_channel("temp1_label").is_none());
+		assert!(parse_channel("temp1_crit").is_none());
+		assert!(parse_channel("curr1_input").is_none());
+		assert!(parse_channel("temp_input").is_none());
+		assert!(parse_channel("name").is_none());
+	}
+
+	#[test]
+	fn test_millidegree_to_temperature() {
+		assert_eq!(millidegree_to_temperature(45500).get::<degree_celsius>(), 45.5);
+		assert_eq!(millidegree_to_temperature(-1000).get::<degree_celsius>(), -1.0);
+	}
+}
@@ -0,0 +1,6 @@
+//! Collect hardware sensor metrics.
+pub use error::HwmonMetricError;
+
+mod error;
+pub mod hwmon;
+pub mod thermal;
@@ -0,0 +1,80 @@
+//! Thermal zones
+//!
+//! Every `/sys/class/thermal/thermal_zone*` directory describes one thermal
+//! zone managed by the kernel thermal framework. `type` names the zone (e.g.
+//! `x86_pkg_temp`, `acpitz`) and `temp` holds its temperature in millidegree
+//! Celsius.
+
+use crate::HwmonMetricError;
+use prism_metric_common::sysfs_root;
+use prism_metric_utils::{read_dir, read_to_string};
+use std::io;
+use uom::si::{f64::ThermodynamicTemperature, thermodynamic_temperature::degree_celsius};
+
+/// A thermal zone from `/sys/class/thermal/thermal_zone*`
+pub struct ThermalZone {
+	/// Zone directory name, e.g. `thermal_zone0`
+	name: String,
+	/// Zone type
+	kind: String,
+	/// Current zone temperature
+	temperature: ThermodynamicTemperature,
+}
+
+impl ThermalZone {
+	pub fn name(&self) -> &str {
+		&self.name
+	}
+
+	pub fn kind(&self) -> &str {
+		&self.kind
+	}
+
+	pub const fn temperature(&self) -> ThermodynamicTemperature {
+		self.temperature
+	}
+}
+
+/// Reads every thermal zone under `/sys/class/thermal`.
+///
+/// Zones whose temperature or type cannot be read or parsed (disabled or
+/// faulty sensors) are skipped.
+pub async fn thermal_zones() -> Result<Vec<ThermalZone>, HwmonMetricError> {
+	let root = sysfs_root().join("class/thermal");
+	let entries = match read_dir(root).await {
+		Ok(entries) => entries,
+		Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+		Err(e) => return Err(e.into()),
+	};
+
+	let mut zones = Vec::with_capacity(entries.len());
+	for dir in entries {
+		let Some(name) = dir.file_name().and_then(|name| name.to_str()) else {
+			continue;
+		};
+		if !name.starts_with("thermal_zone") {
+			continue;
+		}
+		let name = name.to_string();
+
+		let Ok(temp) = read_to_string(dir.join("temp")).await else {
+			continue;
+		};
+		let Ok(millidegree) = temp.trim().parse::<i64>() else {
+			continue;
+		};
+		let Ok(kind) = read_to_string(dir.join("type")).await else {
+			continue;
+		};
+		let kind = kind.trim().to_string();
+
+		zones.push(ThermalZone {
+			name,
+			kind,
+			temperature: ThermodynamicTemperature::new::<degree_celsius>(
+				millidegree as f64 / 1000.0,
+			),
+		});
+	}
+	Ok(zones)
+}
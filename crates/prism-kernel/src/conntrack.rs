@@ -0,0 +1,53 @@
+//! Connection tracking table
+//!
+//! `/proc/sys/net/netfilter/nf_conntrack_count` and `nf_conntrack_max` hold
+//! the number of tracked connections and the table size. Once the table is
+//! full, new connections are dropped.
+
+use crate::KernelMetricError;
+use prism_metric_common::procfs_root;
+use prism_metric_utils::read_to_string;
+use std::io;
+
+/// Connection tracking table usage
+pub struct Conntrack {
+	/// Tracked connections
+	count: u64,
+	/// Table size
+	max: u64,
+}
+
+impl Conntrack {
+	pub const fn count(&self) -> u64 {
+		self.count
+	}
+
+	pub const fn max(&self) -> u64 {
+		self.max
+	}
+
+	/// Tracked connections over the table size.
+	pub fn utilization(&self) -> f64 {
+		self.count as f64 / self.max.max(1) as f64
+	}
+}
+
+async fn read_value(name: &str) -> Result<u64, KernelMetricError> {
+	let content = read_to_string(procfs_root().join("sys/net/netfilter").join(name)).await?;
+	content.trim().parse().map_err(Into::into)
+}
+
+/// Reads the connection tracking table usage.
+///
+/// Returns `None` when the `nf_conntrack` module is not loaded.
+pub async fn conntrack() -> Result<Option<Conntrack>, KernelMetricError> {
+	let count = match read_value("nf_conntrack_count").await {
+		Ok(count) => count,
+		Err(KernelMetricError::IOError(e)) if e.kind() == io::ErrorKind::NotFound => {
+			return Ok(None);
+		},
+		Err(e) => return Err(e),
+	};
+	let max = read_value("nf_conntrack_max").await?;
+	Ok(Some(Conntrack { count, max }))
+}
@@ -0,0 +1,12 @@
+use std::{io, num};
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum KernelMetricError {
+	#[error("IO operated error: {0}")]
+	IOError(#[from] io::Error),
+	#[error("Parse error: {0}")]
+	ParseError(#[from] Box<dyn std::error::Error>),
+	#[error("Parse int error: {0}")]
+	ParseIntError(#[from] num::ParseIntError),
+}
@@ -0,0 +1,111 @@
+//! File handle and inode tables
+//!
+//! `/proc/sys/fs/file-nr` holds the number of allocated file handles, the
+//! number of allocated but unused handles and the system wide limit
+//! (`file-max`). `/proc/sys/fs/inode-nr` holds the number of allocated inodes
+//! and the number of free ones.
+
+use crate::KernelMetricError;
+use prism_metric_common::procfs_root;
+use prism_metric_utils::{parse_next, read_to_string};
+
+/// File handle usage from `/proc/sys/fs/file-nr`
+pub struct FileNr {
+	/// Allocated file handles
+	allocated: u64,
+	/// Allocated but unused file handles, always 0 since Linux 2.6
+	unused: u64,
+	/// Maximum number of file handles (`file-max`)
+	max: u64,
+}
+
+impl FileNr {
+	pub const fn allocated(&self) -> u64 {
+		self.allocated
+	}
+
+	pub const fn unused(&self) -> u64 {
+		self.unused
+	}
+
+	pub const fn max(&self) -> u64 {
+		self.max
+	}
+
+	/// File handles in use over `file-max`.
+	pub fn utilization(&self) -> f64 {
+		(self.allocated - self.unused.min(self.allocated)) as f64 / self.max.max(1) as f64
+	}
+
+	pub fn parse(content: &str) -> Result<Self, KernelMetricError> {
+		let mut fields = content.split_whitespace();
+		Ok(Self {
+			allocated: parse_next::<_, KernelMetricError, _, _, _>(&mut fields)?,
+			unused: parse_next::<_, KernelMetricError, _, _, _>(&mut fields)?,
+			max: parse_next::<_, KernelMetricError, _, _, _>(&mut fields)?,
+		})
+	}
+}
+
+/// Inode usage from `/proc/sys/fs/inode-nr`
+pub struct InodeNr {
+	/// Allocated inodes
+	allocated: u64,
+	/// Free inodes
+	free: u64,
+}
+
+impl InodeNr {
+	pub const fn allocated(&self) -> u64 {
+		self.allocated
+	}
+
+	pub const fn free(&self) -> u64 {
+		self.free
+	}
+
+	/// Inodes in use over allocated inodes.
+	pub fn utilization(&self) -> f64 {
+		(self.allocated - self.free.min(self.allocated)) as f64 / self.allocated.max(1) as f64
+	}
+
+	pub fn parse(content: &str) -> Result<Self, KernelMetricError> {
+		let mut fields = content.split_whitespace();
+		Ok(Self {
+			allocated: parse_next::<_, KernelMetricError, _, _, _>(&mut fields)?,
+			free: parse_next::<_, KernelMetricError, _, _, _>(&mut fields)?,
+		})
+	}
+}
+
+pub async fn file_nr() -> Result<FileNr, KernelMetricError> {
+	let content = read_to_string(procfs_root().join("sys/fs/file-nr")).await?;
+	FileNr::parse(&content)
+}
+
+pub async fn inode_nr() -> Result<InodeNr, KernelMetricError> {
+	let content = read_to_string(procfs_root().join("sys/fs/inode-nr")).await?;
+	InodeNr::parse(&content)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_parse() {
+		let file_nr = FileNr::parse("9856\t0\t9223372036854775807\n").unwrap();
+		assert_eq!(
+			(file_nr.allocated(), file_nr.unused(), file_nr.max()),
+			(9856, 0, i64::MAX as u64)
+		);
+
+		let file_nr = FileNr::parse("500\t100\t1000\n").unwrap();
+		assert_eq!(file_nr.utilization(), 0.4);
+
+		let inode_nr = InodeNr::parse("142334\t35714\n").unwrap();
+		assert_eq!((inode_nr.allocated(), inode_nr.free()), (142334, 35714));
+
+		assert!(FileNr::parse("1\t2\n").is_err());
+	}
+}
@@ -0,0 +1,325 @@
+//! Kernel log
+//!
+//! Every read of `/dev/kmsg` returns one record formatted as
+//! `<prefix>,<seq>,<timestamp>,<flag>[,...];<message>`, optionally followed
+//! by `KEY=value` continuation lines indented with a space. The prefix packs
+//! the syslog facility and level, the timestamp is in microseconds since
+//! boot and a `c` flag marks a continuation fragment.
+//!
+//! A handful of messages report conditions worth an event: OOM kills, hung
+//! tasks, soft lockups, block I/O errors, segfaults and NIC link changes.
+//! Their wording has been stable for years but differs between drivers, so
+//! classification only relies on the common kernel format strings.
+
+use std::time::Duration;
+
+/// A record of the kernel log
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KmsgRecord {
+	/// Syslog level, `0` (emergency) to `7` (debug)
+	level: u8,
+	/// Syslog facility, `0` for the kernel itself
+	facility: u8,
+	/// Sequence number, gaps mean records were overwritten before being read
+	sequence: u64,
+	/// Time since boot
+	timestamp: Duration,
+	/// Fragment of a previous record
+	continuation: bool,
+	message: String,
+}
+
+impl KmsgRecord {
+	pub const fn level(&self) -> u8 {
+		self.level
+	}
+
+	pub const fn facility(&self) -> u8 {
+		self.facility
+	}
+
+	pub const fn sequence(&self) -> u64 {
+		self.sequence
+	}
+
+	pub const fn timestamp(&self) -> Duration {
+		self.timestamp
+	}
+
+	pub const fn continuation(&self) -> bool {
+		self.continuation
+	}
+
+	pub fn message(&self) -> &str {
+		&self.message
+	}
+}
+
+/// Condition reported by a kernel log message
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum KernelEvent {
+	/// Process killed by the global or a memory cgroup OOM killer, sizes in kB
+	OomKill { pid: u32, comm: String, total_vm: u64, anon_rss: u64, file_rss: u64, shmem_rss: u64 },
+	/// Task stuck in uninterruptible sleep
+	HungTask { pid: u32, comm: String, seconds: u64 },
+	/// CPU looping in kernel mode without scheduling
+	SoftLockup { cpu: u32, seconds: u64, pid: u32, comm: String },
+	/// Failed block request, `sector` is unknown for buffer I/O errors
+	IoError { device: String, sector: Option<u64> },
+	/// User space process killed by a segmentation fault
+	Segfault { pid: u32, comm: String, address: String, ip: String, error: u32 },
+	/// NIC link state change, `speed` in Mb/s when reported
+	Link { interface: String, up: bool, speed: Option<u64> },
+}
+
+impl KernelEvent {
+	/// Name of the event kind.
+	pub const fn name(&self) -> &'static str {
+		match self {
+			Self::OomKill { .. } => "oom_kill",
+			Self::HungTask { .. } => "hung_task",
+			Self::SoftLockup { .. } => "soft_lockup",
+			Self::IoError { .. } => "io_error",
+			Self::Segfault { .. } => "segfault",
+			Self::Link { up: true, .. } => "link_up",
+			Self::Link { up: false, .. } => "link_down",
+		}
+	}
+}
+
+/// Parses a record read from `/dev/kmsg`, ignoring its continuation lines.
+pub fn parse(record: &str) -> Option<KmsgRecord> {
+	let (header, message) = record.split_once(';')?;
+	let mut fields = header.split(',');
+	let prefix: u32 = fields.next()?.parse().ok()?;
+	let sequence = fields.next()?.parse().ok()?;
+	let timestamp = fields.next()?.parse().ok()?;
+	let continuation = fields.next().is_some_and(|flag| flag.contains('c'));
+	let message = message.lines().next().unwrap_or_default();
+
+	Some(KmsgRecord {
+		level: (prefix & 7) as u8,
+		facility: (prefix >> 3) as u8,
+		sequence,
+		timestamp: Duration::from_micros(timestamp),
+		continuation,
+		message: message.to_string(),
+	})
+}
+
+/// Parses the leading digits of `s`.
+fn number<T: std::str::FromStr>(s: &str) -> Option<T> {
+	let end = s.find(|c: char| !c.is_ascii_digit()).unwrap_or(s.len());
+	s[..end].parse().ok()
+}
+
+/// Splits `comm:pid` or `comm[pid]` on the last separator, task names may
+/// contain colons, e.g. `kworker/0:1`.
+fn task(s: &str, separator: char) -> Option<(String, u32)> {
+	let (comm, pid) = s.rsplit_once(separator)?;
+	Some((comm.to_string(), number(pid)?))
+}
+
+/// Value following `key` up to the next space or comma.
+fn value_after<'a>(message: &'a str, key: &str) -> Option<&'a str> {
+	let start = message.find(key)? + key.len();
+	let rest = &message[start..];
+	let end = rest.find([' ', ',']).unwrap_or(rest.len());
+	Some(&rest[..end])
+}
+
+/// `Out of memory: Killed process 1234 (stress) total-vm:123456kB, anon-rss:65432kB, ...`
+fn oom_kill(message: &str) -> Option<KernelEvent> {
+	let rest = message.split_once("Killed process ")?.1;
+	let (pid, rest) = rest.split_once(" (")?;
+	let (comm, rest) = rest.split_once(')')?;
+	let size = |key| value_after(rest, key).and_then(number).unwrap_or(0);
+	Some(KernelEvent::OomKill {
+		pid: pid.parse().ok()?,
+		comm: comm.to_string(),
+		total_vm: size("total-vm:"),
+		anon_rss: size("anon-rss:"),
+		file_rss: size("file-rss:"),
+		shmem_rss: size("shmem-rss:"),
+	})
+}
+
+/// `INFO: task kworker/0:1:123 blocked for more than 120 seconds.`
+fn hung_task(message: &str) -> Option<KernelEvent> {
+	let rest = message.strip_prefix("INFO: task ")?;
+	let (task_name, rest) = rest.split_once(" blocked for more than ")?;
+	let (comm, pid) = task(task_name, ':')?;
+	Some(KernelEvent::HungTask { pid, comm, seconds: number(rest)? })
+}
+
+/// `watchdog: BUG: soft lockup - CPU#3 stuck for 22s! [java:4567]`
+fn soft_lockup(message: &str) -> Option<KernelEvent> {
+	let rest = message.split_once("soft lockup - CPU#")?.1;
+	let (cpu, rest) = rest.split_once(" stuck for ")?;
+	let (seconds, rest) = rest.split_once("s! [")?;
+	let (comm, pid) = task(rest.trim_end_matches(']'), ':')?;
+	Some(KernelEvent::SoftLockup {
+		cpu: cpu.parse().ok()?,
+		seconds: seconds.parse().ok()?,
+		pid,
+		comm,
+	})
+}
+
+/// `blk_update_request: I/O error, dev sda, sector 12345 op 0x0:(READ) ...` or
+/// `Buffer I/O error on dev sda1, logical block 0, async page read`
+fn io_error(message: &str) -> Option<KernelEvent> {
+	if let Some(rest) = message.split_once("I/O error, dev ").map(|(_, rest)| rest) {
+		let (device, rest) = rest.split_once(',').unwrap_or((rest, ""));
+		let sector = value_after(rest, "sector ").and_then(number);
+		return Some(KernelEvent::IoError { device: device.to_string(), sector });
+	}
+	let rest = message.split_once("Buffer I/O error on dev ")?.1;
+	let device = rest.split(',').next()?;
+	Some(KernelEvent::IoError { device: device.to_string(), sector: None })
+}
+
+/// `myapp[4321]: segfault at 0 ip 000055d5c8a0 sp 00007ffc4b10 error 4 in myapp[55d5c8a00000+1000]`
+fn segfault(message: &str) -> Option<KernelEvent> {
+	let (task_name, rest) = message.split_once(": segfault at ")?;
+	let (comm, pid) = task(task_name.trim_end_matches(']'), '[')?;
+	let address = rest.split(' ').next()?;
+	Some(KernelEvent::Segfault {
+		pid,
+		comm,
+		address: address.to_string(),
+		ip: value_after(rest, " ip ")?.to_string(),
+		error: value_after(rest, " error ").and_then(number).unwrap_or(0),
+	})
+}
+
+/// `e1000e: eth0 NIC Link is Up 1000 Mbps Full Duplex, Flow Control: None`,
+/// `ixgbe 0000:01:00.0 eth1: NIC Link is Up 10 Gbps, Flow Control: RX/TX` or
+/// `igb 0000:02:00.0 eno1: NIC Link is Down`
+fn link(message: &str) -> Option<KernelEvent> {
+	let (head, rest) = message.split_once("NIC Link is ")?;
+	let interface = head.split_whitespace().last()?.trim_end_matches(':');
+	let up = match rest.get(..2)? {
+		"Up" => true,
+		"Do" => false,
+		_ => return None,
+	};
+	let speed = up.then(|| {
+		let mut words = rest[2..].trim_start_matches([',', ' ']).split_whitespace();
+		let value: u64 = words.next()?.parse().ok()?;
+		match words.next()?.trim_end_matches(',') {
+			"Gbps" => Some(value * 1000),
+			"Mbps" => Some(value),
+			_ => None,
+		}
+	});
+	Some(KernelEvent::Link { interface: interface.to_string(), up, speed: speed.flatten() })
+}
+
+/// Recognises the conditions worth an event, `None` for any other message.
+pub fn classify(message: &str) -> Option<KernelEvent> {
+	if message.contains("Killed process ") {
+		oom_kill(message)
+	} else if message.starts_with("INFO: task ") {
+		hung_task(message)
+	} else if message.contains("soft lockup - CPU#") {
+		soft_lockup(message)
+	} else if message.contains("I/O error") {
+		io_error(message)
+	} else if message.contains(": segfault at ") {
+		segfault(message)
+	} else if message.contains("NIC Link is ") {
+		link(message)
+	} else {
+		None
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_parse() {
+		let record =
+			parse("3,1234,5678901,-;blk_update_request: I/O error\n SUBSYSTEM=block\n").unwrap();
+		assert_eq!(record.level(), 3);
+		assert_eq!(record.facility(), 0);
+		assert_eq!(record.sequence(), 1234);
+		assert_eq!(record.timestamp(), Duration::from_micros(5678901));
+		assert!(!record.continuation());
+		assert_eq!(record.message(), "blk_update_request: I/O error");
+
+		let record = parse("14,5,10,c,more;user; message").unwrap();
+		assert_eq!((record.level(), record.facility()), (6, 1));
+		assert!(record.continuation());
+		assert_eq!(record.message(), "user; message");
+
+		assert_eq!(parse(" SUBSYSTEM=block"), None);
+	}
+
+	#[test]
+	fn test_classify() {
+		assert_eq!(
+			classify(
+				"Out of memory: Killed process 1234 (stress) total-vm:123456kB, anon-rss:65432kB, file-rss:12kB, shmem-rss:0kB, UID:0 pgtables:400kB oom_score_adj:0"
+			),
+			Some(KernelEvent::OomKill {
+				pid: 1234,
+				comm: "stress".to_string(),
+				total_vm: 123456,
+				anon_rss: 65432,
+				file_rss: 12,
+				shmem_rss: 0
+			})
+		);
+		assert_eq!(
+			classify("INFO: task kworker/0:1:123 blocked for more than 120 seconds."),
+			Some(KernelEvent::HungTask { pid: 123, comm: "kworker/0:1".to_string(), seconds: 120 })
+		);
+		assert_eq!(
+			classify("watchdog: BUG: soft lockup - CPU#3 stuck for 22s! [java:4567]"),
+			Some(KernelEvent::SoftLockup {
+				cpu: 3,
+				seconds: 22,
+				pid: 4567,
+				comm: "java".to_string()
+			})
+		);
+		assert_eq!(
+			classify(
+				"I/O error, dev sda, sector 12345 op 0x0:(READ) flags 0x0 phys_seg 1 prio class 2"
+			),
+			Some(KernelEvent::IoError { device: "sda".to_string(), sector: Some(12345) })
+		);
+		assert_eq!(
+			classify("Buffer I/O error on dev sda1, logical block 0, async page read"),
+			Some(KernelEvent::IoError { device: "sda1".to_string(), sector: None })
+		);
+		assert_eq!(
+			classify(
+				"my[app][4321]: segfault at 0 ip 000055d5c8a0 sp 00007ffc4b10 error 4 in myapp[55d5c8a00000+1000]"
+			),
+			Some(KernelEvent::Segfault {
+				pid: 4321,
+				comm: "my[app]".to_string(),
+				address: "0".to_string(),
+				ip: "000055d5c8a0".to_string(),
+				error: 4
+			})
+		);
+		assert_eq!(
+			classify("e1000e: eth0 NIC Link is Up 1000 Mbps Full Duplex, Flow Control: None"),
+			Some(KernelEvent::Link { interface: "eth0".to_string(), up: true, speed: Some(1000) })
+		);
+		assert_eq!(
+			classify("ixgbe 0000:01:00.0 eth1: NIC Link is Up 10 Gbps, Flow Control: RX/TX"),
+			Some(KernelEvent::Link { interface: "eth1".to_string(), up: true, speed: Some(10000) })
+		);
+		assert_eq!(
+			classify("igb 0000:02:00.0 eno1: NIC Link is Down"),
+			Some(KernelEvent::Link { interface: "eno1".to_string(), up: false, speed: None })
+		);
+		assert_eq!(classify("usb 1-1: new high-speed USB device number 2"), None);
+	}
+}
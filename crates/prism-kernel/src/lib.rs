@@ -0,0 +1,9 @@
+//! Collect kernel table metrics and kernel log events.
+pub use error::KernelMetricError;
+
+pub mod conntrack;
+mod error;
+pub mod fs;
+pub mod kmsg;
+pub mod swaps;
+pub mod tasks;
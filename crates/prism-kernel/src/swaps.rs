@@ -0,0 +1,74 @@
+//! Swap areas
+//!
+//! `/proc/swaps` lists the swap areas in use, one per line after a header:
+//! `Filename Type Size Used Priority`. Sizes are in kilobytes.
+
+use crate::KernelMetricError;
+use prism_macros::ProcParser;
+use prism_metric_common::procfs_root;
+use prism_metric_utils::read_to_string;
+use uom::si::{f64::Information, information::kilobyte};
+
+#[derive(ProcParser)]
+#[fmt = "table"]
+pub struct Swap {
+	/// Swap device or file, e.g. `/dev/dm-1` or `/swapfile`
+	#[arg(index = 0)]
+	filename: String,
+	/// `partition` or `file`
+	#[arg(index = 1)]
+	kind: String,
+	/// Size of the swap area
+	#[arg(index = 2, unit = kilobyte)]
+	size: Information,
+	/// Swap space in use
+	#[arg(index = 3, unit = kilobyte)]
+	used: Information,
+	/// Priority, higher priority areas are used first
+	#[arg(index = 4)]
+	priority: i64,
+}
+
+impl Swap {
+	/// Used space over size, `None` for an empty swap area.
+	pub fn utilization(&self) -> Option<f64> {
+		let size = self.size.get::<kilobyte>();
+		(size > 0.0).then(|| self.used.get::<kilobyte>() / size)
+	}
+}
+
+/// Parses the content of `/proc/swaps`, skipping the header line.
+pub fn parse(content: &str) -> Result<Vec<Swap>, KernelMetricError> {
+	let body = content.lines().skip(1).collect::<Vec<_>>().join("\n");
+	Swap::parse_all(&body).map_err(Into::into)
+}
+
+pub async fn swaps() -> Result<Vec<Swap>, KernelMetricError> {
+	let content = read_to_string(procfs_root().join("swaps")).await?;
+	parse(&content)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_parse() {
+		let content = "Filename				Type		Size		Used		Priority
+/dev/dm-1                               partition	8388604		1024		-2
+/swapfile                               file		2097148		0		10
+";
+		let swaps = parse(content).unwrap();
+		assert_eq!(swaps.len(), 2);
+		assert_eq!(swaps[0].get_filename(), "/dev/dm-1");
+		assert_eq!(swaps[0].get_kind(), "partition");
+		assert_eq!(swaps[0].get_size().get::<kilobyte>(), 8388604.0);
+		assert_eq!(swaps[0].get_used().get::<kilobyte>(), 1024.0);
+		assert_eq!(*swaps[0].get_priority(), -2);
+		assert_eq!(swaps[1].get_kind(), "file");
+		assert_eq!(*swaps[1].get_priority(), 10);
+		assert_eq!(swaps[1].utilization(), Some(0.0));
+
+		assert!(parse("Filename\tType\tSize\tUsed\tPriority\n").unwrap().is_empty());
+	}
+}
@@ -0,0 +1,110 @@
+//! Task and entropy limits
+//!
+//! `/proc/sys/kernel/pid_max` and `/proc/sys/kernel/threads-max` bound the
+//! number of tasks. The current number of tasks is the fourth field of
+//! `/proc/loadavg` (`running/total`); every task holds a pid, so it is measured
+//! against both limits. `/proc/sys/kernel/random/entropy_avail` is the entropy
+//! pool fill level out of `poolsize` bits.
+
+use crate::KernelMetricError;
+use prism_metric_common::procfs_root;
+use prism_metric_utils::read_to_string;
+use std::io;
+
+/// Task counts and limits
+pub struct Tasks {
+	/// Tasks (processes and threads) currently existing
+	count: u64,
+	/// Largest pid plus one (`pid_max`)
+	pid_max: u64,
+	/// Maximum number of threads (`threads-max`)
+	threads_max: u64,
+}
+
+impl Tasks {
+	pub const fn count(&self) -> u64 {
+		self.count
+	}
+
+	pub const fn pid_max(&self) -> u64 {
+		self.pid_max
+	}
+
+	pub const fn threads_max(&self) -> u64 {
+		self.threads_max
+	}
+
+	/// Tasks over `pid_max`.
+	pub fn pid_utilization(&self) -> f64 {
+		self.count as f64 / self.pid_max.max(1) as f64
+	}
+
+	/// Tasks over `threads-max`.
+	pub fn threads_utilization(&self) -> f64 {
+		self.count as f64 / self.threads_max.max(1) as f64
+	}
+}
+
+/// Entropy pool fill level
+pub struct Entropy {
+	/// Available entropy, in bits
+	available: u64,
+	/// Pool size, in bits
+	pool_size: u64,
+}
+
+impl Entropy {
+	pub const fn available(&self) -> u64 {
+		self.available
+	}
+
+	pub const fn pool_size(&self) -> u64 {
+		self.pool_size
+	}
+
+	/// Available entropy over the pool size.
+	pub fn utilization(&self) -> f64 {
+		self.available as f64 / self.pool_size.max(1) as f64
+	}
+}
+
+async fn read_value(path: &str) -> Result<u64, KernelMetricError> {
+	let content = read_to_string(procfs_root().join(path)).await?;
+	content.trim().parse().map_err(Into::into)
+}
+
+/// Parses the total number of tasks out of `/proc/loadavg`.
+fn parse_task_count(loadavg: &str) -> Result<u64, KernelMetricError> {
+	let total = loadavg
+		.split_whitespace()
+		.nth(3)
+		.and_then(|tasks| tasks.split_once('/'))
+		.map(|(_, total)| total)
+		.ok_or_else(|| io::Error::from(io::ErrorKind::InvalidData))?;
+	total.parse().map_err(Into::into)
+}
+
+pub async fn tasks() -> Result<Tasks, KernelMetricError> {
+	let loadavg = read_to_string(procfs_root().join("loadavg")).await?;
+	let count = parse_task_count(&loadavg)?;
+	let pid_max = read_value("sys/kernel/pid_max").await?;
+	let threads_max = read_value("sys/kernel/threads-max").await?;
+	Ok(Tasks { count, pid_max, threads_max })
+}
+
+pub async fn entropy() -> Result<Entropy, KernelMetricError> {
+	let available = read_value("sys/kernel/random/entropy_avail").await?;
+	let pool_size = read_value("sys/kernel/random/poolsize").await?;
+	Ok(Entropy { available, pool_size })
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_parse_task_count() {
+		assert_eq!(parse_task_count("0.52 0.58 0.59 3/1234 56789\n").unwrap(), 1234);
+		assert!(parse_task_count("0.52 0.58 0.59\n").is_err());
+	}
+}
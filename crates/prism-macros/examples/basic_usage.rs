@@ -4,19 +4,19 @@ use uom::si::f64::Information;
 #[derive(ProcParser)]
 #[fmt = "kv"]
 struct MemInfo {
-	#[key = "MemTotal"]
+	#[arg(key = "MemTotal")]
 	mem_total: Option<Information>,
 
-	#[key = "MemFree"]
+	#[arg(key = "MemFree")]
 	mem_free: Option<Information>,
 
-	#[key = "MemAvailable"]
+	#[arg(key = "MemAvailable")]
 	mem_available: Option<Information>,
 
-	#[key = "Buffers"]
+	#[arg(key = "Buffers")]
 	buffers: Option<Information>,
 
-	#[key = "Cached"]
+	#[arg(key = "Cached")]
 	cached: Option<Information>,
 
 	// Handle complex field names with parentheses
@@ -8,9 +8,9 @@ use uom::si::{
 #[fmt = "kv"]
 struct CustomUnitTest {
 	mem_total: Information,
-	#[unit = "uom::si::information::kilobyte"]
+	#[arg(unit = kilobyte)]
 	mem_free: Information,
-	#[unit = "uom::si::information::megabyte"]
+	#[arg(unit = megabyte)]
 	mem_available: Information,
 }
 
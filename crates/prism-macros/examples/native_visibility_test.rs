@@ -1,3 +1,5 @@
+#![allow(clippy::needless_pub_self)]
+
 use prism_macros::ProcParser;
 use uom::si::f64::Information;
 
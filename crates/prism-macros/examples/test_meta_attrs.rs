@@ -1,3 +1,5 @@
+#![allow(non_snake_case)]
+
 use prism_macros::ProcParser;
 use uom::si::f64::Information;
 
@@ -22,12 +22,14 @@ fn impl_struct(s: &Struct) -> TokenStream {
 
 	let parse_impl = generate_parse_impl(s, *format);
 	let getters = generate_field_getters(&s.fields, Some(s.visibility));
+	let descriptions = generate_field_descriptions(&s.fields, Some(s.visibility));
 	let helper_functions = generate_conversion_helpers();
 
 	quote! {
 		impl #struct_name {
 			#parse_impl
 			#(#getters)*
+			#(#descriptions)*
 			#helper_functions
 		}
 	}
@@ -434,19 +436,7 @@ fn generate_field_getters(
 			};
 
 			let ty = field.ty;
-
-			// Use field visibility if it's more restrictive than private,
-			// otherwise use struct visibility as fallback
-			let vis = match field.visibility {
-				syn::Visibility::Inherited => {
-					// Field has no explicit visibility, use struct visibility
-					struct_visibility.map(|v| quote! { #v }).unwrap_or_else(|| quote! { pub })
-				},
-				field_vis => {
-					// Field has explicit visibility, use it
-					quote! { #field_vis }
-				},
-			};
+			let vis = field_visibility(field, struct_visibility);
 
 			quote! {
 				paste::paste! {
@@ -477,3 +467,45 @@ fn generate_conversion_helpers() -> TokenStream {
 		}
 	}
 }
+
+/// Exposes the doc comment of each field as a `<FIELD>_DESCRIPTION` constant,
+/// so that collectors can describe the series they build from it.
+fn generate_field_descriptions(
+	fields: &[Field],
+	struct_visibility: Option<&syn::Visibility>,
+) -> Vec<TokenStream> {
+	fields
+		.iter()
+		.filter(|field| !field.attrs.docs.trim().is_empty())
+		.map(|field| {
+			let field_name = match &field.member {
+				crate::ast::MemberUnraw::Named(ident) => &ident.raw,
+				_ => panic!("Only named fields are supported"),
+			};
+			let vis = field_visibility(field, struct_visibility);
+			let description = field.attrs.docs.lines().map(str::trim).collect::<Vec<_>>().join(" ");
+
+			quote! {
+				paste::paste! {
+					#[allow(dead_code)]
+					#vis const [<#field_name:upper _DESCRIPTION>]: &'static str = #description;
+				}
+			}
+		})
+		.collect()
+}
+
+/// Use field visibility if it's more restrictive than private, otherwise use
+/// struct visibility as fallback
+fn field_visibility(field: &Field, struct_visibility: Option<&syn::Visibility>) -> TokenStream {
+	match field.visibility {
+		syn::Visibility::Inherited => {
+			// Field has no explicit visibility, use struct visibility
+			struct_visibility.map(|v| quote! { #v }).unwrap_or_else(|| quote! { pub })
+		},
+		field_vis => {
+			// Field has explicit visibility, use it
+			quote! { #field_vis }
+		},
+	}
+}
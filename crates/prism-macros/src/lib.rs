@@ -19,6 +19,11 @@
 //! - `#[with = "path::to::parser"]` - custom parser function
 //! - `#[optional]` - mark field as optional
 //!
+//! ## Descriptions
+//! The doc comment of each field is exposed as a `<FIELD>_DESCRIPTION`
+//! constant, e.g. `CpuTime::USER_DESCRIPTION`, so that collectors can
+//! describe the series they build from it.
+//!
 //! ## Visibility Control
 //! Generated methods inherit the visibility of the struct definition:
 //! - `struct MyStruct` → `fn get_field()` (private)
@@ -0,0 +1,71 @@
+//! Buddy allocator free lists
+//!
+//! Each line of `/proc/buddyinfo` describes one memory zone and lists the
+//! number of free blocks of every order, a block of order `n` being made of
+//! `2^n` contiguous pages. Few free blocks at high orders indicate that memory
+//! is fragmented even when plenty of pages are free.
+
+use crate::{MemoryMetricError, zoneinfo::parse_zone_header};
+use prism_metric_common::procfs_root;
+use prism_metric_utils::read_to_string;
+
+/// Free blocks of a zone from `/proc/buddyinfo`
+pub struct BuddyInfo {
+	/// NUMA node the zone belongs to
+	node: u32,
+	/// Zone name, e.g. `DMA32` or `Normal`
+	zone: String,
+	/// Number of free blocks, indexed by order
+	free: Vec<u64>,
+}
+
+impl BuddyInfo {
+	pub const fn node(&self) -> u32 {
+		self.node
+	}
+
+	pub fn zone(&self) -> &str {
+		&self.zone
+	}
+
+	pub fn free(&self) -> &[u64] {
+		&self.free
+	}
+}
+
+/// Parses the content of `/proc/buddyinfo`.
+pub fn parse(content: &str) -> Result<Vec<BuddyInfo>, MemoryMetricError> {
+	content
+		.lines()
+		.filter_map(parse_zone_header)
+		.map(|(node, zone, counts)| {
+			let free = counts.split_whitespace().map(str::parse).collect::<Result<_, _>>()?;
+			Ok(BuddyInfo { node, zone: zone.to_string(), free })
+		})
+		.collect()
+}
+
+pub async fn buddyinfo() -> Result<Vec<BuddyInfo>, MemoryMetricError> {
+	let content = read_to_string(procfs_root().join("buddyinfo")).await?;
+	parse(&content)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_parse() {
+		let content = "Node 0, zone      DMA      0      0      0      0      0      0      0      0      1      1      3
+Node 0, zone    DMA32      5      4      6      5      5      4      4      3      5      5    417
+Node 0, zone   Normal  12582   3949   1233    382     66     11      1      0      0      0      0
+";
+		let zones = parse(content).unwrap();
+		assert_eq!(zones.len(), 3);
+		assert_eq!((zones[0].node(), zones[0].zone()), (0, "DMA"));
+		assert_eq!(zones[0].free(), [0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 3]);
+		assert_eq!(zones[2].zone(), "Normal");
+		assert_eq!(zones[2].free()[0], 12582);
+		assert_eq!(zones[2].free().len(), 11);
+	}
+}
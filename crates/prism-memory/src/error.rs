@@ -1,4 +1,4 @@
-use std::io;
+use std::{io, num};
 use thiserror::Error;
 
 #[derive(Debug, Error)]
@@ -7,4 +7,6 @@ pub enum MemoryMetricError {
 	IOError(#[from] io::Error),
 	#[error("Parse error: {0}")]
 	ParseError(#[from] Box<dyn std::error::Error>),
+	#[error("Parse int error: {0}")]
+	ParseIntError(#[from] num::ParseIntError),
 }
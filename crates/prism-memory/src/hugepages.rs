@@ -0,0 +1,105 @@
+//! Huge page pools
+//!
+//! `/proc/meminfo` only reports the default huge page size. Every supported
+//! size has its own pool under `/sys/kernel/mm/hugepages/hugepages-<size>kB`,
+//! and NUMA nodes expose the same layout under
+//! `/sys/devices/system/node/node<N>/hugepages`.
+
+use super::MemoryMetricError;
+use prism_metric_common::sysfs_root;
+use prism_metric_utils::{read_dir, read_to_string};
+use std::{io, path::Path};
+use uom::si::{f64::Information, information::kilobyte};
+
+/// A huge page pool of a single page size
+pub struct HugePagePool {
+	/// Huge page size
+	size: Information,
+	/// Number of huge pages in the pool (`nr_hugepages`)
+	nr: u64,
+	/// Number of huge pages not yet allocated (`free_hugepages`)
+	free: u64,
+	/// Number of huge pages above `nr_hugepages` allocated through
+	/// overcommit (`surplus_hugepages`)
+	surplus: u64,
+}
+
+impl HugePagePool {
+	pub const fn size(&self) -> Information {
+		self.size
+	}
+
+	pub const fn nr(&self) -> u64 {
+		self.nr
+	}
+
+	pub const fn free(&self) -> u64 {
+		self.free
+	}
+
+	pub const fn surplus(&self) -> u64 {
+		self.surplus
+	}
+
+	async fn read(dir: &Path, size: Information) -> Result<Self, MemoryMetricError> {
+		let nr = read_count(dir, "nr_hugepages").await?;
+		let free = read_count(dir, "free_hugepages").await?;
+		let surplus = read_count(dir, "surplus_hugepages").await?;
+		Ok(Self { size, nr, free, surplus })
+	}
+}
+
+async fn read_count(dir: &Path, name: &str) -> Result<u64, MemoryMetricError> {
+	let content = read_to_string(dir.join(name)).await?;
+	content.trim().parse().map_err(Into::into)
+}
+
+/// Parses the page size out of a `hugepages-2048kB` directory name.
+fn parse_pool_size(name: &str) -> Option<Information> {
+	let size = name.strip_prefix("hugepages-")?.strip_suffix("kB")?;
+	size.parse::<u64>().ok().map(|size| Information::new::<kilobyte>(size as f64))
+}
+
+/// Reads every huge page pool under `dir`, sorted by page size.
+///
+/// Kernels built without `CONFIG_HUGETLBFS` have no such directory, which
+/// yields an empty list.
+pub(crate) async fn read_pools(dir: &Path) -> Result<Vec<HugePagePool>, MemoryMetricError> {
+	let entries = match read_dir(dir.to_owned()).await {
+		Ok(entries) => entries,
+		Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+		Err(e) => return Err(e.into()),
+	};
+
+	let mut pools = Vec::with_capacity(entries.len());
+	for entry in entries {
+		let Some(size) = entry.file_name().and_then(|name| name.to_str()).and_then(parse_pool_size)
+		else {
+			continue;
+		};
+		pools.push(HugePagePool::read(&entry, size).await?);
+	}
+	pools.sort_by(|a, b| a.size.value.total_cmp(&b.size.value));
+	Ok(pools)
+}
+
+/// Reads the system wide huge page pools from `/sys/kernel/mm/hugepages`.
+pub async fn hugepages() -> Result<Vec<HugePagePool>, MemoryMetricError> {
+	read_pools(&sysfs_root().join("kernel/mm/hugepages")).await
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_parse_pool_size() {
+		assert_eq!(parse_pool_size("hugepages-2048kB").map(|s| s.get::<kilobyte>()), Some(2048.0));
+		assert_eq!(
+			parse_pool_size("hugepages-1048576kB").map(|s| s.get::<kilobyte>()),
+			Some(1048576.0)
+		);
+		assert!(parse_pool_size("hugepages-2048").is_none());
+		assert!(parse_pool_size("nr_hugepages").is_none());
+	}
+}
@@ -1,6 +1,11 @@
 //! Collect memory metrics.
 pub use error::MemoryMetricError;
 
+pub mod buddyinfo;
 mod error;
+pub mod hugepages;
 pub mod meminfo;
+pub mod numa;
+pub mod pagetypeinfo;
 pub mod vmstat;
+pub mod zoneinfo;
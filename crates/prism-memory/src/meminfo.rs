@@ -161,6 +161,35 @@ pub struct MemInfo {
 	#[arg(unit = kilobyte)]
 	VmallocChunk: Information,
 	// TODO: Percpu is ignored because it don't exist on docs.
+	/// (CONFIG_HUGETLB_PAGE is required.)
+	/// The size of the pool of huge pages of the default size. The other
+	/// supported sizes are reported by [`hugepages`](crate::hugepages).
+	#[arg(key = "HugePages_Total")]
+	HugePages_Total: u64,
+	/// (CONFIG_HUGETLB_PAGE is required.)
+	/// The number of huge pages in the pool that are not yet allocated.
+	#[arg(key = "HugePages_Free")]
+	HugePages_Free: u64,
+	/// (since Linux 2.6.17)
+	/// (CONFIG_HUGETLB_PAGE is required.)
+	/// This is the number of huge pages for which a commitment to allocate
+	/// from the pool has been made, but no allocation has yet been made.
+	#[arg(key = "HugePages_Rsvd")]
+	HugePages_Rsvd: u64,
+	/// (since Linux 2.6.24)
+	/// (CONFIG_HUGETLB_PAGE is required.)
+	/// This is the number of huge pages in the pool above the value in
+	/// /proc/sys/vm/nr_hugepages.
+	#[arg(key = "HugePages_Surp")]
+	HugePages_Surp: u64,
+	/// (CONFIG_HUGETLB_PAGE is required.)
+	/// The size of huge pages.
+	#[arg(unit = kilobyte)]
+	Hugepagesize: Information,
+	/// (since Linux 4.16)
+	/// Total amount of memory consumed by huge pages of all sizes.
+	#[arg(unit = kilobyte)]
+	Hugetlb: Option<Information>,
 }
 
 pub async fn meminfo() -> Result<MemInfo, MemoryMetricError> {
@@ -0,0 +1,206 @@
+//! NUMA nodes
+//!
+//! Every `/sys/devices/system/node/node<N>` directory describes one NUMA node.
+//! `meminfo` has the `/proc/meminfo` layout restricted to the node, with every
+//! line prefixed by `Node <N>`. `numastat` holds the node's allocation
+//! counters and `hugepages` its share of every huge page pool.
+#![allow(non_snake_case)]
+
+use super::{
+	MemoryMetricError,
+	hugepages::{HugePagePool, read_pools},
+};
+use prism_macros::ProcParser;
+use prism_metric_common::sysfs_root;
+use prism_metric_utils::{read_dir, read_to_string};
+use std::{io, path::Path};
+use uom::si::{f64::Information, information::kilobyte};
+
+/// Memory usage of a single node from `node<N>/meminfo`
+#[derive(ProcParser)]
+#[fmt = "kv"]
+pub struct NodeMemInfo {
+	/// Total usable RAM of the node.
+	#[arg(unit = kilobyte)]
+	MemTotal: Information,
+	/// Free RAM of the node.
+	#[arg(unit = kilobyte)]
+	MemFree: Information,
+	/// [`MemTotal`](Self::MemTotal) minus [`MemFree`](Self::MemFree).
+	#[arg(unit = kilobyte)]
+	MemUsed: Information,
+	/// Memory that has been used more recently and usually not reclaimed
+	/// unless absolutely necessary.
+	#[arg(unit = kilobyte)]
+	Active: Information,
+	/// Memory which has been less recently used. It is more eligible to be
+	/// reclaimed for other purposes.
+	#[arg(unit = kilobyte)]
+	Inactive: Information,
+	#[arg(key = "Active(anon)", unit = kilobyte)]
+	Active_anon: Information,
+	#[arg(key = "Inactive(anon)", unit = kilobyte)]
+	Inactive_anon: Information,
+	#[arg(key = "Active(file)", unit = kilobyte)]
+	Active_file: Information,
+	#[arg(key = "Inactive(file)", unit = kilobyte)]
+	Inactive_file: Information,
+	/// Memory which is waiting to get written back to the disk.
+	#[arg(unit = kilobyte)]
+	Dirty: Information,
+	/// Memory which is actively being written back to the disk.
+	#[arg(unit = kilobyte)]
+	Writeback: Information,
+	/// Page cache pages of the node.
+	#[arg(unit = kilobyte)]
+	FilePages: Information,
+	/// Files which have been mapped into memory, such as libraries.
+	#[arg(unit = kilobyte)]
+	Mapped: Information,
+	/// Non-file backed pages mapped into user-space page tables.
+	#[arg(unit = kilobyte)]
+	AnonPages: Information,
+	/// Memory consumed in tmpfs filesystems and shared memory.
+	#[arg(unit = kilobyte)]
+	Shmem: Information,
+	/// In-kernel data structures cache.
+	#[arg(unit = kilobyte)]
+	Slab: Information,
+	/// Part of [`Slab`](Self::Slab), that might be reclaimed, such as caches.
+	#[arg(unit = kilobyte)]
+	SReclaimable: Information,
+	/// Part of [`Slab`](Self::Slab), that cannot be reclaimed on memory
+	/// pressure.
+	#[arg(unit = kilobyte)]
+	SUnreclaim: Information,
+	/// The default size huge page pool of the node.
+	#[arg(key = "HugePages_Total")]
+	HugePages_Total: u64,
+	/// The number of huge pages of the node that are not yet allocated.
+	#[arg(key = "HugePages_Free")]
+	HugePages_Free: u64,
+	/// The number of surplus huge pages of the node.
+	#[arg(key = "HugePages_Surp")]
+	HugePages_Surp: u64,
+}
+
+/// Allocation counters of a single node from `node<N>/numastat`
+#[derive(ProcParser)]
+#[fmt = "space"]
+pub struct NumaStat {
+	/// Pages successfully allocated on this node, as intended
+	numa_hit: u64,
+	/// Pages allocated on this node despite the process preferring some
+	/// other node
+	numa_miss: u64,
+	/// Pages intended for this node but allocated on some other node
+	numa_foreign: u64,
+	/// Interleave policy pages successfully allocated on this node
+	interleave_hit: u64,
+	/// Pages allocated on this node while the process was running on it
+	local_node: u64,
+	/// Pages allocated on this node while the process was running on some
+	/// other node
+	other_node: u64,
+}
+
+/// A NUMA node from `/sys/devices/system/node/node<N>`
+pub struct Node {
+	/// Node id, e.g. `0` for `node0`
+	id: u32,
+	meminfo: NodeMemInfo,
+	numastat: NumaStat,
+	hugepages: Vec<HugePagePool>,
+}
+
+impl Node {
+	pub const fn id(&self) -> u32 {
+		self.id
+	}
+
+	pub const fn meminfo(&self) -> &NodeMemInfo {
+		&self.meminfo
+	}
+
+	pub const fn numastat(&self) -> &NumaStat {
+		&self.numastat
+	}
+
+	pub fn hugepages(&self) -> &[HugePagePool] {
+		&self.hugepages
+	}
+
+	async fn read(dir: &Path, id: u32) -> Result<Self, MemoryMetricError> {
+		let content = read_to_string(dir.join("meminfo")).await?;
+		let meminfo = NodeMemInfo::parse(&strip_node_prefix(&content))?;
+		let content = read_to_string(dir.join("numastat")).await?;
+		let numastat = NumaStat::parse(&content)?;
+		let hugepages = read_pools(&dir.join("hugepages")).await?;
+		Ok(Self { id, meminfo, numastat, hugepages })
+	}
+}
+
+/// Removes the `Node <N> ` prefix of every `node<N>/meminfo` line so that it
+/// can be parsed like `/proc/meminfo`.
+fn strip_node_prefix(content: &str) -> String {
+	content
+		.lines()
+		.map(|line| {
+			line.strip_prefix("Node ")
+				.and_then(|rest| rest.trim_start_matches(|c: char| c.is_ascii_digit()).get(1..))
+				.unwrap_or(line)
+		})
+		.collect::<Vec<_>>()
+		.join("\n")
+}
+
+fn parse_node_id(name: &str) -> Option<u32> {
+	name.strip_prefix("node")?.parse().ok()
+}
+
+/// Reads every NUMA node under `/sys/devices/system/node`, sorted by id.
+///
+/// Kernels built without `CONFIG_NUMA` have no such directory, which yields
+/// an empty list.
+pub async fn nodes() -> Result<Vec<Node>, MemoryMetricError> {
+	let root = sysfs_root().join("devices/system/node");
+	let entries = match read_dir(root).await {
+		Ok(entries) => entries,
+		Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+		Err(e) => return Err(e.into()),
+	};
+
+	let mut nodes = Vec::with_capacity(entries.len());
+	for entry in entries {
+		let Some(id) = entry.file_name().and_then(|name| name.to_str()).and_then(parse_node_id)
+		else {
+			continue;
+		};
+		nodes.push(Node::read(&entry, id).await?);
+	}
+	nodes.sort_by_key(Node::id);
+	Ok(nodes)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_strip_node_prefix() {
+		let content = "Node 0 MemTotal:       32823964 kB\nNode 12 HugePages_Free:      3\n";
+		assert_eq!(
+			strip_node_prefix(content),
+			"MemTotal:       32823964 kB\nHugePages_Free:      3"
+		);
+	}
+
+	#[test]
+	fn test_parse_node_id() {
+		assert_eq!(parse_node_id("node0"), Some(0));
+		assert_eq!(parse_node_id("node17"), Some(17));
+		assert_eq!(parse_node_id("node"), None);
+		assert_eq!(parse_node_id("possible"), None);
+		assert_eq!(parse_node_id("has_memory"), None);
+	}
+}
@@ -0,0 +1,101 @@
+//! Free blocks per migrate type
+//!
+//! `/proc/pagetypeinfo` breaks the `/proc/buddyinfo` free lists down by
+//! migrate type (`Unmovable`, `Movable`, `Reclaimable`, ...). Only the
+//! `Free pages count per migrate type at order` table is parsed. The file is
+//! readable by root only on recent kernels.
+
+use crate::{MemoryMetricError, zoneinfo::parse_zone_header};
+use prism_metric_common::procfs_root;
+use prism_metric_utils::read_to_string;
+
+/// Free blocks of a zone and migrate type from `/proc/pagetypeinfo`
+pub struct PageTypeInfo {
+	/// NUMA node the zone belongs to
+	node: u32,
+	/// Zone name, e.g. `DMA32` or `Normal`
+	zone: String,
+	/// Migrate type, e.g. `Unmovable`
+	migrate_type: String,
+	/// Number of free blocks, indexed by order
+	free: Vec<u64>,
+}
+
+impl PageTypeInfo {
+	pub const fn node(&self) -> u32 {
+		self.node
+	}
+
+	pub fn zone(&self) -> &str {
+		&self.zone
+	}
+
+	pub fn migrate_type(&self) -> &str {
+		&self.migrate_type
+	}
+
+	pub fn free(&self) -> &[u64] {
+		&self.free
+	}
+}
+
+/// Parses the content of `/proc/pagetypeinfo`.
+pub fn parse(content: &str) -> Result<Vec<PageTypeInfo>, MemoryMetricError> {
+	let mut infos = Vec::new();
+	for (node, zone, rest) in content.lines().filter_map(parse_zone_header) {
+		// Lines of the `Number of blocks type` table carry no `type` field.
+		let Some(rest) = rest.strip_prefix(',').map(str::trim_start) else {
+			continue;
+		};
+		let Some(rest) = rest.strip_prefix("type") else {
+			continue;
+		};
+		let mut fields = rest.split_whitespace();
+		let Some(migrate_type) = fields.next() else {
+			continue;
+		};
+		let free = fields.map(str::parse).collect::<Result<_, _>>()?;
+		infos.push(PageTypeInfo {
+			node,
+			zone: zone.to_string(),
+			migrate_type: migrate_type.to_string(),
+			free,
+		});
+	}
+	Ok(infos)
+}
+
+pub async fn pagetypeinfo() -> Result<Vec<PageTypeInfo>, MemoryMetricError> {
+	let content = read_to_string(procfs_root().join("pagetypeinfo")).await?;
+	parse(&content)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_parse() {
+		let content = "Page block order: 9
+Pages per block:  512
+
+Free pages count per migrate type at order       0      1      2      3
+Node    0, zone      DMA, type    Unmovable      0      0      0      1
+Node    0, zone   Normal, type      Movable    153     98     37      2
+Node    0, zone   Normal, type   HighAtomic      0      0      0      0
+
+Number of blocks type     Unmovable      Movable  Reclaimable   HighAtomic
+Node 0, zone      DMA            1            7            0            0
+Node 0, zone   Normal          101         2864           74            0
+";
+		let infos = parse(content).unwrap();
+		assert_eq!(infos.len(), 3);
+		assert_eq!((infos[0].node(), infos[0].zone()), (0, "DMA"));
+		assert_eq!(infos[0].migrate_type(), "Unmovable");
+		assert_eq!(infos[0].free(), [0, 0, 0, 1]);
+		assert_eq!(infos[1].zone(), "Normal");
+		assert_eq!(infos[1].migrate_type(), "Movable");
+		assert_eq!(infos[1].free(), [153, 98, 37, 2]);
+		assert_eq!(infos[2].migrate_type(), "HighAtomic");
+	}
+}
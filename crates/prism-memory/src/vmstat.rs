@@ -13,6 +13,7 @@ use crate::MemoryMetricError;
 use prism_macros::ProcParser;
 use prism_metric_common::procfs_root;
 use prism_metric_utils::read_to_string;
+use std::time::Duration;
 
 #[derive(ProcParser)]
 #[fmt = "space"]
@@ -120,6 +121,18 @@ pub struct VmStat {
 	pgfault: u64,
 	/// (since Linux 2.6.0)
 	pgmajfault: u64,
+	/// (since Linux 4.8)
+	/// Pages reclaimed by kswapd
+	pgsteal_kswapd: Option<u64>,
+	/// (since Linux 4.8)
+	/// Pages reclaimed by direct reclaim
+	pgsteal_direct: Option<u64>,
+	/// (since Linux 4.8)
+	/// Pages scanned by kswapd
+	pgscan_kswapd: Option<u64>,
+	/// (since Linux 4.8)
+	/// Pages scanned by direct reclaim
+	pgscan_direct: Option<u64>,
 	/// (since Linux 3.6)
 	pgscan_direct_throttle: u64,
 	/// (since Linux 2.6.0)
@@ -202,9 +215,160 @@ pub struct VmStat {
 	balloon_deflate: u64,
 	/// (since Linux 3.18)
 	balloon_migrate: u64,
+	/// (since Linux 4.13)
+	/// Processes killed by the OOM killer
+	oom_kill: Option<u64>,
+}
+
+/// Paging, swapping and reclaim activity between two [`VmStat`] samples
+///
+/// Rates are in events (or pages) per second. Fields that the kernel does not
+/// report in both samples are `None`.
+pub struct VmStatRates {
+	/// Page faults per second
+	pgfault: f64,
+	/// Major page faults (requiring disk I/O) per second
+	pgmajfault: f64,
+	/// Pages swapped in per second
+	pswpin: f64,
+	/// Pages swapped out per second
+	pswpout: f64,
+	/// Pages scanned by kswapd per second
+	pgscan_kswapd: Option<f64>,
+	/// Pages scanned by direct reclaim per second
+	pgscan_direct: Option<f64>,
+	/// Pages reclaimed by kswapd per second
+	pgsteal_kswapd: Option<f64>,
+	/// Pages reclaimed by direct reclaim per second
+	pgsteal_direct: Option<f64>,
+	/// Reclaimed pages over scanned pages, `None` when nothing was scanned
+	reclaim_efficiency: Option<f64>,
+	/// Processes killed by the OOM killer between the samples
+	oom_kill: Option<u64>,
+}
+
+impl VmStatRates {
+	/// Computes the rates from two samples taken `elapsed` apart.
+	///
+	/// Counters that went backwards are treated as unchanged.
+	pub fn new(previous: &VmStat, current: &VmStat, elapsed: Duration) -> Self {
+		let seconds = elapsed.as_secs_f64();
+		let rate = |previous: u64, current: u64| {
+			if seconds > 0.0 { current.saturating_sub(previous) as f64 / seconds } else { 0.0 }
+		};
+		let delta =
+			|previous: Option<u64>, current: Option<u64>| Some(current?.saturating_sub(previous?));
+		let optional_rate =
+			|previous: Option<u64>, current: Option<u64>| Some(rate(previous?, current?));
+
+		let scanned = delta(previous.pgscan_kswapd, current.pgscan_kswapd)
+			.zip(delta(previous.pgscan_direct, current.pgscan_direct))
+			.map(|(kswapd, direct)| kswapd + direct);
+		let stolen = delta(previous.pgsteal_kswapd, current.pgsteal_kswapd)
+			.zip(delta(previous.pgsteal_direct, current.pgsteal_direct))
+			.map(|(kswapd, direct)| kswapd + direct);
+		let reclaim_efficiency = scanned
+			.zip(stolen)
+			.filter(|(scanned, _)| *scanned > 0)
+			.map(|(scanned, stolen)| stolen as f64 / scanned as f64);
+
+		Self {
+			pgfault: rate(previous.pgfault, current.pgfault),
+			pgmajfault: rate(previous.pgmajfault, current.pgmajfault),
+			pswpin: rate(previous.pswpin, current.pswpin),
+			pswpout: rate(previous.pswpout, current.pswpout),
+			pgscan_kswapd: optional_rate(previous.pgscan_kswapd, current.pgscan_kswapd),
+			pgscan_direct: optional_rate(previous.pgscan_direct, current.pgscan_direct),
+			pgsteal_kswapd: optional_rate(previous.pgsteal_kswapd, current.pgsteal_kswapd),
+			pgsteal_direct: optional_rate(previous.pgsteal_direct, current.pgsteal_direct),
+			reclaim_efficiency,
+			oom_kill: delta(previous.oom_kill, current.oom_kill),
+		}
+	}
+
+	pub const fn pgfault(&self) -> f64 {
+		self.pgfault
+	}
+
+	pub const fn pgmajfault(&self) -> f64 {
+		self.pgmajfault
+	}
+
+	pub const fn pswpin(&self) -> f64 {
+		self.pswpin
+	}
+
+	pub const fn pswpout(&self) -> f64 {
+		self.pswpout
+	}
+
+	pub const fn pgscan_kswapd(&self) -> Option<f64> {
+		self.pgscan_kswapd
+	}
+
+	pub const fn pgscan_direct(&self) -> Option<f64> {
+		self.pgscan_direct
+	}
+
+	pub const fn pgsteal_kswapd(&self) -> Option<f64> {
+		self.pgsteal_kswapd
+	}
+
+	pub const fn pgsteal_direct(&self) -> Option<f64> {
+		self.pgsteal_direct
+	}
+
+	pub const fn reclaim_efficiency(&self) -> Option<f64> {
+		self.reclaim_efficiency
+	}
+
+	pub const fn oom_kill(&self) -> Option<u64> {
+		self.oom_kill
+	}
 }
 
 pub async fn vmstat() -> Result<VmStat, MemoryMetricError> {
 	let content = read_to_string(procfs_root().join("vmstat")).await?;
 	VmStat::parse(&content).map_err(Into::into)
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_rates() {
+		let previous = VmStat::parse(
+			"pgfault 1000\npgmajfault 10\npswpin 0\npswpout 0\npgscan_kswapd 100\npgscan_direct 0\npgsteal_kswapd 90\npgsteal_direct 0\noom_kill 1\n",
+		)
+		.unwrap();
+		let current = VmStat::parse(
+			"pgfault 3000\npgmajfault 30\npswpin 8\npswpout 4\npgscan_kswapd 300\npgscan_direct 200\npgsteal_kswapd 190\npgsteal_direct 100\noom_kill 3\n",
+		)
+		.unwrap();
+		let rates = VmStatRates::new(&previous, &current, Duration::from_secs(2));
+		assert_eq!(rates.pgfault(), 1000.0);
+		assert_eq!(rates.pgmajfault(), 10.0);
+		assert_eq!((rates.pswpin(), rates.pswpout()), (4.0, 2.0));
+		assert_eq!(rates.pgscan_kswapd(), Some(100.0));
+		assert_eq!(rates.pgscan_direct(), Some(100.0));
+		assert_eq!(rates.pgsteal_kswapd(), Some(50.0));
+		assert_eq!(rates.pgsteal_direct(), Some(50.0));
+		assert_eq!(rates.reclaim_efficiency(), Some(0.5));
+		assert_eq!(rates.oom_kill(), Some(2));
+
+		// Nothing scanned and no oom_kill on old kernels
+		let previous = VmStat::parse(
+			"pgfault 10\npgscan_kswapd 5\npgscan_direct 5\npgsteal_kswapd 5\npgsteal_direct 5\n",
+		)
+		.unwrap();
+		let current = VmStat::parse(
+			"pgfault 5\npgscan_kswapd 5\npgscan_direct 5\npgsteal_kswapd 5\npgsteal_direct 5\n",
+		)
+		.unwrap();
+		let rates = VmStatRates::new(&previous, &current, Duration::from_secs(1));
+		assert_eq!(rates.pgfault(), 0.0);
+		assert_eq!(rates.reclaim_efficiency(), None);
+		assert_eq!(rates.oom_kill(), None);
+	}
+}
@@ -0,0 +1,178 @@
+//! Memory zone information
+//!
+//! `/proc/zoneinfo` is made of one section per memory zone, each starting with
+//! a `Node <N>, zone <name>` header. The body mixes the zone page counters and
+//! watermarks with per-node and per-cpu blocks. Sections are split by hand and
+//! every body is then parsed as `name value` pairs, so the nested blocks
+//! (`protection: (...)`, `cpu: 0`, `count: 12`) are skipped.
+
+use crate::MemoryMetricError;
+use prism_macros::ProcParser;
+use prism_metric_common::procfs_root;
+use prism_metric_utils::read_to_string;
+
+/// Page counters and watermarks of a zone, in pages
+#[derive(ProcParser)]
+#[fmt = "space"]
+pub struct ZoneStat {
+	/// Free pages (`pages free`)
+	free: u64,
+	/// (since Linux 5.0)
+	/// Temporary watermark boost applied after fragmentation events
+	boost: Option<u64>,
+	/// Min watermark, below which only atomic allocations may succeed
+	min: u64,
+	/// Low watermark, below which kswapd is woken up
+	low: u64,
+	/// High watermark, at which kswapd goes back to sleep
+	high: u64,
+	/// Pages spanned by the zone, including holes
+	spanned: u64,
+	/// Physical pages present in the zone
+	present: u64,
+	/// Present pages managed by the buddy allocator
+	managed: u64,
+}
+
+/// A memory zone from `/proc/zoneinfo`
+pub struct Zone {
+	/// NUMA node the zone belongs to
+	node: u32,
+	/// Zone name, e.g. `DMA32` or `Normal`
+	name: String,
+	stat: ZoneStat,
+}
+
+impl Zone {
+	pub const fn node(&self) -> u32 {
+		self.node
+	}
+
+	pub fn name(&self) -> &str {
+		&self.name
+	}
+
+	pub const fn stat(&self) -> &ZoneStat {
+		&self.stat
+	}
+}
+
+/// Splits a `Node <N>, zone <name>...` line into the node id, the zone name
+/// and the remainder of the line.
+///
+/// The same header is shared by `/proc/zoneinfo`, `/proc/buddyinfo` and
+/// `/proc/pagetypeinfo`.
+pub(crate) fn parse_zone_header(line: &str) -> Option<(u32, &str, &str)> {
+	let (node, rest) = line.strip_prefix("Node")?.split_once(',')?;
+	let node = node.trim().parse().ok()?;
+	let rest = rest.trim_start().strip_prefix("zone")?.trim_start();
+	let end = rest.find(|c: char| c.is_whitespace() || c == ',').unwrap_or(rest.len());
+	if end == 0 {
+		return None;
+	}
+	Some((node, &rest[..end], &rest[end..]))
+}
+
+/// Parses the content of `/proc/zoneinfo`.
+pub fn parse(content: &str) -> Result<Vec<Zone>, MemoryMetricError> {
+	let mut sections: Vec<(u32, &str, String)> = Vec::new();
+	for line in content.lines() {
+		if let Some((node, name, _)) = parse_zone_header(line) {
+			sections.push((node, name, String::new()));
+			continue;
+		}
+		let Some((_, _, body)) = sections.last_mut() else {
+			continue;
+		};
+		// `pages free 123` is the only counter spread over three words.
+		let line = line.trim_start();
+		body.push_str(line.strip_prefix("pages ").unwrap_or(line));
+		body.push('\n');
+	}
+
+	sections
+		.into_iter()
+		.map(|(node, name, body)| {
+			let stat = ZoneStat::parse(&body)?;
+			Ok(Zone { node, name: name.to_string(), stat })
+		})
+		.collect()
+}
+
+pub async fn zoneinfo() -> Result<Vec<Zone>, MemoryMetricError> {
+	let content = read_to_string(procfs_root().join("zoneinfo")).await?;
+	parse(&content)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_parse_zone_header() {
+		assert_eq!(parse_zone_header("Node 0, zone      DMA"), Some((0, "DMA", "")));
+		assert_eq!(
+			parse_zone_header("Node 1, zone   Normal   4   2"),
+			Some((1, "Normal", "   4   2"))
+		);
+		assert_eq!(
+			parse_zone_header("Node    0, zone    DMA32, type    Movable      3"),
+			Some((0, "DMA32", ", type    Movable      3"))
+		);
+		assert_eq!(parse_zone_header("  pages free     3975"), None);
+		assert_eq!(parse_zone_header("Node 0, zone"), None);
+	}
+
+	#[test]
+	fn test_parse() {
+		let content = "Node 0, zone      DMA
+  per-node stats
+      nr_inactive_anon 18021
+      nr_free_pages 1
+  pages free     3840
+        boost    0
+        min      13
+        low      16
+        high     19
+        spanned  4095
+        present  3998
+        managed  3840
+        cma      0
+        protection: (0, 1877, 7829, 7829, 7829)
+      nr_free_pages 3840
+  pagesets
+    cpu: 0
+              count: 0
+              high:  0
+              batch: 1
+  vm stats threshold: 6
+  node_unreclaimable:  0
+  start_pfn:           1
+Node 0, zone   Normal
+  pages free     120467
+        min      11268
+        low      14085
+        high     16902
+        spanned  1572864
+        present  1572864
+        managed  1523101
+";
+		let zones = parse(content).unwrap();
+		assert_eq!(zones.len(), 2);
+
+		assert_eq!((zones[0].node(), zones[0].name()), (0, "DMA"));
+		let stat = zones[0].stat();
+		assert_eq!(*stat.get_free(), 3840);
+		assert_eq!(*stat.get_boost(), Some(0));
+		assert_eq!((*stat.get_min(), *stat.get_low(), *stat.get_high()), (13, 16, 19));
+		assert_eq!(*stat.get_spanned(), 4095);
+		assert_eq!(*stat.get_present(), 3998);
+		assert_eq!(*stat.get_managed(), 3840);
+
+		assert_eq!(zones[1].name(), "Normal");
+		let stat = zones[1].stat();
+		assert_eq!(*stat.get_free(), 120467);
+		assert_eq!(*stat.get_boost(), None);
+		assert_eq!(*stat.get_managed(), 1523101);
+	}
+}
@@ -24,12 +24,21 @@ pub fn init_roots() {
 			},
 			None => info!("SYSFS_ROOT is unset. Using default '/sys' for sysfs root."),
 		}
+
+		match std::env::var_os("ETC_ROOT") {
+			Some(etc_root) => {
+				info!("ETC_ROOT is set in envvars. Using custom: {etc_root:?} for etc.");
+				set_etc_root(std::path::PathBuf::from(&etc_root));
+			},
+			None => info!("ETC_ROOT is unset. Using default '/etc' for etc root."),
+		}
 	});
 }
 
 /// Holds global state for custom paths. Static's leveraging this type can only be set ONCE per binary run.
 static PROCFS_ROOT: OnceLock<PathBuf> = OnceLock::new();
 static SYSFS_ROOT: OnceLock<PathBuf> = OnceLock::new();
+static ETC_ROOT: OnceLock<PathBuf> = OnceLock::new();
 
 /// Instantiates the OnceLock holding PROCFS_ROOT in the case that it hasn't already been instantiated.
 ///
@@ -60,3 +69,18 @@ pub fn set_sysfs_root<T: Into<Cow<'static, Path>>>(root: T) {
 pub fn sysfs_root() -> &'static Path {
 	SYSFS_ROOT.get_or_init(|| PathBuf::from("/sys")).as_ref()
 }
+
+/// Instantiates the OnceLock holding ETC_ROOT in the case that it hasn't already been instantiated.
+///
+/// This can only be set ONCE per binary run.
+pub fn set_etc_root<T: Into<Cow<'static, Path>>>(root: T) {
+	let root = root.into().into_owned();
+	let _ = ETC_ROOT.get_or_init(|| root);
+}
+
+/// Returns the static `Path` value of a configured ETC_ROOT.
+///
+/// If uninitialized, initializes the ETC_ROOT with the default path - `/etc`
+pub fn etc_root() -> &'static Path {
+	ETC_ROOT.get_or_init(|| PathBuf::from("/etc")).as_ref()
+}
@@ -2,7 +2,11 @@ use futures::Stream;
 use futures_util::TryStreamExt;
 use log::error;
 use prism_runtime::handle;
-use std::{io, path::Path, str::FromStr};
+use std::{
+	io,
+	path::{Path, PathBuf},
+	str::FromStr,
+};
 use tokio::{
 	fs::File,
 	io::{AsyncBufReadExt, BufReader},
@@ -41,6 +45,38 @@ where
 		.map_err(join_err_to_io)?
 }
 
+/// Lists the entries of a directory, sorted by path.
+pub async fn read_dir<P>(path: P) -> io::Result<Vec<PathBuf>>
+where
+	P: AsRef<Path> + Send + 'static,
+{
+	let path = path.as_ref().to_owned();
+
+	handle()
+		.spawn_blocking(move || {
+			let mut entries = std::fs::read_dir(&path)?
+				.map(|entry| entry.map(|entry| entry.path()))
+				.collect::<io::Result<Vec<_>>>()?;
+			entries.sort();
+			Ok(entries)
+		})
+		.await
+		.map_err(join_err_to_io)?
+}
+
+/// Reads the target of a symbolic link.
+pub async fn read_link<P>(path: P) -> io::Result<PathBuf>
+where
+	P: AsRef<Path> + Send + 'static,
+{
+	let path = path.as_ref().to_owned();
+
+	handle()
+		.spawn_blocking(move || std::fs::read_link(&path))
+		.await
+		.map_err(join_err_to_io)?
+}
+
 pub async fn read_into<T, R, E>(path: T) -> Result<R, E>
 where
 	T: AsRef<Path> + Send + 'static,
@@ -0,0 +1,30 @@
+//! Application metrics
+//!
+//! Ships the instruments that the embedding service registered in a
+//! [`Registry`] along with the host metrics.
+
+use super::{Collector, MetricError};
+use prism_event::metric::{Metric, registry::Registry};
+use std::sync::Arc;
+
+pub(crate) struct ApplicationCollector {
+	registry: Arc<Registry>,
+}
+
+impl ApplicationCollector {
+	pub(crate) const fn new(registry: Arc<Registry>) -> Self {
+		Self { registry }
+	}
+}
+
+#[async_trait::async_trait]
+impl Collector for ApplicationCollector {
+	fn name(&self) -> &'static str {
+		"Application Collector"
+	}
+
+	async fn collect(&self, buffer: &mut Vec<Metric>) -> Result<(), MetricError> {
+		buffer.extend(self.registry.snapshot());
+		Ok(())
+	}
+}
@@ -1,3 +1,6 @@
+// Collection
+pub(super) const COLLECTION_LATENCY: &str = "collection_latency";
+
 // CPU
 // /proc/stat
 pub(super) const USER_USAGE: &str = "user_usage";
@@ -51,6 +54,16 @@ pub(super) const COMMITTED_AS: &str = "Committed_AS";
 pub(super) const VMALLOC_TOTAL: &str = "VmallocTotal";
 pub(super) const VMALLOC_USED: &str = "VmallocUsed";
 pub(super) const VMALLOC_CHUNK: &str = "VmallocChunk";
+pub(super) const HUGEPAGES_TOTAL: &str = "HugePages_Total";
+pub(super) const HUGEPAGES_FREE: &str = "HugePages_Free";
+pub(super) const HUGEPAGES_RSVD: &str = "HugePages_Rsvd";
+pub(super) const HUGEPAGES_SURP: &str = "HugePages_Surp";
+pub(super) const HUGEPAGESIZE: &str = "Hugepagesize";
+pub(super) const HUGETLB: &str = "Hugetlb";
+// /sys/kernel/mm/hugepages
+pub(super) const NR_HUGEPAGES: &str = "nr_hugepages";
+pub(super) const FREE_HUGEPAGES: &str = "free_hugepages";
+pub(super) const SURPLUS_HUGEPAGES: &str = "surplus_hugepages";
 // /proc/vmstat
 pub(super) const NR_FREE_PAGES: &str = "nr_free_pages";
 pub(super) const NR_INACTIVE_ANON: &str = "nr_inactive_anon";
@@ -143,6 +156,41 @@ pub(super) const THP_ZERO_PAGE_ALLOC_FAILED: &str = "thp_zero_page_alloc_failed"
 pub(super) const BALLOON_INFLATE: &str = "balloon_inflate";
 pub(super) const BALLOON_DEFLATE: &str = "balloon_deflate";
 pub(super) const BALLOON_MIGRATE: &str = "balloon_migrate";
+// /proc/vmstat rates
+pub(super) const PGFAULT_RATE: &str = "pgfault_rate";
+pub(super) const PGMAJFAULT_RATE: &str = "pgmajfault_rate";
+pub(super) const PSWPIN_RATE: &str = "pswpin_rate";
+pub(super) const PSWPOUT_RATE: &str = "pswpout_rate";
+pub(super) const PGSCAN_KSWAPD_RATE: &str = "pgscan_kswapd_rate";
+pub(super) const PGSCAN_DIRECT_RATE: &str = "pgscan_direct_rate";
+pub(super) const PGSTEAL_KSWAPD_RATE: &str = "pgsteal_kswapd_rate";
+pub(super) const PGSTEAL_DIRECT_RATE: &str = "pgsteal_direct_rate";
+pub(super) const RECLAIM_EFFICIENCY: &str = "reclaim_efficiency";
+pub(super) const OOM_KILLS: &str = "oom_kills";
+
+// NUMA
+// /sys/devices/system/node/node*/meminfo
+pub(super) const MEM_USED: &str = "MemUsed";
+pub(super) const FILE_PAGES: &str = "FilePages";
+// /sys/devices/system/node/node*/numastat
+pub(super) const INTERLEAVE_HIT: &str = "interleave_hit";
+pub(super) const LOCAL_NODE: &str = "local_node";
+pub(super) const OTHER_NODE: &str = "other_node";
+
+// Fragmentation
+// /proc/buddyinfo
+pub(super) const FREE_BLOCKS: &str = "free_blocks";
+// /proc/pagetypeinfo
+pub(super) const FREE_BLOCKS_BY_TYPE: &str = "free_blocks_by_type";
+// /proc/zoneinfo
+pub(super) const ZONE_PAGES_FREE: &str = "pages_free";
+pub(super) const ZONE_WATERMARK_MIN: &str = "watermark_min";
+pub(super) const ZONE_WATERMARK_LOW: &str = "watermark_low";
+pub(super) const ZONE_WATERMARK_HIGH: &str = "watermark_high";
+pub(super) const ZONE_WATERMARK_BOOST: &str = "watermark_boost";
+pub(super) const ZONE_PAGES_SPANNED: &str = "pages_spanned";
+pub(super) const ZONE_PAGES_PRESENT: &str = "pages_present";
+pub(super) const ZONE_PAGES_MANAGED: &str = "pages_managed";
 
 // Disk
 // /proc/diskstats
@@ -166,6 +214,26 @@ pub(super) const SECTORS_DISCARDED: &str = "sectors_discarded";
 pub(super) const DISCARDING_TIME: &str = "discarding_time";
 pub(super) const FLUSH_COMPLETED: &str = "flush_completed";
 pub(super) const FLUSHING_TIME: &str = "flushing_time";
+// /sys/block
+pub(super) const DISK_INFO: &str = "disk_info";
+pub(super) const DISK_SIZE: &str = "disk_size";
+pub(super) const DISK_REMOVABLE: &str = "disk_removable";
+pub(super) const DISK_ROTATIONAL: &str = "disk_rotational";
+pub(super) const DISK_NR_REQUESTS: &str = "disk_nr_requests";
+// /proc/mdstat
+pub(super) const RAID_ACTIVE: &str = "raid_active";
+pub(super) const RAID_READ_ONLY: &str = "raid_read_only";
+pub(super) const RAID_SIZE: &str = "raid_size";
+pub(super) const RAID_DEGRADED: &str = "raid_degraded";
+pub(super) const RAID_STATE: &str = "raid_state";
+pub(super) const RAID_DISKS: &str = "raid_disks";
+pub(super) const RAID_ACTIVE_DISKS: &str = "raid_active_disks";
+pub(super) const RAID_FAILED_MEMBERS: &str = "raid_failed_members";
+pub(super) const RAID_SPARE_MEMBERS: &str = "raid_spare_members";
+pub(super) const RAID_MEMBER: &str = "raid_member";
+pub(super) const RAID_SYNC_PROGRESS: &str = "raid_sync_progress";
+pub(super) const RAID_SYNC_FINISH: &str = "raid_sync_finish";
+pub(super) const RAID_SYNC_SPEED: &str = "raid_sync_speed";
 
 // Network
 // /proc/net/dev
@@ -186,3 +254,65 @@ pub(super) const TX_FIFO: &str = "tx_fifo";
 pub(super) const TX_COLLS: &str = "tx_colls";
 pub(super) const TX_CARRIER: &str = "tx_carrier";
 pub(super) const TX_COMPRESSED: &str = "tx_compressed";
+// /sys/class/net
+pub(super) const LINK_INFO: &str = "link_info";
+pub(super) const LINK_UP: &str = "link_up";
+pub(super) const LINK_OPERSTATE: &str = "link_operstate";
+pub(super) const LINK_CARRIER: &str = "link_carrier";
+pub(super) const LINK_CARRIER_CHANGES: &str = "link_carrier_changes";
+pub(super) const LINK_SPEED: &str = "link_speed";
+pub(super) const LINK_FULL_DUPLEX: &str = "link_full_duplex";
+pub(super) const LINK_MTU: &str = "link_mtu";
+pub(super) const LINK_MEMBERS: &str = "link_members";
+
+// Kernel tables
+// /proc/swaps
+pub(super) const SWAP_SIZE: &str = "swap_size";
+pub(super) const SWAP_USED: &str = "swap_used";
+pub(super) const SWAP_PRIORITY: &str = "swap_priority";
+pub(super) const SWAP_UTILIZATION: &str = "swap_utilization";
+// /proc/sys/fs/file-nr
+pub(super) const FILE_HANDLES_ALLOCATED: &str = "file_handles_allocated";
+pub(super) const FILE_HANDLES_UNUSED: &str = "file_handles_unused";
+pub(super) const FILE_HANDLES_MAX: &str = "file_handles_max";
+pub(super) const FILE_HANDLES_UTILIZATION: &str = "file_handles_utilization";
+// /proc/sys/fs/inode-nr
+pub(super) const INODES_ALLOCATED: &str = "inodes_allocated";
+pub(super) const INODES_FREE: &str = "inodes_free";
+pub(super) const INODES_UTILIZATION: &str = "inodes_utilization";
+// /proc/sys/kernel/{pid_max,threads-max}
+pub(super) const TASKS: &str = "tasks";
+pub(super) const PID_MAX: &str = "pid_max";
+pub(super) const PID_UTILIZATION: &str = "pid_utilization";
+pub(super) const THREADS_MAX: &str = "threads_max";
+pub(super) const THREADS_UTILIZATION: &str = "threads_utilization";
+// /proc/sys/net/netfilter/nf_conntrack_{count,max}
+pub(super) const CONNTRACK_COUNT: &str = "nf_conntrack_count";
+pub(super) const CONNTRACK_MAX: &str = "nf_conntrack_max";
+pub(super) const CONNTRACK_UTILIZATION: &str = "nf_conntrack_utilization";
+// /proc/sys/kernel/random/entropy_avail
+pub(super) const ENTROPY_AVAIL: &str = "entropy_avail";
+pub(super) const ENTROPY_POOL_SIZE: &str = "entropy_pool_size";
+pub(super) const ENTROPY_UTILIZATION: &str = "entropy_utilization";
+
+// Inventory
+// /proc/sys/kernel, /etc/machine-id and /etc/os-release
+pub(super) const HOST_INFO: &str = "host_info";
+// /proc/cpuinfo
+pub(super) const CPU_INFO: &str = "cpu_info";
+pub(super) const CPU_COUNT: &str = "cpu_count";
+// /sys/class/dmi/id
+pub(super) const DMI_INFO: &str = "dmi_info";
+// /proc/meminfo and /sys/block
+pub(super) const INVENTORY_MEMORY_TOTAL: &str = "inventory_memory_total";
+pub(super) const INVENTORY_DISK: &str = "inventory_disk";
+
+// Hardware sensors
+// /sys/class/hwmon
+pub(super) const TEMPERATURE: &str = "temperature";
+pub(super) const TEMPERATURE_CRITICAL: &str = "temperature_critical";
+pub(super) const FAN_SPEED: &str = "fan_speed";
+pub(super) const VOLTAGE: &str = "voltage";
+pub(super) const POWER: &str = "power";
+// /sys/class/thermal
+pub(super) const THERMAL_ZONE_TEMPERATURE: &str = "thermal_zone_temperature";
@@ -1,7 +1,7 @@
 use super::{Collector, MetricError, constants::*};
 use log::warn;
 use prism_cpu::stat;
-use prism_event::{gauge, metric::Metric};
+use prism_event::{counter, gauge, metric::Metric, shared_tags};
 use std::io;
 use uom::si::time::second;
 
@@ -23,30 +23,57 @@ impl Collector for CpuCollector {
 		match stat::stat().await {
 			Ok(stat) => {
 				for (cpu, time) in stat.cpu_times() {
-					buffer.push(gauge!(USER_USAGE, time.get_user().get::<second>(), "cpu" => cpu));
-					buffer.push(gauge!(NICE_USAGE, time.get_nice().get::<second>(), "cpu" => cpu));
-					buffer.push(
-						gauge!(SYSTEM_USAGE, time.get_system().get::<second>(), "cpu" => cpu),
-					);
-					buffer.push(gauge!(IDLE_USAGE, time.get_idle().get::<second>(), "cpu" => cpu));
-					buffer.push(
-						gauge!(IO_WAIT_USAGE, time.get_iowait().get::<second>(), "cpu" => cpu),
-					);
-					buffer.push(gauge!(IRQ_USAGE, time.get_irq().get::<second>(), "cpu" => cpu));
-					buffer.push(
-						gauge!(SOFT_IRQ_USAGE, time.get_softirq().get::<second>(), "cpu" => cpu),
-					);
-					buffer
-						.push(gauge!(STEAL_USAGE, time.get_steal().get::<second>(), "cpu" => cpu));
-					buffer
-						.push(gauge!(GUEST_USAGE, time.get_guest().get::<second>(), "cpu" => cpu));
-					buffer.push(
-						gauge!(GUEST_NICE_USAGE, time.get_guest_nice().get::<second>(), "cpu" => cpu),
-					);
+					let tags = shared_tags!("cpu" => cpu);
+					buffer.push(counter!(
+						USER_USAGE,
+						time.get_user().get::<second>(),
+						tags = &tags
+					));
+					buffer.push(counter!(
+						NICE_USAGE,
+						time.get_nice().get::<second>(),
+						tags = &tags
+					));
+					buffer.push(counter!(
+						SYSTEM_USAGE,
+						time.get_system().get::<second>(),
+						tags = &tags
+					));
+					buffer.push(counter!(
+						IDLE_USAGE,
+						time.get_idle().get::<second>(),
+						tags = &tags
+					));
+					buffer.push(counter!(
+						IO_WAIT_USAGE,
+						time.get_iowait().get::<second>(),
+						tags = &tags
+					));
+					buffer.push(counter!(IRQ_USAGE, time.get_irq().get::<second>(), tags = &tags));
+					buffer.push(counter!(
+						SOFT_IRQ_USAGE,
+						time.get_softirq().get::<second>(),
+						tags = &tags
+					));
+					buffer.push(counter!(
+						STEAL_USAGE,
+						time.get_steal().get::<second>(),
+						tags = &tags
+					));
+					buffer.push(counter!(
+						GUEST_USAGE,
+						time.get_guest().get::<second>(),
+						tags = &tags
+					));
+					buffer.push(counter!(
+						GUEST_NICE_USAGE,
+						time.get_guest_nice().get::<second>(),
+						tags = &tags
+					));
 				}
-				buffer.push(gauge!(CONTEXT_SWITCHES, stat.context_switches()));
+				buffer.push(counter!(CONTEXT_SWITCHES, stat.context_switches()));
 				buffer.push(gauge!(BOOT_TIME, stat.boot_time()));
-				buffer.push(gauge!(PROCESSES, stat.processes()));
+				buffer.push(counter!(PROCESSES, stat.processes()));
 				buffer.push(gauge!(PROCS_RUNNING, stat.procs_running()));
 				buffer.push(gauge!(PROCS_BLOCKED, stat.procs_blocked()));
 			},
@@ -0,0 +1,909 @@
+//! Descriptors of the host series, attached by the [`HostCollector`](super::HostCollector)
+//! to the samples of its collectors. Descriptions of the CPU, disk and network
+//! counters come from the doc comments of the fields they are parsed from.
+
+use super::constants::*;
+use prism_cpu::stat::CpuTime;
+use prism_disk::diskstat::DiskStat;
+use prism_event::metric::descriptor::{MetricDescriptor, MetricKind, Stability, Unit};
+use prism_network::netdev::Interface;
+
+pub(super) static DESCRIPTORS: &[MetricDescriptor] = &[
+	// Collection
+	MetricDescriptor::new(COLLECTION_LATENCY, MetricKind::Histogram)
+		.with_unit(Unit::Seconds)
+		.with_description("Time taken by each host collector to collect its metrics"),
+	// /proc/stat
+	MetricDescriptor::new(USER_USAGE, MetricKind::Counter)
+		.with_unit(Unit::Seconds)
+		.with_description(CpuTime::USER_DESCRIPTION)
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(NICE_USAGE, MetricKind::Counter)
+		.with_unit(Unit::Seconds)
+		.with_description(CpuTime::NICE_DESCRIPTION)
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(SYSTEM_USAGE, MetricKind::Counter)
+		.with_unit(Unit::Seconds)
+		.with_description(CpuTime::SYSTEM_DESCRIPTION)
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(IDLE_USAGE, MetricKind::Counter)
+		.with_unit(Unit::Seconds)
+		.with_description(CpuTime::IDLE_DESCRIPTION)
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(IO_WAIT_USAGE, MetricKind::Counter)
+		.with_unit(Unit::Seconds)
+		.with_description(CpuTime::IOWAIT_DESCRIPTION)
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(IRQ_USAGE, MetricKind::Counter)
+		.with_unit(Unit::Seconds)
+		.with_description(CpuTime::IRQ_DESCRIPTION)
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(SOFT_IRQ_USAGE, MetricKind::Counter)
+		.with_unit(Unit::Seconds)
+		.with_description(CpuTime::SOFTIRQ_DESCRIPTION)
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(STEAL_USAGE, MetricKind::Counter)
+		.with_unit(Unit::Seconds)
+		.with_description(CpuTime::STEAL_DESCRIPTION)
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(GUEST_USAGE, MetricKind::Counter)
+		.with_unit(Unit::Seconds)
+		.with_description(CpuTime::GUEST_DESCRIPTION)
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(GUEST_NICE_USAGE, MetricKind::Counter)
+		.with_unit(Unit::Seconds)
+		.with_description(CpuTime::GUEST_NICE_DESCRIPTION)
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(CONTEXT_SWITCHES, MetricKind::Counter)
+		.with_description("Context switches since boot")
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(BOOT_TIME, MetricKind::Gauge)
+		.with_unit(Unit::Seconds)
+		.with_description("Boot time in seconds since the Unix epoch")
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(PROCESSES, MetricKind::Counter)
+		.with_description("Processes and threads created since boot")
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(PROCS_RUNNING, MetricKind::Gauge)
+		.with_description("Processes currently running")
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(PROCS_BLOCKED, MetricKind::Gauge)
+		.with_description("Processes currently blocked waiting for I/O")
+		.with_stability(Stability::Stable),
+	// /proc/meminfo
+	MetricDescriptor::new(MEM_TOTAL, MetricKind::Gauge)
+		.with_unit(Unit::Kibibytes)
+		.with_description(
+			"Usable RAM, i.e. physical RAM minus reserved memory and the kernel binary",
+		)
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(MEM_FREE, MetricKind::Gauge)
+		.with_unit(Unit::Kibibytes)
+		.with_description("RAM left unused")
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(MEM_AVAILABLE, MetricKind::Gauge)
+		.with_unit(Unit::Kibibytes)
+		.with_description(
+			"Estimate of the memory available to start new applications without swapping",
+		)
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(BUFFERS, MetricKind::Gauge)
+		.with_unit(Unit::Kibibytes)
+		.with_description("Temporary storage for raw disk blocks")
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(CACHED, MetricKind::Gauge)
+		.with_unit(Unit::Kibibytes)
+		.with_description("Page cache of files read from disk, without the swap cache")
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(SWAP_CACHED, MetricKind::Gauge)
+		.with_unit(Unit::Kibibytes)
+		.with_description("Memory swapped back in that is still in the swap file")
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(ACTIVE, MetricKind::Gauge)
+		.with_unit(Unit::Kibibytes)
+		.with_description("Memory used recently and usually not reclaimed")
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(INACTIVE, MetricKind::Gauge)
+		.with_unit(Unit::Kibibytes)
+		.with_description("Memory used less recently, eligible for reclaim")
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(ACTIVE_ANON, MetricKind::Gauge)
+		.with_unit(Unit::Kibibytes)
+		.with_description("Anonymous memory used recently")
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(INACTIVE_ANON, MetricKind::Gauge)
+		.with_unit(Unit::Kibibytes)
+		.with_description("Anonymous memory used less recently, eligible for swapping")
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(ACTIVE_FILE, MetricKind::Gauge)
+		.with_unit(Unit::Kibibytes)
+		.with_description("Page cache used recently")
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(INACTIVE_FILE, MetricKind::Gauge)
+		.with_unit(Unit::Kibibytes)
+		.with_description("Page cache used less recently, eligible for reclaim")
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(SWAP_TOTAL, MetricKind::Gauge)
+		.with_unit(Unit::Kibibytes)
+		.with_description("Swap space available")
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(SWAP_FREE, MetricKind::Gauge)
+		.with_unit(Unit::Kibibytes)
+		.with_description("Swap space left unused")
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(DIRTY, MetricKind::Gauge)
+		.with_unit(Unit::Kibibytes)
+		.with_description("Memory waiting to be written back to disk")
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(WRITEBACK, MetricKind::Gauge)
+		.with_unit(Unit::Kibibytes)
+		.with_description("Memory being written back to disk")
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(ANON_PAGES, MetricKind::Gauge)
+		.with_unit(Unit::Kibibytes)
+		.with_description("Non-file backed pages mapped into user-space page tables")
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(MAPPED, MetricKind::Gauge)
+		.with_unit(Unit::Kibibytes)
+		.with_description("Files mapped into memory, such as libraries")
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(SHMEM, MetricKind::Gauge)
+		.with_unit(Unit::Kibibytes)
+		.with_description("Memory used by tmpfs, shared memory and shared anonymous mappings")
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(KRECLAIMABLE, MetricKind::Gauge)
+		.with_unit(Unit::Kibibytes)
+		.with_description("Kernel allocations that can be reclaimed under memory pressure")
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(SLAB, MetricKind::Gauge)
+		.with_unit(Unit::Kibibytes)
+		.with_description("In-kernel data structures cache")
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(SRECLAIMABLE, MetricKind::Gauge)
+		.with_unit(Unit::Kibibytes)
+		.with_description("Part of the slab that can be reclaimed, such as caches")
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(SUNRECLAIM, MetricKind::Gauge)
+		.with_unit(Unit::Kibibytes)
+		.with_description("Part of the slab that cannot be reclaimed")
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(KERNEL_STACK, MetricKind::Gauge)
+		.with_unit(Unit::Kibibytes)
+		.with_description("Memory allocated to kernel stacks")
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(PAGE_TABLES, MetricKind::Gauge)
+		.with_unit(Unit::Kibibytes)
+		.with_description("Memory dedicated to the lowest level of page tables")
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(NFS_UNSTABLE, MetricKind::Gauge)
+		.with_unit(Unit::Kibibytes)
+		.with_description("NFS pages sent to the server but not yet committed to stable storage")
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(BOUNCE, MetricKind::Gauge)
+		.with_unit(Unit::Kibibytes)
+		.with_description("Memory used for block device bounce buffers")
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(WRITEBACK_TMP, MetricKind::Gauge)
+		.with_unit(Unit::Kibibytes)
+		.with_description("Memory used by FUSE for temporary writeback buffers")
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(COMMIT_LIMIT, MetricKind::Gauge)
+		.with_unit(Unit::Kibibytes)
+		.with_description("Memory that can be allocated under strict overcommit accounting")
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(COMMITTED_AS, MetricKind::Gauge)
+		.with_unit(Unit::Kibibytes)
+		.with_description("Memory allocated by processes, even if not used yet")
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(VMALLOC_TOTAL, MetricKind::Gauge)
+		.with_unit(Unit::Kibibytes)
+		.with_description("Size of the vmalloc area")
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(VMALLOC_USED, MetricKind::Gauge)
+		.with_unit(Unit::Kibibytes)
+		.with_description("Used vmalloc area, always 0 since Linux 4.4")
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(VMALLOC_CHUNK, MetricKind::Gauge)
+		.with_unit(Unit::Kibibytes)
+		.with_description("Largest free block of the vmalloc area, always 0 since Linux 4.4")
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(HUGEPAGES_TOTAL, MetricKind::Gauge)
+		.with_description("Huge pages of the default size in the pool")
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(HUGEPAGES_FREE, MetricKind::Gauge)
+		.with_description("Huge pages of the default size not allocated yet")
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(HUGEPAGES_RSVD, MetricKind::Gauge)
+		.with_description("Huge pages of the default size committed to but not allocated yet")
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(HUGEPAGES_SURP, MetricKind::Gauge)
+		.with_description("Huge pages of the default size above nr_hugepages")
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(HUGEPAGESIZE, MetricKind::Gauge)
+		.with_unit(Unit::Kibibytes)
+		.with_description("Default size of huge pages")
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(HUGETLB, MetricKind::Gauge)
+		.with_unit(Unit::Kibibytes)
+		.with_description("Memory used by huge pages of all sizes")
+		.with_stability(Stability::Stable),
+	// /sys/kernel/mm/hugepages
+	MetricDescriptor::new(NR_HUGEPAGES, MetricKind::Gauge)
+		.with_description("Huge pages in the pool, by size"),
+	MetricDescriptor::new(FREE_HUGEPAGES, MetricKind::Gauge)
+		.with_description("Huge pages not allocated yet, by size"),
+	MetricDescriptor::new(SURPLUS_HUGEPAGES, MetricKind::Gauge)
+		.with_description("Huge pages above nr_hugepages, by size"),
+	// /proc/vmstat
+	MetricDescriptor::new(NR_FREE_PAGES, MetricKind::Gauge)
+		.with_unit(Unit::Pages)
+		.with_description("Free pages")
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(NR_INACTIVE_ANON, MetricKind::Gauge)
+		.with_unit(Unit::Pages)
+		.with_description("Anonymous pages on the inactive list")
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(NR_ACTIVE_ANON, MetricKind::Gauge)
+		.with_unit(Unit::Pages)
+		.with_description("Anonymous pages on the active list")
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(NR_INACTIVE_FILE, MetricKind::Gauge)
+		.with_unit(Unit::Pages)
+		.with_description("File pages on the inactive list")
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(NR_ACTIVE_FILE, MetricKind::Gauge)
+		.with_unit(Unit::Pages)
+		.with_description("File pages on the active list")
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(NR_UNEVICTABLE, MetricKind::Gauge)
+		.with_unit(Unit::Pages)
+		.with_description("Pages that cannot be evicted, e.g. locked ones")
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(NR_MLOCK, MetricKind::Gauge)
+		.with_unit(Unit::Pages)
+		.with_description("Pages locked with mlock")
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(NR_ANON_PAGES, MetricKind::Gauge)
+		.with_unit(Unit::Pages)
+		.with_description("Anonymous pages mapped into user-space page tables")
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(NR_MAPPED, MetricKind::Gauge)
+		.with_unit(Unit::Pages)
+		.with_description("File pages mapped into memory")
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(NR_FILE_PAGES, MetricKind::Gauge)
+		.with_unit(Unit::Pages)
+		.with_description("Pages of the page cache")
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(NR_DIRTY, MetricKind::Gauge)
+		.with_unit(Unit::Pages)
+		.with_description("Pages waiting to be written back to disk")
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(NR_WRITEBACK, MetricKind::Gauge)
+		.with_unit(Unit::Pages)
+		.with_description("Pages being written back to disk")
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(NR_SLAB_RECLAIMABLE, MetricKind::Gauge)
+		.with_unit(Unit::Pages)
+		.with_description("Slab pages that can be reclaimed")
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(NR_SLAB_UNRECLAIMABLE, MetricKind::Gauge)
+		.with_unit(Unit::Pages)
+		.with_description("Slab pages that cannot be reclaimed")
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(NR_PAGE_TABLE_PAGES, MetricKind::Gauge)
+		.with_unit(Unit::Pages)
+		.with_description("Pages allocated to page tables")
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(NR_KERNEL_STACK, MetricKind::Gauge)
+		.with_description("Memory allocated to kernel stacks, in KiB on recent kernels")
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(NR_UNSTABLE, MetricKind::Gauge)
+		.with_unit(Unit::Pages)
+		.with_description("NFS pages not yet committed to stable storage")
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(NR_BOUNCE, MetricKind::Gauge)
+		.with_unit(Unit::Pages)
+		.with_description("Pages used for block device bounce buffers")
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(NR_VMSCAN_WRITE, MetricKind::Counter)
+		.with_unit(Unit::Pages)
+		.with_description("Pages written back by page reclaim")
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(NR_VMSCAN_IMMEDIATE_RECLAIM, MetricKind::Counter)
+		.with_unit(Unit::Pages)
+		.with_description("Pages reclaimed as soon as their writeback completed")
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(NR_WRITEBACK_TEMP, MetricKind::Gauge)
+		.with_unit(Unit::Pages)
+		.with_description("Pages used by FUSE for temporary writeback buffers")
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(NR_ISOLATED_ANON, MetricKind::Gauge)
+		.with_unit(Unit::Pages)
+		.with_description("Anonymous pages temporarily isolated from the LRU lists")
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(NR_ISOLATED_FILE, MetricKind::Gauge)
+		.with_unit(Unit::Pages)
+		.with_description("File pages temporarily isolated from the LRU lists")
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(NR_SHMEM, MetricKind::Gauge)
+		.with_unit(Unit::Pages)
+		.with_description("Pages of tmpfs and shared memory")
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(NR_DIRTIED, MetricKind::Counter)
+		.with_unit(Unit::Pages)
+		.with_description("Pages dirtied")
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(NR_WRITTEN, MetricKind::Counter)
+		.with_unit(Unit::Pages)
+		.with_description("Pages written back")
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(NUMA_HIT, MetricKind::Counter)
+		.with_description("Allocations satisfied from the intended node")
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(NUMA_MISS, MetricKind::Counter)
+		.with_description("Allocations satisfied from another node than the intended one")
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(NUMA_FOREIGN, MetricKind::Counter)
+		.with_description("Allocations intended for a node but satisfied from another one")
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(NUMA_INTERLEAVE, MetricKind::Counter)
+		.with_description("Interleaved allocations satisfied from the intended node")
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(NUMA_LOCAL, MetricKind::Counter)
+		.with_description("Allocations satisfied from the node of the allocating task")
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(NUMA_OTHER, MetricKind::Counter)
+		.with_description(
+			"Allocations satisfied from another node than the one of the allocating task",
+		)
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(WORKINGSET_NODERECLAIM, MetricKind::Counter)
+		.with_description("Shadow entry nodes reclaimed")
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(NR_ANON_TRANSPARENT_HUGEPAGES, MetricKind::Gauge)
+		.with_description("Anonymous transparent huge pages")
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(NR_FREE_CMA, MetricKind::Gauge)
+		.with_unit(Unit::Pages)
+		.with_description("Free pages of the contiguous memory allocator")
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(NR_DIRTY_THRESHOLD, MetricKind::Gauge)
+		.with_unit(Unit::Pages)
+		.with_description("Dirty pages above which writers are throttled")
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(NR_DIRTY_BACKGROUND_THRESHOLD, MetricKind::Gauge)
+		.with_unit(Unit::Pages)
+		.with_description("Dirty pages above which background writeback starts")
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(PGPGIN, MetricKind::Counter)
+		.with_unit(Unit::Kibibytes)
+		.with_description("Memory paged in from disk")
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(PGPGOUT, MetricKind::Counter)
+		.with_unit(Unit::Kibibytes)
+		.with_description("Memory paged out to disk")
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(PSWPIN, MetricKind::Counter)
+		.with_unit(Unit::Pages)
+		.with_description("Pages swapped in")
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(PSWPOUT, MetricKind::Counter)
+		.with_unit(Unit::Pages)
+		.with_description("Pages swapped out")
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(PGALLOC_DMA, MetricKind::Counter)
+		.with_unit(Unit::Pages)
+		.with_description("Pages allocated in the DMA zone")
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(PGALLOC_DMA32, MetricKind::Counter)
+		.with_unit(Unit::Pages)
+		.with_description("Pages allocated in the DMA32 zone")
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(PGALLOC_NORMAL, MetricKind::Counter)
+		.with_unit(Unit::Pages)
+		.with_description("Pages allocated in the normal zone")
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(PGALLOC_MOVABLE, MetricKind::Counter)
+		.with_unit(Unit::Pages)
+		.with_description("Pages allocated in the movable zone")
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(PGFREE, MetricKind::Counter)
+		.with_unit(Unit::Pages)
+		.with_description("Pages freed")
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(PGACTIVATE, MetricKind::Counter)
+		.with_unit(Unit::Pages)
+		.with_description("Pages moved to the active list")
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(PGDEACTIVATE, MetricKind::Counter)
+		.with_unit(Unit::Pages)
+		.with_description("Pages moved to the inactive list")
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(PGFAULT, MetricKind::Counter)
+		.with_description("Page faults")
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(PGMAJFAULT, MetricKind::Counter)
+		.with_description("Major page faults, requiring disk I/O")
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(PGSCAN_DIRECT_THROTTLE, MetricKind::Counter)
+		.with_description("Times direct reclaim was throttled")
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(PGINODESTEAL, MetricKind::Counter)
+		.with_unit(Unit::Pages)
+		.with_description("Pages reclaimed by freeing inodes")
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(SLABS_SCANNED, MetricKind::Counter)
+		.with_description("Slab objects scanned by shrinkers")
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(KSWAPD_INODESTEAL, MetricKind::Counter)
+		.with_unit(Unit::Pages)
+		.with_description("Pages reclaimed by kswapd by freeing inodes")
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(KSWAPD_LOW_WMARK_HIT_QUICKLY, MetricKind::Counter)
+		.with_description("Times kswapd reached the low watermark quickly")
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(KSWAPD_HIGH_WMARK_HIT_QUICKLY, MetricKind::Counter)
+		.with_description("Times kswapd reached the high watermark quickly")
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(PAGEOUTRUN, MetricKind::Counter)
+		.with_description("Runs of kswapd balancing a node")
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(PGROTATED, MetricKind::Counter)
+		.with_unit(Unit::Pages)
+		.with_description("Pages rotated to the tail of the inactive list after writeback")
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(DROP_PAGECACHE, MetricKind::Counter)
+		.with_description("Times the page cache was dropped through drop_caches")
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(DROP_SLAB, MetricKind::Counter)
+		.with_description("Times the slab caches were dropped through drop_caches")
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(NUMA_PTE_UPDATES, MetricKind::Counter)
+		.with_description("Page table entries marked for NUMA hinting faults")
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(NUMA_HUGE_PTE_UPDATES, MetricKind::Counter)
+		.with_description("Huge page table entries marked for NUMA hinting faults")
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(NUMA_HINT_FAULTS, MetricKind::Counter)
+		.with_description("NUMA hinting faults")
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(NUMA_HINT_FAULTS_LOCAL, MetricKind::Counter)
+		.with_description("NUMA hinting faults on pages of the local node")
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(NUMA_PAGES_MIGRATED, MetricKind::Counter)
+		.with_unit(Unit::Pages)
+		.with_description("Pages migrated by automatic NUMA balancing")
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(PGMIGRATE_SUCCESS, MetricKind::Counter)
+		.with_unit(Unit::Pages)
+		.with_description("Pages migrated")
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(PGMIGRATE_FAIL, MetricKind::Counter)
+		.with_unit(Unit::Pages)
+		.with_description("Pages that failed to migrate")
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(COMPACT_MIGRATE_SCANNED, MetricKind::Counter)
+		.with_unit(Unit::Pages)
+		.with_description("Pages scanned by compaction for pages to migrate")
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(COMPACT_FREE_SCANNED, MetricKind::Counter)
+		.with_unit(Unit::Pages)
+		.with_description("Pages scanned by compaction for free pages")
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(COMPACT_ISOLATED, MetricKind::Counter)
+		.with_unit(Unit::Pages)
+		.with_description("Pages isolated by compaction")
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(COMPACT_STALL, MetricKind::Counter)
+		.with_description("Times an allocation stalled on direct compaction")
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(COMPACT_FAIL, MetricKind::Counter)
+		.with_description("Direct compactions that failed to free a suitable block")
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(COMPACT_SUCCESS, MetricKind::Counter)
+		.with_description("Direct compactions that freed a suitable block")
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(HTLB_BUDDY_ALLOC_SUCCESS, MetricKind::Counter)
+		.with_description("Huge pages allocated from the buddy allocator")
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(HTLB_BUDDY_ALLOC_FAIL, MetricKind::Counter)
+		.with_description("Huge pages that failed to be allocated from the buddy allocator")
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(UNEVICTABLE_PGS_CULLED, MetricKind::Counter)
+		.with_unit(Unit::Pages)
+		.with_description("Pages moved to the unevictable list")
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(UNEVICTABLE_PGS_SCANNED, MetricKind::Counter)
+		.with_unit(Unit::Pages)
+		.with_description("Unevictable pages scanned")
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(UNEVICTABLE_PGS_RESCUED, MetricKind::Counter)
+		.with_unit(Unit::Pages)
+		.with_description("Pages moved back from the unevictable list")
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(UNEVICTABLE_PGS_MLOCKED, MetricKind::Counter)
+		.with_unit(Unit::Pages)
+		.with_description("Pages locked with mlock")
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(UNEVICTABLE_PGS_MUNLOCKED, MetricKind::Counter)
+		.with_unit(Unit::Pages)
+		.with_description("Pages unlocked with munlock")
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(UNEVICTABLE_PGS_CLEARED, MetricKind::Counter)
+		.with_unit(Unit::Pages)
+		.with_description("Locked pages cleared on unmap or truncation")
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(UNEVICTABLE_PGS_STRANDED, MetricKind::Counter)
+		.with_unit(Unit::Pages)
+		.with_description("Locked pages that could not be isolated")
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(THP_FAULT_ALLOC, MetricKind::Counter)
+		.with_description("Transparent huge pages allocated on a page fault")
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(THP_FAULT_FALLBACK, MetricKind::Counter)
+		.with_description("Page faults that fell back to small pages")
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(THP_COLLAPSE_ALLOC, MetricKind::Counter)
+		.with_description("Transparent huge pages allocated by khugepaged to collapse small pages")
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(THP_COLLAPSE_ALLOC_FAILED, MetricKind::Counter)
+		.with_description("Transparent huge pages that khugepaged failed to allocate")
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(THP_ZERO_PAGE_ALLOC, MetricKind::Counter)
+		.with_description("Huge zero pages allocated")
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(THP_ZERO_PAGE_ALLOC_FAILED, MetricKind::Counter)
+		.with_description("Huge zero pages that failed to be allocated")
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(BALLOON_INFLATE, MetricKind::Counter)
+		.with_unit(Unit::Pages)
+		.with_description("Pages taken by the balloon driver")
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(BALLOON_DEFLATE, MetricKind::Counter)
+		.with_unit(Unit::Pages)
+		.with_description("Pages given back by the balloon driver")
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(BALLOON_MIGRATE, MetricKind::Counter)
+		.with_unit(Unit::Pages)
+		.with_description("Balloon pages migrated")
+		.with_stability(Stability::Stable),
+	// /proc/vmstat rates
+	MetricDescriptor::new(PGFAULT_RATE, MetricKind::Gauge)
+		.with_description("Page faults per second"),
+	MetricDescriptor::new(PGMAJFAULT_RATE, MetricKind::Gauge)
+		.with_description("Major page faults per second"),
+	MetricDescriptor::new(PSWPIN_RATE, MetricKind::Gauge)
+		.with_description("Pages swapped in per second"),
+	MetricDescriptor::new(PSWPOUT_RATE, MetricKind::Gauge)
+		.with_description("Pages swapped out per second"),
+	MetricDescriptor::new(PGSCAN_KSWAPD_RATE, MetricKind::Gauge)
+		.with_description("Pages scanned by kswapd per second"),
+	MetricDescriptor::new(PGSCAN_DIRECT_RATE, MetricKind::Gauge)
+		.with_description("Pages scanned by direct reclaim per second"),
+	MetricDescriptor::new(PGSTEAL_KSWAPD_RATE, MetricKind::Gauge)
+		.with_description("Pages reclaimed by kswapd per second"),
+	MetricDescriptor::new(PGSTEAL_DIRECT_RATE, MetricKind::Gauge)
+		.with_description("Pages reclaimed by direct reclaim per second"),
+	MetricDescriptor::new(RECLAIM_EFFICIENCY, MetricKind::Gauge)
+		.with_unit(Unit::Ratio)
+		.with_description("Pages reclaimed over pages scanned since the previous collection"),
+	MetricDescriptor::new(OOM_KILLS, MetricKind::Gauge)
+		.with_description("Processes killed by the OOM killer since the previous collection"),
+	// /sys/devices/system/node/node*/meminfo
+	MetricDescriptor::new(MEM_USED, MetricKind::Gauge)
+		.with_unit(Unit::Kibibytes)
+		.with_description("Memory of the node in use"),
+	MetricDescriptor::new(FILE_PAGES, MetricKind::Gauge)
+		.with_unit(Unit::Kibibytes)
+		.with_description("Page cache of the node"),
+	// /sys/devices/system/node/node*/numastat
+	MetricDescriptor::new(INTERLEAVE_HIT, MetricKind::Counter)
+		.with_description("Interleaved allocations satisfied from the node"),
+	MetricDescriptor::new(LOCAL_NODE, MetricKind::Counter)
+		.with_description("Allocations satisfied from the node by a task running on it"),
+	MetricDescriptor::new(OTHER_NODE, MetricKind::Counter)
+		.with_description("Allocations satisfied from the node by a task running on another one"),
+	// /proc/buddyinfo
+	MetricDescriptor::new(FREE_BLOCKS, MetricKind::Gauge)
+		.with_description("Free blocks of 2^order pages, by zone"),
+	// /proc/pagetypeinfo
+	MetricDescriptor::new(FREE_BLOCKS_BY_TYPE, MetricKind::Gauge)
+		.with_description("Free blocks of 2^order pages, by zone and migrate type"),
+	// /proc/zoneinfo
+	MetricDescriptor::new(ZONE_PAGES_FREE, MetricKind::Gauge)
+		.with_unit(Unit::Pages)
+		.with_description("Free pages of the zone"),
+	MetricDescriptor::new(ZONE_WATERMARK_MIN, MetricKind::Gauge)
+		.with_unit(Unit::Pages)
+		.with_description("Free pages below which only atomic allocations succeed"),
+	MetricDescriptor::new(ZONE_WATERMARK_LOW, MetricKind::Gauge)
+		.with_unit(Unit::Pages)
+		.with_description("Free pages below which kswapd starts reclaiming"),
+	MetricDescriptor::new(ZONE_WATERMARK_HIGH, MetricKind::Gauge)
+		.with_unit(Unit::Pages)
+		.with_description("Free pages above which kswapd stops reclaiming"),
+	MetricDescriptor::new(ZONE_WATERMARK_BOOST, MetricKind::Gauge)
+		.with_unit(Unit::Pages)
+		.with_description("Temporary boost of the watermarks to reduce fragmentation"),
+	MetricDescriptor::new(ZONE_PAGES_SPANNED, MetricKind::Gauge)
+		.with_unit(Unit::Pages)
+		.with_description("Pages spanned by the zone, holes included"),
+	MetricDescriptor::new(ZONE_PAGES_PRESENT, MetricKind::Gauge)
+		.with_unit(Unit::Pages)
+		.with_description("Physical pages of the zone"),
+	MetricDescriptor::new(ZONE_PAGES_MANAGED, MetricKind::Gauge)
+		.with_unit(Unit::Pages)
+		.with_description("Pages of the zone managed by the buddy allocator"),
+	// /proc/diskstats
+	MetricDescriptor::new(MAJOR, MetricKind::Gauge)
+		.with_description("Major number of the block device"),
+	MetricDescriptor::new(MINOR, MetricKind::Gauge)
+		.with_description("Minor number of the block device"),
+	MetricDescriptor::new(READ_COMPLETED, MetricKind::Counter)
+		.with_description(DiskStat::READ_COMPLETED_DESCRIPTION)
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(READ_MERGED, MetricKind::Counter)
+		.with_description(DiskStat::READ_MERGED_DESCRIPTION)
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(SECTORS_READ, MetricKind::Counter)
+		.with_unit(Unit::Bytes)
+		.with_description(DiskStat::SECTORS_READ_DESCRIPTION)
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(READ_TIME, MetricKind::Counter)
+		.with_unit(Unit::Milliseconds)
+		.with_description(DiskStat::READ_TIME_DESCRIPTION)
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(WRITE_COMPLETED, MetricKind::Counter)
+		.with_description(DiskStat::WRITE_COMPLETED_DESCRIPTION)
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(WRITE_MERGED, MetricKind::Counter)
+		.with_description(DiskStat::WRITE_MERGED_DESCRIPTION)
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(SECTORS_WRITTEN, MetricKind::Counter)
+		.with_unit(Unit::Bytes)
+		.with_description(DiskStat::SECTORS_WRITTEN_DESCRIPTION)
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(WRITING_TIME, MetricKind::Counter)
+		.with_unit(Unit::Milliseconds)
+		.with_description(DiskStat::WRITING_TIME_DESCRIPTION)
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(IOS_IN_PROGRESS, MetricKind::Gauge)
+		.with_description(DiskStat::IOS_IN_PROGRESS_DESCRIPTION)
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(IO_TIME, MetricKind::Counter)
+		.with_unit(Unit::Milliseconds)
+		.with_description(DiskStat::IO_TIME_DESCRIPTION)
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(WEIGHTED_IO_TIME, MetricKind::Counter)
+		.with_unit(Unit::Milliseconds)
+		.with_description(DiskStat::WEIGHTED_IO_TIME_DESCRIPTION)
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(DISCARD_COMPLETED, MetricKind::Counter)
+		.with_description(DiskStat::DISCARD_COMPLETED_DESCRIPTION)
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(DISCARD_MERGED, MetricKind::Counter)
+		.with_description(DiskStat::DISCARD_MERGED_DESCRIPTION)
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(SECTORS_DISCARDED, MetricKind::Counter)
+		.with_unit(Unit::Bytes)
+		.with_description(DiskStat::SECTORS_DISCARDED_DESCRIPTION)
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(DISCARDING_TIME, MetricKind::Counter)
+		.with_unit(Unit::Milliseconds)
+		.with_description(DiskStat::DISCARDING_TIME_DESCRIPTION)
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(FLUSH_COMPLETED, MetricKind::Counter)
+		.with_description(DiskStat::FLUSH_COMPLETED_DESCRIPTION)
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(FLUSHING_TIME, MetricKind::Counter)
+		.with_unit(Unit::Milliseconds)
+		.with_description(DiskStat::FLUSHING_TIME_DESCRIPTION)
+		.with_stability(Stability::Stable),
+	// /sys/block
+	MetricDescriptor::new(DISK_INFO, MetricKind::Info)
+		.with_description("Block device type, model and stacking"),
+	MetricDescriptor::new(DISK_SIZE, MetricKind::Gauge)
+		.with_unit(Unit::Bytes)
+		.with_description("Size of the block device"),
+	MetricDescriptor::new(DISK_REMOVABLE, MetricKind::Gauge)
+		.with_description("Whether the block device is removable"),
+	MetricDescriptor::new(DISK_ROTATIONAL, MetricKind::Gauge)
+		.with_description("Whether the block device is rotational"),
+	MetricDescriptor::new(DISK_NR_REQUESTS, MetricKind::Gauge)
+		.with_description("Maximum queued requests of the block device"),
+	// /proc/mdstat
+	MetricDescriptor::new(RAID_ACTIVE, MetricKind::Gauge)
+		.with_description("Whether the array is active"),
+	MetricDescriptor::new(RAID_READ_ONLY, MetricKind::Gauge)
+		.with_description("Whether the array is read-only"),
+	MetricDescriptor::new(RAID_SIZE, MetricKind::Gauge)
+		.with_unit(Unit::Bytes)
+		.with_description("Usable size of the array"),
+	MetricDescriptor::new(RAID_DEGRADED, MetricKind::Gauge)
+		.with_description("Whether the array runs with missing or failed members"),
+	MetricDescriptor::new(RAID_DISKS, MetricKind::Gauge)
+		.with_description("Members the array is made of"),
+	MetricDescriptor::new(RAID_ACTIVE_DISKS, MetricKind::Gauge)
+		.with_description("Members of the array in sync"),
+	MetricDescriptor::new(RAID_FAILED_MEMBERS, MetricKind::Gauge)
+		.with_description("Failed members of the array"),
+	MetricDescriptor::new(RAID_SPARE_MEMBERS, MetricKind::Gauge)
+		.with_description("Spare members of the array"),
+	MetricDescriptor::new(RAID_MEMBER, MetricKind::Gauge)
+		.with_description("Member of the array, with its role and slot"),
+	MetricDescriptor::new(RAID_SYNC_FINISH, MetricKind::Gauge)
+		.with_unit(Unit::Seconds)
+		.with_description("Estimated time left for the running resync, recovery or check"),
+	MetricDescriptor::new(RAID_STATE, MetricKind::StateSet)
+		.with_description("Activity, read-only, degraded and syncing states of the array"),
+	MetricDescriptor::new(RAID_SYNC_PROGRESS, MetricKind::Gauge)
+		.with_unit(Unit::Ratio)
+		.with_description("Progress of the running resync, recovery or check"),
+	MetricDescriptor::new(RAID_SYNC_SPEED, MetricKind::Gauge)
+		.with_unit(Unit::BytesPerSecond)
+		.with_description("Speed of the running resync, recovery or check"),
+	// /proc/net/dev
+	MetricDescriptor::new(RX_BYTES, MetricKind::Counter)
+		.with_unit(Unit::Bytes)
+		.with_description(Interface::RX_BYTES_DESCRIPTION)
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(RX_PACKETS, MetricKind::Counter)
+		.with_description(Interface::RX_PACKETS_DESCRIPTION)
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(RX_ERRORS, MetricKind::Counter)
+		.with_description(Interface::RX_ERRORS_DESCRIPTION)
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(RX_DROPPED, MetricKind::Counter)
+		.with_description(Interface::RX_DROPPED_DESCRIPTION)
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(RX_FIFO, MetricKind::Counter)
+		.with_description(Interface::RX_FIFO_DESCRIPTION)
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(RX_FRAME, MetricKind::Counter)
+		.with_description(Interface::RX_FRAME_DESCRIPTION)
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(RX_COMPRESSED, MetricKind::Counter)
+		.with_description(Interface::RX_COMPRESSED_DESCRIPTION)
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(RX_MULTICAST, MetricKind::Counter)
+		.with_description(Interface::RX_MULTICAST_DESCRIPTION)
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(TX_BYTES, MetricKind::Counter)
+		.with_unit(Unit::Bytes)
+		.with_description(Interface::TX_BYTES_DESCRIPTION)
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(TX_PACKETS, MetricKind::Counter)
+		.with_description(Interface::TX_PACKETS_DESCRIPTION)
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(TX_ERRORS, MetricKind::Counter)
+		.with_description(Interface::TX_ERRORS_DESCRIPTION)
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(TX_DROPPED, MetricKind::Counter)
+		.with_description(Interface::TX_DROPPED_DESCRIPTION)
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(TX_FIFO, MetricKind::Counter)
+		.with_description(Interface::TX_FIFO_DESCRIPTION)
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(TX_COLLS, MetricKind::Counter)
+		.with_description(Interface::TX_COLLS_DESCRIPTION)
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(TX_CARRIER, MetricKind::Counter)
+		.with_description(Interface::TX_CARRIER_DESCRIPTION)
+		.with_stability(Stability::Stable),
+	MetricDescriptor::new(TX_COMPRESSED, MetricKind::Counter)
+		.with_description(Interface::TX_COMPRESSED_DESCRIPTION)
+		.with_stability(Stability::Stable),
+	// /sys/class/net
+	MetricDescriptor::new(LINK_INFO, MetricKind::Info)
+		.with_description("Interface role, address, duplex and type"),
+	MetricDescriptor::new(LINK_OPERSTATE, MetricKind::StateSet)
+		.with_description("Operational state of the interface, as defined by RFC 2863"),
+	MetricDescriptor::new(LINK_SPEED, MetricKind::Gauge)
+		.with_unit(Unit::MegabitsPerSecond)
+		.with_description("Negotiated speed of the link"),
+	MetricDescriptor::new(LINK_MTU, MetricKind::Gauge)
+		.with_unit(Unit::Bytes)
+		.with_description("Maximum transmission unit of the interface"),
+	MetricDescriptor::new(LINK_UP, MetricKind::Gauge)
+		.with_description("Whether the interface is operationally up"),
+	MetricDescriptor::new(LINK_CARRIER, MetricKind::Gauge)
+		.with_description("Whether the interface has a carrier"),
+	MetricDescriptor::new(LINK_CARRIER_CHANGES, MetricKind::Counter)
+		.with_description("Carrier changes of the interface"),
+	MetricDescriptor::new(LINK_FULL_DUPLEX, MetricKind::Gauge)
+		.with_description("Whether the link is full duplex"),
+	MetricDescriptor::new(LINK_MEMBERS, MetricKind::Gauge)
+		.with_description("Members of the bond or bridge"),
+	// /proc/swaps
+	MetricDescriptor::new(SWAP_SIZE, MetricKind::Gauge)
+		.with_unit(Unit::Kibibytes)
+		.with_description("Size of the swap area"),
+	MetricDescriptor::new(SWAP_USED, MetricKind::Gauge)
+		.with_unit(Unit::Kibibytes)
+		.with_description("Used space of the swap area"),
+	MetricDescriptor::new(SWAP_PRIORITY, MetricKind::Gauge)
+		.with_description("Priority of the swap area"),
+	MetricDescriptor::new(SWAP_UTILIZATION, MetricKind::Gauge)
+		.with_unit(Unit::Ratio)
+		.with_description("Used space over size of the swap area"),
+	// /proc/sys/fs/file-nr
+	MetricDescriptor::new(FILE_HANDLES_ALLOCATED, MetricKind::Gauge)
+		.with_description("File handles allocated"),
+	MetricDescriptor::new(FILE_HANDLES_UNUSED, MetricKind::Gauge)
+		.with_description("File handles allocated but unused"),
+	MetricDescriptor::new(FILE_HANDLES_MAX, MetricKind::Gauge)
+		.with_description("Maximum file handles, file-max"),
+	MetricDescriptor::new(FILE_HANDLES_UTILIZATION, MetricKind::Gauge)
+		.with_unit(Unit::Ratio)
+		.with_description("File handles in use over file-max"),
+	// /proc/sys/fs/inode-nr
+	MetricDescriptor::new(INODES_ALLOCATED, MetricKind::Gauge).with_description("Inodes allocated"),
+	MetricDescriptor::new(INODES_FREE, MetricKind::Gauge)
+		.with_description("Inodes allocated but free"),
+	MetricDescriptor::new(INODES_UTILIZATION, MetricKind::Gauge)
+		.with_unit(Unit::Ratio)
+		.with_description("Inodes in use over allocated inodes"),
+	// /proc/sys/kernel/{pid_max,threads-max}
+	MetricDescriptor::new(TASKS, MetricKind::Gauge).with_description("Processes and threads"),
+	MetricDescriptor::new(PID_MAX, MetricKind::Gauge)
+		.with_description("Value above which PIDs wrap around, pid_max"),
+	MetricDescriptor::new(PID_UTILIZATION, MetricKind::Gauge)
+		.with_unit(Unit::Ratio)
+		.with_description("Tasks over pid_max"),
+	MetricDescriptor::new(THREADS_MAX, MetricKind::Gauge)
+		.with_description("Maximum threads, threads-max"),
+	MetricDescriptor::new(THREADS_UTILIZATION, MetricKind::Gauge)
+		.with_unit(Unit::Ratio)
+		.with_description("Tasks over threads-max"),
+	// /proc/sys/net/netfilter/nf_conntrack_{count,max}
+	MetricDescriptor::new(CONNTRACK_COUNT, MetricKind::Gauge)
+		.with_description("Connections tracked by netfilter"),
+	MetricDescriptor::new(CONNTRACK_MAX, MetricKind::Gauge)
+		.with_description("Size of the netfilter connection tracking table"),
+	MetricDescriptor::new(CONNTRACK_UTILIZATION, MetricKind::Gauge)
+		.with_unit(Unit::Ratio)
+		.with_description("Tracked connections over the table size"),
+	// /proc/sys/kernel/random/entropy_avail
+	MetricDescriptor::new(ENTROPY_AVAIL, MetricKind::Gauge)
+		.with_description("Entropy available in the kernel pool, in bits"),
+	MetricDescriptor::new(ENTROPY_POOL_SIZE, MetricKind::Gauge)
+		.with_description("Size of the kernel entropy pool, in bits"),
+	MetricDescriptor::new(ENTROPY_UTILIZATION, MetricKind::Gauge)
+		.with_unit(Unit::Ratio)
+		.with_description("Available entropy over the pool size"),
+	// Inventory
+	MetricDescriptor::new(HOST_INFO, MetricKind::Info)
+		.with_description("Host identity and operating system"),
+	MetricDescriptor::new(CPU_INFO, MetricKind::Info)
+		.with_description("CPU model, microcode and flags"),
+	MetricDescriptor::new(DMI_INFO, MetricKind::Info)
+		.with_description("System, board and BIOS identification from DMI"),
+	MetricDescriptor::new(CPU_COUNT, MetricKind::Gauge).with_description("Logical CPUs"),
+	MetricDescriptor::new(INVENTORY_MEMORY_TOTAL, MetricKind::Gauge)
+		.with_unit(Unit::Bytes)
+		.with_description("Usable RAM"),
+	MetricDescriptor::new(INVENTORY_DISK, MetricKind::Gauge)
+		.with_unit(Unit::Bytes)
+		.with_description("Size of the block device, with its type and model"),
+	// /sys/class/hwmon
+	MetricDescriptor::new(TEMPERATURE, MetricKind::Gauge)
+		.with_unit(Unit::Celsius)
+		.with_description("Temperature reported by the sensor"),
+	MetricDescriptor::new(TEMPERATURE_CRITICAL, MetricKind::Gauge)
+		.with_unit(Unit::Celsius)
+		.with_description("Critical temperature of the sensor"),
+	MetricDescriptor::new(FAN_SPEED, MetricKind::Gauge)
+		.with_unit(Unit::RevolutionsPerMinute)
+		.with_description("Speed of the fan"),
+	MetricDescriptor::new(VOLTAGE, MetricKind::Gauge)
+		.with_unit(Unit::Volts)
+		.with_description("Voltage reported by the sensor"),
+	MetricDescriptor::new(POWER, MetricKind::Gauge)
+		.with_unit(Unit::Watts)
+		.with_description("Power reported by the sensor"),
+	// /sys/class/thermal
+	MetricDescriptor::new(THERMAL_ZONE_TEMPERATURE, MetricKind::Gauge)
+		.with_unit(Unit::Celsius)
+		.with_description("Temperature of the thermal zone"),
+];
@@ -1,8 +1,12 @@
 use super::{Collector, MetricError, constants::*};
 use log::warn;
-use prism_disk::diskstat;
-use prism_event::{gauge, metric::Metric};
-use std::io;
+use prism_disk::{block, diskstat};
+use prism_event::{
+	counter, gauge, info_metric,
+	metric::{Metric, MetricTags},
+	shared_tags,
+};
+use std::{collections::BTreeMap, io, sync::Arc};
 use uom::si::{information::byte, time::millisecond};
 
 pub struct DiskCollector;
@@ -20,85 +24,149 @@ impl Collector for DiskCollector {
 	}
 
 	async fn collect(&self, buffer: &mut Vec<Metric>) -> Result<(), MetricError> {
-		match diskstat::diskstat().await {
-			Ok(diskstats) => {
-				for disk in diskstats {
-					let device_name = disk.get_name().to_string();
+		let diskstats = match diskstat::diskstat().await {
+			Ok(diskstats) => diskstats,
+			Err(error) => {
+				warn!("Failed to collect disk metrics: {error}");
+				return Err(io::Error::last_os_error().into());
+			},
+		};
+		// sysfs only enriches the diskstats counters, which are kept without it
+		let devices = match block::block_devices().await {
+			Ok(devices) => devices,
+			Err(error) => {
+				warn!("Failed to collect block device metrics: {error}");
+				Vec::new()
+			},
+		};
+		let devices: BTreeMap<_, _> =
+			devices.iter().map(|device| (device.name().to_string(), device)).collect();
 
-					// Basic disk information
-					buffer.push(gauge!(MAJOR, disk.get_major(), "device" => device_name.clone()));
-					buffer.push(gauge!(MINOR, disk.get_minor(), "device" => device_name.clone()));
+		for disk in diskstats {
+			let device_name = disk.get_name().to_string();
+			let device = devices.get(&device_name);
+			// Whole disks have no parent, which lets dashboards sum them
+			// without counting their partitions twice.
+			// Devices missing from sysfs are only tagged with their name.
+			let tags = match device {
+				Some(device) => {
+					let mut tags = MetricTags::new();
+					tags.insert("device", device_name.clone());
+					tags.insert("disk_type", device.disk_type().as_str());
+					tags.insert("parent", device.parents().join(","));
+					// Device mapper volumes also carry their friendly name, e.g. `vg0-root`.
+					if let Some(dm_name) = device.dm_name() {
+						tags.insert("dm_name", dm_name.to_string());
+					}
+					Arc::new(tags)
+				},
+				None => shared_tags!("device" => device_name.clone()),
+			};
 
-					// Read metrics
-					buffer.push(
-						gauge!(READ_COMPLETED, disk.get_read_completed(), "device" => device_name.clone()),
-					);
-					buffer.push(
-						gauge!(READ_MERGED, disk.get_read_merged(), "device" => device_name.clone()),
-					);
-					buffer.push(
-						gauge!(SECTORS_READ, disk.get_sectors_read().get::<byte>(), "device" => device_name.clone()),
-					);
-					buffer.push(
-						gauge!(READ_TIME, disk.get_read_time().get::<millisecond>(), "device" => device_name.clone()),
-					);
+			// Block device attributes
+			if let Some(device) = device {
+				let disk_type = device.disk_type().as_str();
+				let parent = device.parents().join(",");
+				let device_tags = shared_tags!("device" => device_name.clone());
+				buffer.push(info_metric!(
+					DISK_INFO,
+					"device" => device_name,
+					"disk_type" => disk_type,
+					"parent" => parent,
+					"holders" => device.holders().join(","),
+					"model" => device.model().unwrap_or_default().to_string(),
+					"scheduler" => device.scheduler().unwrap_or_default().to_string(),
+					"dm_name" => device.dm_name().unwrap_or_default().to_string(),
+					"dm_uuid" => device.dm_uuid().unwrap_or_default().to_string()
+				));
+				buffer.push(gauge!(DISK_SIZE, device.size().get::<byte>(), tags = &device_tags));
+				buffer.push(gauge!(
+					DISK_REMOVABLE,
+					u8::from(device.removable()),
+					tags = &device_tags
+				));
+				if let Some(rotational) = device.rotational() {
+					buffer.push(gauge!(DISK_ROTATIONAL, u8::from(rotational), tags = &device_tags));
+				}
+				if let Some(nr_requests) = device.nr_requests() {
+					buffer.push(gauge!(DISK_NR_REQUESTS, nr_requests, tags = &device_tags));
+				}
+			}
 
-					// Write metrics
-					buffer.push(
-						gauge!(WRITE_COMPLETED, disk.get_write_completed(), "device" => device_name.clone()),
-					);
-					buffer.push(
-						gauge!(WRITE_MERGED, disk.get_write_merged(), "device" => device_name.clone()),
-					);
-					buffer.push(gauge!(SECTORS_WRITTEN, disk.get_sectors_written().get::<byte>(), "device" => device_name.clone()));
-					buffer.push(gauge!(WRITING_TIME, disk.get_writing_time().get::<millisecond>(), "device" => device_name.clone()));
+			// Basic disk information
+			buffer.push(gauge!(MAJOR, disk.get_major(), tags = &tags));
+			buffer.push(gauge!(MINOR, disk.get_minor(), tags = &tags));
 
-					// I/O metrics
-					buffer.push(
-						gauge!(IOS_IN_PROGRESS, disk.get_ios_in_progress(), "device" => device_name.clone()),
-					);
-					buffer.push(
-						gauge!(IO_TIME, disk.get_io_time().get::<millisecond>(), "device" => device_name.clone()),
-					);
-					buffer.push(gauge!(WEIGHTED_IO_TIME, disk.get_weighted_io_time().get::<millisecond>(), "device" => device_name.clone()));
+			// Read metrics
+			buffer.push(counter!(READ_COMPLETED, disk.get_read_completed(), tags = &tags));
+			buffer.push(counter!(READ_MERGED, disk.get_read_merged(), tags = &tags));
+			buffer.push(counter!(
+				SECTORS_READ,
+				disk.get_sectors_read().get::<byte>(),
+				tags = &tags
+			));
+			buffer.push(counter!(
+				READ_TIME,
+				disk.get_read_time().get::<millisecond>(),
+				tags = &tags
+			));
 
-					// Optional discard metrics (kernel 4.18+)
-					if let Some(discard_completed) = disk.get_discard_completed() {
-						buffer.push(
-							gauge!(DISCARD_COMPLETED, discard_completed, "device" => device_name.clone()),
-						);
-					}
-					if let Some(discard_merged) = disk.get_discard_merged() {
-						buffer.push(
-							gauge!(DISCARD_MERGED, discard_merged, "device" => device_name.clone()),
-						);
-					}
-					if let Some(sectors_discarded) = disk.get_sectors_discarded() {
-						buffer.push(
-							gauge!(SECTORS_DISCARDED, sectors_discarded.get::<byte>(), "device" => device_name.clone()),
-						);
-					}
-					if let Some(discarding_time) = disk.get_discarding_time() {
-						buffer.push(gauge!(DISCARDING_TIME, discarding_time.get::<millisecond>(), "device" => device_name.clone()));
-					}
+			// Write metrics
+			buffer.push(counter!(WRITE_COMPLETED, disk.get_write_completed(), tags = &tags));
+			buffer.push(counter!(WRITE_MERGED, disk.get_write_merged(), tags = &tags));
+			buffer.push(counter!(
+				SECTORS_WRITTEN,
+				disk.get_sectors_written().get::<byte>(),
+				tags = &tags
+			));
+			buffer.push(counter!(
+				WRITING_TIME,
+				disk.get_writing_time().get::<millisecond>(),
+				tags = &tags
+			));
 
-					// Optional flush metrics (kernel 5.5+)
-					if let Some(flush_completed) = disk.get_flush_completed() {
-						buffer.push(
-							gauge!(FLUSH_COMPLETED, flush_completed, "device" => device_name.clone()),
-						);
-					}
-					if let Some(flushing_time) = disk.get_flushing_time() {
-						buffer.push(
-							gauge!(FLUSHING_TIME, flushing_time.get::<millisecond>(), "device" => device_name.clone()),
-						);
-					}
-				}
-			},
-			Err(error) => {
-				warn!("Failed to collect disk metrics: {error}");
-				return Err(io::Error::last_os_error().into());
-			},
+			// I/O metrics
+			buffer.push(gauge!(IOS_IN_PROGRESS, disk.get_ios_in_progress(), tags = &tags));
+			buffer.push(counter!(IO_TIME, disk.get_io_time().get::<millisecond>(), tags = &tags));
+			buffer.push(counter!(
+				WEIGHTED_IO_TIME,
+				disk.get_weighted_io_time().get::<millisecond>(),
+				tags = &tags
+			));
+
+			// Optional discard metrics (kernel 4.18+)
+			if let Some(discard_completed) = disk.get_discard_completed() {
+				buffer.push(counter!(DISCARD_COMPLETED, discard_completed, tags = &tags));
+			}
+			if let Some(discard_merged) = disk.get_discard_merged() {
+				buffer.push(counter!(DISCARD_MERGED, discard_merged, tags = &tags));
+			}
+			if let Some(sectors_discarded) = disk.get_sectors_discarded() {
+				buffer.push(counter!(
+					SECTORS_DISCARDED,
+					sectors_discarded.get::<byte>(),
+					tags = &tags
+				));
+			}
+			if let Some(discarding_time) = disk.get_discarding_time() {
+				buffer.push(counter!(
+					DISCARDING_TIME,
+					discarding_time.get::<millisecond>(),
+					tags = &tags
+				));
+			}
+
+			// Optional flush metrics (kernel 5.5+)
+			if let Some(flush_completed) = disk.get_flush_completed() {
+				buffer.push(counter!(FLUSH_COMPLETED, flush_completed, tags = &tags));
+			}
+			if let Some(flushing_time) = disk.get_flushing_time() {
+				buffer.push(counter!(
+					FLUSHING_TIME,
+					flushing_time.get::<millisecond>(),
+					tags = &tags
+				));
+			}
 		}
 		Ok(())
 	}
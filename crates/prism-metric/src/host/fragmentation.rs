@@ -0,0 +1,77 @@
+use super::{Collector, MetricError, constants::*};
+use log::warn;
+use prism_event::{gauge, metric::Metric, shared_tags};
+use prism_memory::{MemoryMetricError, buddyinfo, pagetypeinfo, zoneinfo};
+use std::io;
+
+pub struct FragmentationCollector;
+
+impl FragmentationCollector {
+	pub(crate) const fn new() -> Result<Self, MetricError> {
+		Ok(Self {})
+	}
+}
+
+#[async_trait::async_trait]
+impl Collector for FragmentationCollector {
+	fn name(&self) -> &'static str {
+		"host fragmentation collector"
+	}
+
+	async fn collect(&self, buffer: &mut Vec<Metric>) -> Result<(), MetricError> {
+		match buddyinfo::buddyinfo().await {
+			Ok(zones) => {
+				for zone in zones {
+					let node = format!("node{}", zone.node());
+					for (order, free) in zone.free().iter().enumerate() {
+						buffer.push(gauge!(FREE_BLOCKS, free, "node" => node.clone(), "zone" => zone.zone().to_string(), "order" => order.to_string()));
+					}
+				}
+			},
+			Err(error) => {
+				warn!("Failed to collect buddyinfo metrics: {error}");
+				return Err(io::Error::last_os_error().into());
+			},
+		}
+		match zoneinfo::zoneinfo().await {
+			Ok(zones) => {
+				for zone in zones {
+					let tags = shared_tags!("node" => format!("node{}", zone.node()), "zone" => zone.name().to_string());
+					let stat = zone.stat();
+					buffer.push(gauge!(ZONE_PAGES_FREE, stat.get_free(), tags = &tags));
+					buffer.push(gauge!(ZONE_WATERMARK_MIN, stat.get_min(), tags = &tags));
+					buffer.push(gauge!(ZONE_WATERMARK_LOW, stat.get_low(), tags = &tags));
+					buffer.push(gauge!(ZONE_WATERMARK_HIGH, stat.get_high(), tags = &tags));
+					if let Some(boost) = stat.get_boost() {
+						buffer.push(gauge!(ZONE_WATERMARK_BOOST, boost, tags = &tags));
+					}
+					buffer.push(gauge!(ZONE_PAGES_SPANNED, stat.get_spanned(), tags = &tags));
+					buffer.push(gauge!(ZONE_PAGES_PRESENT, stat.get_present(), tags = &tags));
+					buffer.push(gauge!(ZONE_PAGES_MANAGED, stat.get_managed(), tags = &tags));
+				}
+			},
+			Err(error) => {
+				warn!("Failed to collect zoneinfo metrics: {error}");
+				return Err(io::Error::last_os_error().into());
+			},
+		}
+		match pagetypeinfo::pagetypeinfo().await {
+			Ok(infos) => {
+				for info in infos {
+					let node = format!("node{}", info.node());
+					for (order, free) in info.free().iter().enumerate() {
+						buffer.push(gauge!(FREE_BLOCKS_BY_TYPE, free, "node" => node.clone(), "zone" => info.zone().to_string(), "type" => info.migrate_type().to_string(), "order" => order.to_string()));
+					}
+				}
+			},
+			// Only root may read pagetypeinfo, the other sources are still worth reporting.
+			Err(MemoryMetricError::IOError(error))
+				if error.kind() == io::ErrorKind::PermissionDenied => {},
+			Err(error) => {
+				warn!("Failed to collect pagetypeinfo metrics: {error}");
+				return Err(io::Error::last_os_error().into());
+			},
+		}
+		Ok(())
+	}
+}
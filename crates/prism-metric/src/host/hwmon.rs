@@ -0,0 +1,83 @@
+use super::{Collector, MetricError, constants::*};
+use log::warn;
+use prism_event::{gauge, metric::Metric, shared_tags};
+use prism_hwmon::{hwmon, thermal};
+use std::io;
+use uom::si::{
+	angular_velocity::revolution_per_minute, electric_potential::volt, power::watt,
+	thermodynamic_temperature::degree_celsius,
+};
+
+pub struct HwmonCollector;
+
+impl HwmonCollector {
+	pub(crate) const fn new() -> Result<Self, MetricError> {
+		Ok(Self {})
+	}
+}
+
+#[async_trait::async_trait]
+impl Collector for HwmonCollector {
+	fn name(&self) -> &'static str {
+		"host hwmon collector"
+	}
+
+	async fn collect(&self, buffer: &mut Vec<Metric>) -> Result<(), MetricError> {
+		match hwmon::chips().await {
+			Ok(chips) => {
+				for chip in chips {
+					let device = chip.device().to_string();
+					let chip_name = chip.name().to_string();
+
+					for sensor in chip.temperatures() {
+						let tags = shared_tags!("device" => device.clone(), "chip" => chip_name.clone(), "sensor" => sensor.label().to_string());
+						buffer.push(gauge!(
+							TEMPERATURE,
+							sensor.input().get::<degree_celsius>(),
+							tags = &tags
+						));
+						if let Some(crit) = sensor.crit() {
+							buffer.push(gauge!(
+								TEMPERATURE_CRITICAL,
+								crit.get::<degree_celsius>(),
+								tags = &tags
+							));
+						}
+					}
+
+					for sensor in chip.fans() {
+						let tags = shared_tags!("device" => device.clone(), "chip" => chip_name.clone(), "sensor" => sensor.label().to_string());
+						buffer.push(gauge!(
+							FAN_SPEED,
+							sensor.input().get::<revolution_per_minute>(),
+							tags = &tags
+						));
+					}
+
+					for sensor in chip.voltages() {
+						let tags = shared_tags!("device" => device.clone(), "chip" => chip_name.clone(), "sensor" => sensor.label().to_string());
+						buffer.push(gauge!(VOLTAGE, sensor.input().get::<volt>(), tags = &tags));
+					}
+
+					for sensor in chip.powers() {
+						let tags = shared_tags!("device" => device.clone(), "chip" => chip_name.clone(), "sensor" => sensor.label().to_string());
+						buffer.push(gauge!(POWER, sensor.input().get::<watt>(), tags = &tags));
+					}
+				}
+			},
+			Err(error) => warn!("Failed to collect hwmon metrics: {error}"),
+		}
+		match thermal::thermal_zones().await {
+			Ok(zones) => {
+				for zone in zones {
+					buffer.push(gauge!(THERMAL_ZONE_TEMPERATURE, zone.temperature().get::<degree_celsius>(), "zone" => zone.name().to_string(), "type" => zone.kind().to_string()));
+				}
+			},
+			Err(error) => {
+				warn!("Failed to collect thermal zone metrics: {error}");
+				return Err(io::Error::last_os_error().into());
+			},
+		}
+		Ok(())
+	}
+}
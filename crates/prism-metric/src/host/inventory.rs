@@ -0,0 +1,179 @@
+use super::{Collector, MetricError, constants::*};
+use log::warn;
+use prism_disk::block;
+use prism_event::{
+	gauge, info_metric,
+	metric::{Metric, MetricTags},
+};
+use prism_host::{
+	cpuinfo, dmi, identity,
+	os_release::{self, OsRelease},
+};
+use prism_memory::meminfo;
+use std::{
+	io,
+	sync::Mutex,
+	time::{Duration, Instant},
+};
+use uom::si::information::byte;
+
+/// Inventory barely changes, so it is emitted at a much lower rate than the
+/// other host metrics.
+const INVENTORY_INTERVAL: Duration = Duration::from_secs(600);
+
+pub struct InventoryCollector {
+	/// Time of the last emitted inventory
+	last: Mutex<Option<Instant>>,
+}
+
+impl InventoryCollector {
+	pub(crate) const fn new() -> Result<Self, MetricError> {
+		Ok(Self { last: Mutex::new(None) })
+	}
+
+	/// Whether the inventory is due, i.e. none was emitted in the last interval.
+	fn due(&self) -> bool {
+		let last = self.last.lock().unwrap_or_else(|e| e.into_inner());
+		!last.is_some_and(|last| last.elapsed() < INVENTORY_INTERVAL)
+	}
+
+	/// Records a complete inventory, so that a failed one is retried on the
+	/// next collection rather than after a whole interval.
+	fn emitted(&self) {
+		*self.last.lock().unwrap_or_else(|e| e.into_inner()) = Some(Instant::now());
+	}
+}
+
+/// Reads the identity fields that can be attached as global tags to every
+/// metric.
+pub(crate) async fn identity_tags() -> Result<MetricTags, MetricError> {
+	let os = os_release::os_release().await.map_err(io::Error::other)?;
+	host_tags(&os).await
+}
+
+/// Reads the host identity and adds the fields of the already read `os`.
+async fn host_tags(os: &OsRelease) -> Result<MetricTags, MetricError> {
+	let identity = identity::identity().await.map_err(io::Error::other)?;
+
+	let mut tags = MetricTags::new();
+	tags.insert("hostname", identity.hostname().to_string());
+	tags.insert("boot_id", identity.boot_id().to_string());
+	tags.insert("kernel_release", identity.kernel_release().to_string());
+	if let Some(machine_id) = identity.machine_id() {
+		tags.insert("machine_id", machine_id.to_string());
+	}
+	if let Some(id) = os.id() {
+		tags.insert("os_id", id.to_string());
+	}
+	if let Some(version_id) = os.version_id() {
+		tags.insert("os_version_id", version_id.to_string());
+	}
+	Ok(tags)
+}
+
+#[async_trait::async_trait]
+impl Collector for InventoryCollector {
+	fn name(&self) -> &'static str {
+		"host inventory collector"
+	}
+
+	/// Every source is optional: one that fails is skipped with a warning and
+	/// the rest of the inventory is still emitted.
+	async fn collect(&self, buffer: &mut Vec<Metric>) -> Result<(), MetricError> {
+		if !self.due() {
+			return Ok(());
+		}
+		let mut complete = true;
+
+		let tags = match os_release::os_release().await {
+			Ok(os) => host_tags(&os).await.map(|tags| (os, tags)),
+			Err(error) => Err(io::Error::other(error).into()),
+		};
+		match tags {
+			Ok((os, mut tags)) => {
+				tags.insert("os_pretty_name", os.pretty_name().unwrap_or_default().to_string());
+				let mut info = info_metric!(HOST_INFO);
+				info.merge_tags(&tags);
+				buffer.push(info);
+			},
+			Err(error) => {
+				warn!("Failed to collect host identity: {error}");
+				complete = false;
+			},
+		}
+
+		match cpuinfo::cpuinfo().await {
+			Ok(cpu) => {
+				buffer.push(info_metric!(
+					CPU_INFO,
+					"model" => cpu.model().unwrap_or_default().to_string(),
+					"microcode" => cpu.microcode().unwrap_or_default().to_string(),
+					"flags_hash" => cpu.flags_hash().to_string()
+				));
+				buffer.push(gauge!(CPU_COUNT, cpu.cpus() as u64));
+			},
+			Err(error) => {
+				warn!("Failed to collect cpu inventory: {error}");
+				complete = false;
+			},
+		}
+
+		match dmi::dmi().await {
+			Ok(dmi) => {
+				buffer.push(info_metric!(
+					DMI_INFO,
+					"sys_vendor" => dmi.sys_vendor().unwrap_or_default().to_string(),
+					"product_name" => dmi.product_name().unwrap_or_default().to_string(),
+					"product_version" => dmi.product_version().unwrap_or_default().to_string(),
+					"product_serial" => dmi.product_serial().unwrap_or_default().to_string(),
+					"product_uuid" => dmi.product_uuid().unwrap_or_default().to_string(),
+					"board_vendor" => dmi.board_vendor().unwrap_or_default().to_string(),
+					"board_name" => dmi.board_name().unwrap_or_default().to_string(),
+					"bios_vendor" => dmi.bios_vendor().unwrap_or_default().to_string(),
+					"bios_version" => dmi.bios_version().unwrap_or_default().to_string(),
+					"bios_date" => dmi.bios_date().unwrap_or_default().to_string(),
+					"chassis_type" => dmi.chassis_type().unwrap_or_default().to_string()
+				));
+			},
+			Err(error) => {
+				warn!("Failed to collect dmi inventory: {error}");
+				complete = false;
+			},
+		}
+
+		match meminfo::meminfo().await {
+			Ok(meminfo) => {
+				buffer.push(gauge!(INVENTORY_MEMORY_TOTAL, meminfo.get_MemTotal().get::<byte>()));
+			},
+			Err(error) => {
+				warn!("Failed to collect memory inventory: {error}");
+				complete = false;
+			},
+		}
+
+		match block::block_devices().await {
+			// Only whole disks that are not built on other devices, i.e. no
+			// partitions, device-mapper or md devices
+			Ok(devices) => {
+				for device in devices.iter().filter(|device| device.parents().is_empty()) {
+					buffer.push(gauge!(
+						INVENTORY_DISK,
+						device.size().get::<byte>(),
+						"device" => device.name().to_string(),
+						"disk_type" => device.disk_type().as_str(),
+						"model" => device.model().unwrap_or_default().to_string()
+					));
+				}
+			},
+			Err(error) => {
+				warn!("Failed to collect disk inventory: {error}");
+				complete = false;
+			},
+		}
+
+		if complete {
+			self.emitted();
+		}
+		Ok(())
+	}
+}
@@ -0,0 +1,101 @@
+use super::{Collector, MetricError, constants::*};
+use log::warn;
+use prism_event::{gauge, metric::Metric, shared_tags};
+use prism_kernel::{conntrack, fs, swaps, tasks};
+use std::io;
+use uom::si::information::kilobyte;
+
+pub struct KernelCollector;
+
+impl KernelCollector {
+	pub(crate) const fn new() -> Result<Self, MetricError> {
+		Ok(Self {})
+	}
+}
+
+#[async_trait::async_trait]
+impl Collector for KernelCollector {
+	fn name(&self) -> &'static str {
+		"host kernel collector"
+	}
+
+	async fn collect(&self, buffer: &mut Vec<Metric>) -> Result<(), MetricError> {
+		match swaps::swaps().await {
+			Ok(swaps) => {
+				for swap in swaps {
+					let tags = shared_tags!("device" => swap.get_filename().to_string(), "type" => swap.get_kind().to_string());
+					buffer.push(gauge!(SWAP_SIZE, swap.get_size().get::<kilobyte>(), tags = &tags));
+					buffer.push(gauge!(SWAP_USED, swap.get_used().get::<kilobyte>(), tags = &tags));
+					buffer.push(gauge!(SWAP_PRIORITY, *swap.get_priority() as f64, tags = &tags));
+					if let Some(utilization) = swap.utilization() {
+						buffer.push(gauge!(SWAP_UTILIZATION, utilization, tags = &tags));
+					}
+				}
+			},
+			Err(error) => {
+				warn!("Failed to collect swaps metrics: {error}");
+				return Err(io::Error::last_os_error().into());
+			},
+		}
+		match fs::file_nr().await {
+			Ok(file_nr) => {
+				buffer.push(gauge!(FILE_HANDLES_ALLOCATED, file_nr.allocated()));
+				buffer.push(gauge!(FILE_HANDLES_UNUSED, file_nr.unused()));
+				buffer.push(gauge!(FILE_HANDLES_MAX, file_nr.max()));
+				buffer.push(gauge!(FILE_HANDLES_UTILIZATION, file_nr.utilization()));
+			},
+			Err(error) => {
+				warn!("Failed to collect file-nr metrics: {error}");
+				return Err(io::Error::last_os_error().into());
+			},
+		}
+		match fs::inode_nr().await {
+			Ok(inode_nr) => {
+				buffer.push(gauge!(INODES_ALLOCATED, inode_nr.allocated()));
+				buffer.push(gauge!(INODES_FREE, inode_nr.free()));
+				buffer.push(gauge!(INODES_UTILIZATION, inode_nr.utilization()));
+			},
+			Err(error) => {
+				warn!("Failed to collect inode-nr metrics: {error}");
+				return Err(io::Error::last_os_error().into());
+			},
+		}
+		match tasks::tasks().await {
+			Ok(tasks) => {
+				buffer.push(gauge!(TASKS, tasks.count()));
+				buffer.push(gauge!(PID_MAX, tasks.pid_max()));
+				buffer.push(gauge!(PID_UTILIZATION, tasks.pid_utilization()));
+				buffer.push(gauge!(THREADS_MAX, tasks.threads_max()));
+				buffer.push(gauge!(THREADS_UTILIZATION, tasks.threads_utilization()));
+			},
+			Err(error) => {
+				warn!("Failed to collect task limit metrics: {error}");
+				return Err(io::Error::last_os_error().into());
+			},
+		}
+		match conntrack::conntrack().await {
+			Ok(Some(conntrack)) => {
+				buffer.push(gauge!(CONNTRACK_COUNT, conntrack.count()));
+				buffer.push(gauge!(CONNTRACK_MAX, conntrack.max()));
+				buffer.push(gauge!(CONNTRACK_UTILIZATION, conntrack.utilization()));
+			},
+			Ok(None) => {},
+			Err(error) => {
+				warn!("Failed to collect conntrack metrics: {error}");
+				return Err(io::Error::last_os_error().into());
+			},
+		}
+		match tasks::entropy().await {
+			Ok(entropy) => {
+				buffer.push(gauge!(ENTROPY_AVAIL, entropy.available()));
+				buffer.push(gauge!(ENTROPY_POOL_SIZE, entropy.pool_size()));
+				buffer.push(gauge!(ENTROPY_UTILIZATION, entropy.utilization()));
+			},
+			Err(error) => {
+				warn!("Failed to collect entropy metrics: {error}");
+				return Err(io::Error::last_os_error().into());
+			},
+		}
+		Ok(())
+	}
+}
@@ -1,15 +1,18 @@
 use super::{Collector, MetricError, constants::*};
 use log::warn;
-use prism_event::{gauge, metric::Metric};
-use prism_memory::*;
-use std::io;
+use prism_event::{counter, gauge, metric::Metric};
+use prism_memory::{vmstat::VmStatRates, *};
+use std::{io, sync::Mutex, time::Instant};
 use uom::si::information::kilobyte;
 
-pub struct MemoryCollector;
+pub struct MemoryCollector {
+	/// Previous vmstat sample, used to derive rates
+	previous: Mutex<Option<(Instant, vmstat::VmStat)>>,
+}
 
 impl MemoryCollector {
 	pub(crate) const fn new() -> Result<Self, MetricError> {
-		Ok(Self {})
+		Ok(Self { previous: Mutex::new(None) })
 	}
 }
 
@@ -55,6 +58,14 @@ impl Collector for MemoryCollector {
 				buffer.push(gauge!(VMALLOC_TOTAL, meminfo.get_VmallocTotal().get::<kilobyte>()));
 				buffer.push(gauge!(VMALLOC_USED, meminfo.get_VmallocUsed().get::<kilobyte>()));
 				buffer.push(gauge!(VMALLOC_CHUNK, meminfo.get_VmallocChunk().get::<kilobyte>()));
+				buffer.push(gauge!(HUGEPAGES_TOTAL, meminfo.get_HugePages_Total()));
+				buffer.push(gauge!(HUGEPAGES_FREE, meminfo.get_HugePages_Free()));
+				buffer.push(gauge!(HUGEPAGES_RSVD, meminfo.get_HugePages_Rsvd()));
+				buffer.push(gauge!(HUGEPAGES_SURP, meminfo.get_HugePages_Surp()));
+				buffer.push(gauge!(HUGEPAGESIZE, meminfo.get_Hugepagesize().get::<kilobyte>()));
+				if let Some(hugetlb) = meminfo.get_Hugetlb() {
+					buffer.push(gauge!(HUGETLB, hugetlb.get::<kilobyte>()));
+				}
 			},
 			Err(error) => {
 				warn!("Failed to collect memory metrics: {error}");
@@ -81,8 +92,8 @@ impl Collector for MemoryCollector {
 				buffer.push(gauge!(NR_KERNEL_STACK, vmstat.get_nr_kernel_stack()));
 				buffer.push(gauge!(NR_UNSTABLE, vmstat.get_nr_unstable()));
 				buffer.push(gauge!(NR_BOUNCE, vmstat.get_nr_bounce()));
-				buffer.push(gauge!(NR_VMSCAN_WRITE, vmstat.get_nr_vmscan_write()));
-				buffer.push(gauge!(
+				buffer.push(counter!(NR_VMSCAN_WRITE, vmstat.get_nr_vmscan_write()));
+				buffer.push(counter!(
 					NR_VMSCAN_IMMEDIATE_RECLAIM,
 					vmstat.get_nr_vmscan_immediate_reclaim()
 				));
@@ -90,15 +101,15 @@ impl Collector for MemoryCollector {
 				buffer.push(gauge!(NR_ISOLATED_ANON, vmstat.get_nr_isolated_anon()));
 				buffer.push(gauge!(NR_ISOLATED_FILE, vmstat.get_nr_isolated_file()));
 				buffer.push(gauge!(NR_SHMEM, vmstat.get_nr_shmem()));
-				buffer.push(gauge!(NR_DIRTIED, vmstat.get_nr_dirtied()));
-				buffer.push(gauge!(NR_WRITTEN, vmstat.get_nr_written()));
-				buffer.push(gauge!(NUMA_HIT, vmstat.get_numa_hit()));
-				buffer.push(gauge!(NUMA_MISS, vmstat.get_numa_miss()));
-				buffer.push(gauge!(NUMA_FOREIGN, vmstat.get_numa_foreign()));
-				buffer.push(gauge!(NUMA_INTERLEAVE, vmstat.get_numa_interleave()));
-				buffer.push(gauge!(NUMA_LOCAL, vmstat.get_numa_local()));
-				buffer.push(gauge!(NUMA_OTHER, vmstat.get_numa_other()));
-				buffer.push(gauge!(WORKINGSET_NODERECLAIM, vmstat.get_workingset_nodereclaim()));
+				buffer.push(counter!(NR_DIRTIED, vmstat.get_nr_dirtied()));
+				buffer.push(counter!(NR_WRITTEN, vmstat.get_nr_written()));
+				buffer.push(counter!(NUMA_HIT, vmstat.get_numa_hit()));
+				buffer.push(counter!(NUMA_MISS, vmstat.get_numa_miss()));
+				buffer.push(counter!(NUMA_FOREIGN, vmstat.get_numa_foreign()));
+				buffer.push(counter!(NUMA_INTERLEAVE, vmstat.get_numa_interleave()));
+				buffer.push(counter!(NUMA_LOCAL, vmstat.get_numa_local()));
+				buffer.push(counter!(NUMA_OTHER, vmstat.get_numa_other()));
+				buffer.push(counter!(WORKINGSET_NODERECLAIM, vmstat.get_workingset_nodereclaim()));
 				buffer.push(gauge!(
 					NR_ANON_TRANSPARENT_HUGEPAGES,
 					vmstat.get_nr_anon_transparent_hugepages()
@@ -109,83 +120,135 @@ impl Collector for MemoryCollector {
 					NR_DIRTY_BACKGROUND_THRESHOLD,
 					vmstat.get_nr_dirty_background_threshold()
 				));
-				buffer.push(gauge!(PGPGIN, vmstat.get_pgpgin()));
-				buffer.push(gauge!(PGPGOUT, vmstat.get_pgpgout()));
-				buffer.push(gauge!(PSWPIN, vmstat.get_pswpin()));
-				buffer.push(gauge!(PSWPOUT, vmstat.get_pswpout()));
-				buffer.push(gauge!(PGALLOC_DMA, vmstat.get_pgalloc_dma()));
-				buffer.push(gauge!(PGALLOC_DMA32, vmstat.get_pgalloc_dma32()));
-				buffer.push(gauge!(PGALLOC_NORMAL, vmstat.get_pgalloc_normal()));
-				buffer.push(gauge!(PGALLOC_MOVABLE, vmstat.get_pgalloc_movable()));
-				buffer.push(gauge!(PGFREE, vmstat.get_pgfree()));
-				buffer.push(gauge!(PGACTIVATE, vmstat.get_pgactivate()));
-				buffer.push(gauge!(PGDEACTIVATE, vmstat.get_pgdeactivate()));
-				buffer.push(gauge!(PGFAULT, vmstat.get_pgfault()));
-				buffer.push(gauge!(PGMAJFAULT, vmstat.get_pgmajfault()));
-				buffer.push(gauge!(PGSCAN_DIRECT_THROTTLE, vmstat.get_pgscan_direct_throttle()));
-				buffer.push(gauge!(PGINODESTEAL, vmstat.get_pginodesteal()));
-				buffer.push(gauge!(SLABS_SCANNED, vmstat.get_slabs_scanned()));
-				buffer.push(gauge!(KSWAPD_INODESTEAL, vmstat.get_kswapd_inodesteal()));
-				buffer.push(gauge!(
+				buffer.push(counter!(PGPGIN, vmstat.get_pgpgin()));
+				buffer.push(counter!(PGPGOUT, vmstat.get_pgpgout()));
+				buffer.push(counter!(PSWPIN, vmstat.get_pswpin()));
+				buffer.push(counter!(PSWPOUT, vmstat.get_pswpout()));
+				buffer.push(counter!(PGALLOC_DMA, vmstat.get_pgalloc_dma()));
+				buffer.push(counter!(PGALLOC_DMA32, vmstat.get_pgalloc_dma32()));
+				buffer.push(counter!(PGALLOC_NORMAL, vmstat.get_pgalloc_normal()));
+				buffer.push(counter!(PGALLOC_MOVABLE, vmstat.get_pgalloc_movable()));
+				buffer.push(counter!(PGFREE, vmstat.get_pgfree()));
+				buffer.push(counter!(PGACTIVATE, vmstat.get_pgactivate()));
+				buffer.push(counter!(PGDEACTIVATE, vmstat.get_pgdeactivate()));
+				buffer.push(counter!(PGFAULT, vmstat.get_pgfault()));
+				buffer.push(counter!(PGMAJFAULT, vmstat.get_pgmajfault()));
+				buffer.push(counter!(PGSCAN_DIRECT_THROTTLE, vmstat.get_pgscan_direct_throttle()));
+				buffer.push(counter!(PGINODESTEAL, vmstat.get_pginodesteal()));
+				buffer.push(counter!(SLABS_SCANNED, vmstat.get_slabs_scanned()));
+				buffer.push(counter!(KSWAPD_INODESTEAL, vmstat.get_kswapd_inodesteal()));
+				buffer.push(counter!(
 					KSWAPD_LOW_WMARK_HIT_QUICKLY,
 					vmstat.get_kswapd_low_wmark_hit_quickly()
 				));
-				buffer.push(gauge!(
+				buffer.push(counter!(
 					KSWAPD_HIGH_WMARK_HIT_QUICKLY,
 					vmstat.get_kswapd_high_wmark_hit_quickly()
 				));
-				buffer.push(gauge!(PAGEOUTRUN, vmstat.get_pageoutrun()));
-				buffer.push(gauge!(PGROTATED, vmstat.get_pgrotated()));
-				buffer.push(gauge!(DROP_PAGECACHE, vmstat.get_drop_pagecache()));
-				buffer.push(gauge!(DROP_SLAB, vmstat.get_drop_slab()));
-				buffer.push(gauge!(NUMA_PTE_UPDATES, vmstat.get_numa_pte_updates()));
-				buffer.push(gauge!(NUMA_HUGE_PTE_UPDATES, vmstat.get_numa_huge_pte_updates()));
-				buffer.push(gauge!(NUMA_HINT_FAULTS, vmstat.get_numa_hint_faults()));
-				buffer.push(gauge!(NUMA_HINT_FAULTS_LOCAL, vmstat.get_numa_hint_faults_local()));
-				buffer.push(gauge!(NUMA_PAGES_MIGRATED, vmstat.get_numa_pages_migrated()));
-				buffer.push(gauge!(PGMIGRATE_SUCCESS, vmstat.get_pgmigrate_success()));
-				buffer.push(gauge!(PGMIGRATE_FAIL, vmstat.get_pgmigrate_fail()));
-				buffer.push(gauge!(COMPACT_MIGRATE_SCANNED, vmstat.get_compact_migrate_scanned()));
-				buffer.push(gauge!(COMPACT_FREE_SCANNED, vmstat.get_compact_free_scanned()));
-				buffer.push(gauge!(COMPACT_ISOLATED, vmstat.get_compact_isolated()));
-				buffer.push(gauge!(COMPACT_STALL, vmstat.get_compact_stall()));
-				buffer.push(gauge!(COMPACT_FAIL, vmstat.get_compact_fail()));
-				buffer.push(gauge!(COMPACT_SUCCESS, vmstat.get_compact_success()));
+				buffer.push(counter!(PAGEOUTRUN, vmstat.get_pageoutrun()));
+				buffer.push(counter!(PGROTATED, vmstat.get_pgrotated()));
+				buffer.push(counter!(DROP_PAGECACHE, vmstat.get_drop_pagecache()));
+				buffer.push(counter!(DROP_SLAB, vmstat.get_drop_slab()));
+				buffer.push(counter!(NUMA_PTE_UPDATES, vmstat.get_numa_pte_updates()));
+				buffer.push(counter!(NUMA_HUGE_PTE_UPDATES, vmstat.get_numa_huge_pte_updates()));
+				buffer.push(counter!(NUMA_HINT_FAULTS, vmstat.get_numa_hint_faults()));
+				buffer.push(counter!(NUMA_HINT_FAULTS_LOCAL, vmstat.get_numa_hint_faults_local()));
+				buffer.push(counter!(NUMA_PAGES_MIGRATED, vmstat.get_numa_pages_migrated()));
+				buffer.push(counter!(PGMIGRATE_SUCCESS, vmstat.get_pgmigrate_success()));
+				buffer.push(counter!(PGMIGRATE_FAIL, vmstat.get_pgmigrate_fail()));
 				buffer
-					.push(gauge!(HTLB_BUDDY_ALLOC_SUCCESS, vmstat.get_htlb_buddy_alloc_success()));
-				buffer.push(gauge!(HTLB_BUDDY_ALLOC_FAIL, vmstat.get_htlb_buddy_alloc_fail()));
-				buffer.push(gauge!(UNEVICTABLE_PGS_CULLED, vmstat.get_unevictable_pgs_culled()));
-				buffer.push(gauge!(UNEVICTABLE_PGS_SCANNED, vmstat.get_unevictable_pgs_scanned()));
-				buffer.push(gauge!(UNEVICTABLE_PGS_RESCUED, vmstat.get_unevictable_pgs_rescued()));
-				buffer.push(gauge!(UNEVICTABLE_PGS_MLOCKED, vmstat.get_unevictable_pgs_mlocked()));
-				buffer.push(gauge!(
+					.push(counter!(COMPACT_MIGRATE_SCANNED, vmstat.get_compact_migrate_scanned()));
+				buffer.push(counter!(COMPACT_FREE_SCANNED, vmstat.get_compact_free_scanned()));
+				buffer.push(counter!(COMPACT_ISOLATED, vmstat.get_compact_isolated()));
+				buffer.push(counter!(COMPACT_STALL, vmstat.get_compact_stall()));
+				buffer.push(counter!(COMPACT_FAIL, vmstat.get_compact_fail()));
+				buffer.push(counter!(COMPACT_SUCCESS, vmstat.get_compact_success()));
+				buffer.push(counter!(
+					HTLB_BUDDY_ALLOC_SUCCESS,
+					vmstat.get_htlb_buddy_alloc_success()
+				));
+				buffer.push(counter!(HTLB_BUDDY_ALLOC_FAIL, vmstat.get_htlb_buddy_alloc_fail()));
+				buffer.push(counter!(UNEVICTABLE_PGS_CULLED, vmstat.get_unevictable_pgs_culled()));
+				buffer
+					.push(counter!(UNEVICTABLE_PGS_SCANNED, vmstat.get_unevictable_pgs_scanned()));
+				buffer
+					.push(counter!(UNEVICTABLE_PGS_RESCUED, vmstat.get_unevictable_pgs_rescued()));
+				buffer
+					.push(counter!(UNEVICTABLE_PGS_MLOCKED, vmstat.get_unevictable_pgs_mlocked()));
+				buffer.push(counter!(
 					UNEVICTABLE_PGS_MUNLOCKED,
 					vmstat.get_unevictable_pgs_munlocked()
 				));
-				buffer.push(gauge!(UNEVICTABLE_PGS_CLEARED, vmstat.get_unevictable_pgs_cleared()));
 				buffer
-					.push(gauge!(UNEVICTABLE_PGS_STRANDED, vmstat.get_unevictable_pgs_stranded()));
-				buffer.push(gauge!(THP_FAULT_ALLOC, vmstat.get_thp_fault_alloc()));
-				buffer.push(gauge!(THP_FAULT_FALLBACK, vmstat.get_thp_fault_fallback()));
-				buffer.push(gauge!(THP_COLLAPSE_ALLOC, vmstat.get_thp_collapse_alloc()));
-				buffer.push(gauge!(
+					.push(counter!(UNEVICTABLE_PGS_CLEARED, vmstat.get_unevictable_pgs_cleared()));
+				buffer.push(counter!(
+					UNEVICTABLE_PGS_STRANDED,
+					vmstat.get_unevictable_pgs_stranded()
+				));
+				buffer.push(counter!(THP_FAULT_ALLOC, vmstat.get_thp_fault_alloc()));
+				buffer.push(counter!(THP_FAULT_FALLBACK, vmstat.get_thp_fault_fallback()));
+				buffer.push(counter!(THP_COLLAPSE_ALLOC, vmstat.get_thp_collapse_alloc()));
+				buffer.push(counter!(
 					THP_COLLAPSE_ALLOC_FAILED,
 					vmstat.get_thp_collapse_alloc_failed()
 				));
-				buffer.push(gauge!(THP_ZERO_PAGE_ALLOC, vmstat.get_thp_zero_page_alloc()));
-				buffer.push(gauge!(
+				buffer.push(counter!(THP_ZERO_PAGE_ALLOC, vmstat.get_thp_zero_page_alloc()));
+				buffer.push(counter!(
 					THP_ZERO_PAGE_ALLOC_FAILED,
 					vmstat.get_thp_zero_page_alloc_failed()
 				));
-				buffer.push(gauge!(BALLOON_INFLATE, vmstat.get_balloon_inflate()));
-				buffer.push(gauge!(BALLOON_DEFLATE, vmstat.get_balloon_deflate()));
-				buffer.push(gauge!(BALLOON_MIGRATE, vmstat.get_balloon_migrate()));
+				buffer.push(counter!(BALLOON_INFLATE, vmstat.get_balloon_inflate()));
+				buffer.push(counter!(BALLOON_DEFLATE, vmstat.get_balloon_deflate()));
+				buffer.push(counter!(BALLOON_MIGRATE, vmstat.get_balloon_migrate()));
+
+				let now = Instant::now();
+				let mut previous = self.previous.lock().unwrap_or_else(|e| e.into_inner());
+				if let Some((at, previous)) = previous.as_ref() {
+					let rates = VmStatRates::new(previous, &vmstat, now.duration_since(*at));
+					buffer.push(gauge!(PGFAULT_RATE, rates.pgfault()));
+					buffer.push(gauge!(PGMAJFAULT_RATE, rates.pgmajfault()));
+					buffer.push(gauge!(PSWPIN_RATE, rates.pswpin()));
+					buffer.push(gauge!(PSWPOUT_RATE, rates.pswpout()));
+					if let Some(rate) = rates.pgscan_kswapd() {
+						buffer.push(gauge!(PGSCAN_KSWAPD_RATE, rate));
+					}
+					if let Some(rate) = rates.pgscan_direct() {
+						buffer.push(gauge!(PGSCAN_DIRECT_RATE, rate));
+					}
+					if let Some(rate) = rates.pgsteal_kswapd() {
+						buffer.push(gauge!(PGSTEAL_KSWAPD_RATE, rate));
+					}
+					if let Some(rate) = rates.pgsteal_direct() {
+						buffer.push(gauge!(PGSTEAL_DIRECT_RATE, rate));
+					}
+					if let Some(efficiency) = rates.reclaim_efficiency() {
+						buffer.push(gauge!(RECLAIM_EFFICIENCY, efficiency));
+					}
+					if let Some(kills) = rates.oom_kill() {
+						buffer.push(gauge!(OOM_KILLS, kills));
+					}
+				}
+				*previous = Some((now, vmstat));
 			},
 			Err(error) => {
 				warn!("Failed to collect vmstat metrics: {error}");
 				return Err(io::Error::last_os_error().into());
 			},
 		}
+		match hugepages::hugepages().await {
+			Ok(pools) => {
+				for pool in pools {
+					let size = format!("{}kB", pool.size().get::<kilobyte>());
+					buffer.push(gauge!(NR_HUGEPAGES, pool.nr(), "size" => size.clone()));
+					buffer.push(gauge!(FREE_HUGEPAGES, pool.free(), "size" => size.clone()));
+					buffer.push(gauge!(SURPLUS_HUGEPAGES, pool.surplus(), "size" => size));
+				}
+			},
+			Err(error) => {
+				warn!("Failed to collect hugepages metrics: {error}");
+				return Err(io::Error::last_os_error().into());
+			},
+		}
 		Ok(())
 	}
 }
@@ -1,19 +1,43 @@
 use super::{Collector, MetricError};
+use constants::COLLECTION_LATENCY;
 use cpu::CpuCollector;
+use descriptors::DESCRIPTORS;
 use disk::DiskCollector;
+use fragmentation::FragmentationCollector;
+use hwmon::HwmonCollector;
+use inventory::InventoryCollector;
+pub(super) use inventory::identity_tags;
+use kernel::KernelCollector;
 use log::warn;
 use memory::MemoryCollector;
 use network::NetworkCollector;
-use prism_event::metric::Metric;
+use numa::NumaCollector;
+use prism_event::{
+	metric::{Buckets, Histogram, Metric, MetricValue, descriptor::Descriptors},
+	metric_tags,
+};
+use raid::RaidCollector;
+use std::time::Instant;
 
 mod constants;
 mod cpu;
+mod descriptors;
 mod disk;
+mod fragmentation;
+mod hwmon;
+mod inventory;
+mod kernel;
 mod memory;
 mod network;
+mod numa;
+mod raid;
 
 pub(super) struct HostCollector {
 	collectors: Vec<Box<dyn Collector>>,
+	/// Collection latency of each collector since the start, in seconds
+	latencies: Vec<Histogram>,
+	/// Descriptors attached to the host series
+	descriptors: Descriptors,
 }
 
 impl HostCollector {
@@ -23,11 +47,27 @@ impl HostCollector {
 		let memory_collector = MemoryCollector::new()?;
 		let network_collector = NetworkCollector::new()?;
 		let disk_collector = DiskCollector::new()?;
+		let hwmon_collector = HwmonCollector::new()?;
+		let numa_collector = NumaCollector::new()?;
+		let fragmentation_collector = FragmentationCollector::new()?;
+		let kernel_collector = KernelCollector::new()?;
+		let raid_collector = RaidCollector::new()?;
+		let inventory_collector = InventoryCollector::new()?;
 		collectors.push(Box::new(cpu_collector));
 		collectors.push(Box::new(memory_collector));
 		collectors.push(Box::new(network_collector));
 		collectors.push(Box::new(disk_collector));
-		Ok(Self { collectors })
+		collectors.push(Box::new(hwmon_collector));
+		collectors.push(Box::new(numa_collector));
+		collectors.push(Box::new(fragmentation_collector));
+		collectors.push(Box::new(kernel_collector));
+		collectors.push(Box::new(raid_collector));
+		collectors.push(Box::new(inventory_collector));
+		// Scale 2 buckets grow by ~19%, enough to tell a slow filesystem read
+		// from a regular one.
+		let latencies =
+			collectors.iter().map(|_| Histogram::new(Buckets::exponential(2))).collect();
+		Ok(Self { collectors, latencies, descriptors: Descriptors::new(DESCRIPTORS) })
 	}
 }
 
@@ -38,10 +78,24 @@ impl Collector for HostCollector {
 	}
 
 	async fn collect(&self, buffer: &mut Vec<Metric>) -> Result<(), MetricError> {
-		for collector in &self.collectors {
+		let first = buffer.len();
+		for (collector, latency) in self.collectors.iter().zip(&self.latencies) {
+			let start = Instant::now();
 			if let Err(e) = collector.collect(buffer).await {
 				warn!("Collector {} failed: {:?}", collector.name(), e);
 			}
+			latency.record(start.elapsed());
+			if let Some(snapshot) = latency.snapshot() {
+				buffer.push(
+					Metric::new(COLLECTION_LATENCY, MetricValue::Histogram(snapshot))
+						.with_namespace(Some("host"))
+						.with_tags(metric_tags!("collector" => collector.name())),
+				);
+			}
+		}
+		// Only the samples of this collection, the buffer may hold others
+		for metric in &mut buffer[first..] {
+			self.descriptors.describe(metric);
 		}
 		Ok(())
 	}
@@ -1,9 +1,16 @@
 use super::{Collector, MetricError, constants::*};
 use log::warn;
-use prism_event::{gauge, metric::Metric};
-use prism_network::netdev::interfaces;
+use prism_event::{counter, gauge, info_metric, metric::Metric, shared_tags, state_set};
+use prism_network::{
+	link::{Duplex, LinkRole, link},
+	netdev::interfaces,
+};
 use std::io;
-use uom::si::information::byte;
+use uom::si::{information::byte, information_rate::megabit_per_second};
+
+/// Operational states of RFC 2863, as reported by `/sys/class/net/<interface>/operstate`
+const OPERSTATES: [&str; 7] =
+	["unknown", "notpresent", "down", "lowerlayerdown", "testing", "dormant", "up"];
 
 pub struct NetworkCollector;
 
@@ -20,64 +27,72 @@ impl Collector for NetworkCollector {
 	}
 
 	async fn collect(&self, buffer: &mut Vec<Metric>) -> Result<(), MetricError> {
-		match interfaces().await {
-			Ok(interfaces) => {
-				for interface in interfaces {
-					let interface_name = interface.name.clone();
-
-					// Receive metrics
-					buffer.push(gauge!(RX_BYTES, interface.get_rx_bytes().get::<byte>(), "interface" => interface_name.clone()));
-					buffer.push(
-						gauge!(RX_PACKETS, interface.get_rx_packets(), "interface" => interface_name.clone()),
-					);
-					buffer.push(
-						gauge!(RX_ERRORS, interface.get_rx_errors(), "interface" => interface_name.clone()),
-					);
-					buffer.push(
-						gauge!(RX_DROPPED, interface.get_rx_dropped(), "interface" => interface_name.clone()),
-					);
-					buffer.push(
-						gauge!(RX_FIFO, interface.get_rx_fifo(), "interface" => interface_name.clone()),
-					);
-					buffer.push(
-						gauge!(RX_FRAME, interface.get_rx_frame(), "interface" => interface_name.clone()),
-					);
-					buffer.push(
-						gauge!(RX_COMPRESSED, interface.get_rx_compressed(), "interface" => interface_name.clone()),
-					);
-					buffer.push(
-						gauge!(RX_MULTICAST, interface.get_rx_multicast(), "interface" => interface_name.clone()),
-					);
-
-					// Transmit metrics
-					buffer.push(gauge!(TX_BYTES, interface.get_tx_bytes().get::<byte>(), "interface" => interface_name.clone()));
-					buffer.push(
-						gauge!(TX_PACKETS, interface.get_tx_packets(), "interface" => interface_name.clone()),
-					);
-					buffer.push(
-						gauge!(TX_ERRORS, interface.get_tx_errors(), "interface" => interface_name.clone()),
-					);
-					buffer.push(
-						gauge!(TX_DROPPED, interface.get_tx_dropped(), "interface" => interface_name.clone()),
-					);
-					buffer.push(
-						gauge!(TX_FIFO, interface.get_tx_fifo(), "interface" => interface_name.clone()),
-					);
-					buffer.push(
-						gauge!(TX_COLLS, interface.get_tx_colls(), "interface" => interface_name.clone()),
-					);
-					buffer.push(
-						gauge!(TX_CARRIER, interface.get_tx_carrier(), "interface" => interface_name.clone()),
-					);
-					buffer.push(
-						gauge!(TX_COMPRESSED, interface.get_tx_compressed(), "interface" => interface_name.clone()),
-					);
-				}
-			},
+		// `/proc/net/dev` errors are not `Send` and must not live across the link reads.
+		let interfaces = match interfaces().await {
+			Ok(interfaces) => interfaces,
 			Err(error) => {
 				warn!("Failed to collect network metrics: {error}");
 				return Err(io::Error::last_os_error().into());
 			},
+		};
+		for interface in interfaces {
+			let interface_name = interface.name.clone();
+			let tags = shared_tags!("interface" => interface_name.clone());
+
+			// Receive metrics
+			buffer.push(counter!(RX_BYTES, interface.get_rx_bytes().get::<byte>(), tags = &tags));
+			buffer.push(counter!(RX_PACKETS, interface.get_rx_packets(), tags = &tags));
+			buffer.push(counter!(RX_ERRORS, interface.get_rx_errors(), tags = &tags));
+			buffer.push(counter!(RX_DROPPED, interface.get_rx_dropped(), tags = &tags));
+			buffer.push(counter!(RX_FIFO, interface.get_rx_fifo(), tags = &tags));
+			buffer.push(counter!(RX_FRAME, interface.get_rx_frame(), tags = &tags));
+			buffer.push(counter!(RX_COMPRESSED, interface.get_rx_compressed(), tags = &tags));
+			buffer.push(counter!(RX_MULTICAST, interface.get_rx_multicast(), tags = &tags));
+
+			// Transmit metrics
+			buffer.push(counter!(TX_BYTES, interface.get_tx_bytes().get::<byte>(), tags = &tags));
+			buffer.push(counter!(TX_PACKETS, interface.get_tx_packets(), tags = &tags));
+			buffer.push(counter!(TX_ERRORS, interface.get_tx_errors(), tags = &tags));
+			buffer.push(counter!(TX_DROPPED, interface.get_tx_dropped(), tags = &tags));
+			buffer.push(counter!(TX_FIFO, interface.get_tx_fifo(), tags = &tags));
+			buffer.push(counter!(TX_COLLS, interface.get_tx_colls(), tags = &tags));
+			buffer.push(counter!(TX_CARRIER, interface.get_tx_carrier(), tags = &tags));
+			buffer.push(counter!(TX_COMPRESSED, interface.get_tx_compressed(), tags = &tags));
+
+			// Link state
+			let link = match link(&interface_name).await {
+				Ok(Some(link)) => link,
+				Ok(None) => continue,
+				Err(error) => {
+					warn!("Failed to collect {interface_name} link metrics: {error}");
+					continue;
+				},
+			};
+			let role = link.role().as_str();
+			let tags = shared_tags!("interface" => interface_name.clone(), "role" => role);
+			buffer.push(info_metric!(LINK_INFO, "interface" => interface_name.clone(), "role" => role, "master" => link.master().unwrap_or_default().to_string(), "address" => link.address().to_string(), "operstate" => link.operstate().to_string(), "duplex" => link.duplex().as_str(), "type" => link.kind().to_string()));
+			buffer.push(gauge!(LINK_UP, u8::from(link.is_up()), tags = &tags));
+			buffer.push(state_set!(LINK_OPERSTATE, OPERSTATES, link.operstate().to_string(), "interface" => interface_name.clone(), "role" => role));
+			buffer.push(gauge!(LINK_MTU, link.mtu(), tags = &tags));
+			if let Some(carrier) = link.carrier() {
+				buffer.push(gauge!(LINK_CARRIER, u8::from(carrier), tags = &tags));
+			}
+			if let Some(changes) = link.carrier_changes() {
+				buffer.push(counter!(LINK_CARRIER_CHANGES, changes, tags = &tags));
+			}
+			if let Some(speed) = link.speed() {
+				buffer.push(gauge!(LINK_SPEED, speed.get::<megabit_per_second>(), tags = &tags));
+			}
+			if link.duplex() != Duplex::Unknown {
+				buffer.push(gauge!(
+					LINK_FULL_DUPLEX,
+					u8::from(link.duplex() == Duplex::Full),
+					tags = &tags
+				));
+			}
+			if matches!(link.role(), LinkRole::Bond | LinkRole::Bridge) {
+				buffer.push(gauge!(LINK_MEMBERS, link.members().len() as u64, tags = &tags));
+			}
 		}
 		Ok(())
 	}
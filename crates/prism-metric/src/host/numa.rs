@@ -0,0 +1,149 @@
+use super::{Collector, MetricError, constants::*};
+use log::warn;
+use prism_event::{counter, gauge, metric::Metric, shared_tags};
+use prism_memory::numa;
+use std::io;
+use uom::si::information::kilobyte;
+
+pub struct NumaCollector;
+
+impl NumaCollector {
+	pub(crate) const fn new() -> Result<Self, MetricError> {
+		Ok(Self {})
+	}
+}
+
+#[async_trait::async_trait]
+impl Collector for NumaCollector {
+	fn name(&self) -> &'static str {
+		"host numa collector"
+	}
+
+	async fn collect(&self, buffer: &mut Vec<Metric>) -> Result<(), MetricError> {
+		match numa::nodes().await {
+			Ok(nodes) => {
+				for node in nodes {
+					let id = format!("node{}", node.id());
+					let tags = shared_tags!("node" => id.clone());
+
+					let meminfo = node.meminfo();
+					buffer.push(gauge!(
+						MEM_TOTAL,
+						meminfo.get_MemTotal().get::<kilobyte>(),
+						tags = &tags
+					));
+					buffer.push(gauge!(
+						MEM_FREE,
+						meminfo.get_MemFree().get::<kilobyte>(),
+						tags = &tags
+					));
+					buffer.push(gauge!(
+						MEM_USED,
+						meminfo.get_MemUsed().get::<kilobyte>(),
+						tags = &tags
+					));
+					buffer.push(gauge!(
+						ACTIVE,
+						meminfo.get_Active().get::<kilobyte>(),
+						tags = &tags
+					));
+					buffer.push(gauge!(
+						INACTIVE,
+						meminfo.get_Inactive().get::<kilobyte>(),
+						tags = &tags
+					));
+					buffer.push(gauge!(
+						ACTIVE_ANON,
+						meminfo.get_Active_anon().get::<kilobyte>(),
+						tags = &tags
+					));
+					buffer.push(gauge!(
+						INACTIVE_ANON,
+						meminfo.get_Inactive_anon().get::<kilobyte>(),
+						tags = &tags
+					));
+					buffer.push(gauge!(
+						ACTIVE_FILE,
+						meminfo.get_Active_file().get::<kilobyte>(),
+						tags = &tags
+					));
+					buffer.push(gauge!(
+						INACTIVE_FILE,
+						meminfo.get_Inactive_file().get::<kilobyte>(),
+						tags = &tags
+					));
+					buffer.push(gauge!(DIRTY, meminfo.get_Dirty().get::<kilobyte>(), tags = &tags));
+					buffer.push(gauge!(
+						WRITEBACK,
+						meminfo.get_Writeback().get::<kilobyte>(),
+						tags = &tags
+					));
+					buffer.push(gauge!(
+						FILE_PAGES,
+						meminfo.get_FilePages().get::<kilobyte>(),
+						tags = &tags
+					));
+					buffer.push(gauge!(
+						MAPPED,
+						meminfo.get_Mapped().get::<kilobyte>(),
+						tags = &tags
+					));
+					buffer.push(gauge!(
+						ANON_PAGES,
+						meminfo.get_AnonPages().get::<kilobyte>(),
+						tags = &tags
+					));
+					buffer.push(gauge!(SHMEM, meminfo.get_Shmem().get::<kilobyte>(), tags = &tags));
+					buffer.push(gauge!(SLAB, meminfo.get_Slab().get::<kilobyte>(), tags = &tags));
+					buffer.push(gauge!(
+						SRECLAIMABLE,
+						meminfo.get_SReclaimable().get::<kilobyte>(),
+						tags = &tags
+					));
+					buffer.push(gauge!(
+						SUNRECLAIM,
+						meminfo.get_SUnreclaim().get::<kilobyte>(),
+						tags = &tags
+					));
+					buffer.push(gauge!(
+						HUGEPAGES_TOTAL,
+						meminfo.get_HugePages_Total(),
+						tags = &tags
+					));
+					buffer.push(gauge!(HUGEPAGES_FREE, meminfo.get_HugePages_Free(), tags = &tags));
+					buffer.push(gauge!(HUGEPAGES_SURP, meminfo.get_HugePages_Surp(), tags = &tags));
+
+					let numastat = node.numastat();
+					buffer.push(counter!(NUMA_HIT, numastat.get_numa_hit(), tags = &tags));
+					buffer.push(counter!(NUMA_MISS, numastat.get_numa_miss(), tags = &tags));
+					buffer.push(counter!(NUMA_FOREIGN, numastat.get_numa_foreign(), tags = &tags));
+					buffer.push(counter!(
+						INTERLEAVE_HIT,
+						numastat.get_interleave_hit(),
+						tags = &tags
+					));
+					buffer.push(counter!(LOCAL_NODE, numastat.get_local_node(), tags = &tags));
+					buffer.push(counter!(OTHER_NODE, numastat.get_other_node(), tags = &tags));
+
+					for pool in node.hugepages() {
+						let size = format!("{}kB", pool.size().get::<kilobyte>());
+						buffer.push(
+							gauge!(NR_HUGEPAGES, pool.nr(), "node" => id.clone(), "size" => size.clone()),
+						);
+						buffer.push(
+							gauge!(FREE_HUGEPAGES, pool.free(), "node" => id.clone(), "size" => size.clone()),
+						);
+						buffer.push(
+							gauge!(SURPLUS_HUGEPAGES, pool.surplus(), "node" => id.clone(), "size" => size),
+						);
+					}
+				}
+			},
+			Err(error) => {
+				warn!("Failed to collect numa metrics: {error}");
+				return Err(io::Error::last_os_error().into());
+			},
+		}
+		Ok(())
+	}
+}
@@ -0,0 +1,89 @@
+use super::{Collector, MetricError, constants::*};
+use log::warn;
+use prism_disk::mdstat::{self, MemberRole};
+use prism_event::{gauge, metric::Metric, shared_tags, state_set};
+use std::io;
+use uom::si::{information::byte, information_rate::byte_per_second, time::second};
+
+pub struct RaidCollector;
+
+impl RaidCollector {
+	pub(crate) const fn new() -> Result<Self, MetricError> {
+		Ok(Self {})
+	}
+}
+
+#[async_trait::async_trait]
+impl Collector for RaidCollector {
+	fn name(&self) -> &'static str {
+		"host raid collector"
+	}
+
+	async fn collect(&self, buffer: &mut Vec<Metric>) -> Result<(), MetricError> {
+		match mdstat::mdstat().await {
+			Ok(arrays) => {
+				for array in arrays {
+					let name = array.name().to_string();
+					let level = array.level().unwrap_or_default().to_string();
+					let tags = shared_tags!("array" => name.clone(), "level" => level.clone());
+
+					buffer.push(gauge!(RAID_ACTIVE, u8::from(array.is_active()), "array" => name.clone(), "level" => level.clone(), "state" => array.state().to_string()));
+					buffer.push(gauge!(RAID_READ_ONLY, u8::from(array.read_only()), tags = &tags));
+					buffer.push(gauge!(RAID_SIZE, array.size().get::<byte>(), tags = &tags));
+					buffer.push(gauge!(RAID_DEGRADED, u8::from(array.is_degraded()), tags = &tags));
+					let states = [
+						("active", array.is_active()),
+						("inactive", !array.is_active()),
+						("read_only", array.read_only()),
+						("degraded", array.is_degraded()),
+						("syncing", array.sync().is_some()),
+					];
+					buffer.push(state_set!(RAID_STATE, states, tags = &tags));
+					if let Some(raid_disks) = array.raid_disks() {
+						buffer.push(gauge!(RAID_DISKS, raid_disks, tags = &tags));
+					}
+					if let Some(active_disks) = array.active_disks() {
+						buffer.push(gauge!(RAID_ACTIVE_DISKS, active_disks, tags = &tags));
+					}
+					buffer.push(gauge!(
+						RAID_FAILED_MEMBERS,
+						array.count(MemberRole::Failed) as u64,
+						tags = &tags
+					));
+					buffer.push(gauge!(
+						RAID_SPARE_MEMBERS,
+						array.count(MemberRole::Spare) as u64,
+						tags = &tags
+					));
+					for member in array.members() {
+						buffer.push(gauge!(RAID_MEMBER, 1, "array" => name.clone(), "device" => member.name().to_string(), "role" => member.role().as_str(), "slot" => member.slot().to_string()));
+					}
+
+					if let Some(sync) = array.sync() {
+						let sync_tags = shared_tags!("array" => name.clone(), "level" => level.clone(), "action" => sync.action().to_string());
+						buffer.push(gauge!(RAID_SYNC_PROGRESS, sync.progress(), tags = &sync_tags));
+						if let Some(finish) = sync.finish() {
+							buffer.push(gauge!(
+								RAID_SYNC_FINISH,
+								finish.get::<second>(),
+								tags = &sync_tags
+							));
+						}
+						if let Some(speed) = sync.speed() {
+							buffer.push(gauge!(
+								RAID_SYNC_SPEED,
+								speed.get::<byte_per_second>(),
+								tags = &sync_tags
+							));
+						}
+					}
+				}
+			},
+			Err(error) => {
+				warn!("Failed to collect raid metrics: {error}");
+				return Err(io::Error::last_os_error().into());
+			},
+		}
+		Ok(())
+	}
+}
@@ -0,0 +1,294 @@
+//! Kernel log events
+//!
+//! Tails `/dev/kmsg` and turns the messages recognised by
+//! [`prism_kernel::kmsg::classify`] into [`Event`]s. The device only yields
+//! records logged after the collector started; a regular file, e.g. a
+//! captured log used in tests, is read from its beginning and then followed.
+
+use crate::MetricError;
+use chrono::{DateTime, Local, TimeDelta};
+use log::{info, warn};
+use prism_core::Module;
+use prism_event::{
+	event,
+	event::{Event, Severity},
+};
+use prism_kernel::kmsg::{self, KernelEvent, KmsgRecord};
+use prism_metric_common::{init_roots, procfs_root};
+use prism_metric_utils::read_to_string;
+use prism_runtime::handle;
+use std::{
+	fs::{self, OpenOptions},
+	io::{self, Read, Seek, SeekFrom},
+	os::unix::fs::{FileTypeExt, OpenOptionsExt},
+	path::PathBuf,
+	sync::{
+		Arc,
+		atomic::{AtomicBool, Ordering},
+	},
+	time::Duration,
+};
+use tokio::{
+	fs::File,
+	io::{AsyncBufReadExt, BufReader, Interest, unix::AsyncFd},
+	sync::mpsc::Sender,
+	task::JoinHandle,
+	time,
+};
+
+const KMSG_PATH: &str = "/dev/kmsg";
+/// Largest record read from `/dev/kmsg`, a smaller buffer fails with `EINVAL`
+const RECORD_SIZE: usize = 8192;
+/// Delay before reading a regular file again once its end is reached
+const FOLLOW_INTERVAL: Duration = Duration::from_secs(1);
+
+pub struct KmsgCollector {
+	running: Arc<AtomicBool>,
+	path: PathBuf,
+	handle: Option<JoinHandle<Result<(), MetricError>>>,
+	output: Sender<Vec<Event>>,
+}
+
+impl KmsgCollector {
+	pub fn new(output: Sender<Vec<Event>>) -> Self {
+		init_roots();
+		Self { running: Default::default(), path: PathBuf::from(KMSG_PATH), handle: None, output }
+	}
+
+	/// Reads the kernel log from `path` instead of `/dev/kmsg`.
+	#[must_use]
+	pub fn with_path(mut self, path: impl Into<PathBuf>) -> Self {
+		self.path = path.into();
+		self
+	}
+}
+
+/// Wall clock time of the boot, kernel log timestamps are relative to it.
+async fn boot_time() -> Result<DateTime<Local>, MetricError> {
+	let content = read_to_string(procfs_root().join("uptime")).await?;
+	let uptime: f64 = content.split_whitespace().next().unwrap_or_default().parse()?;
+	Ok(Local::now() - TimeDelta::milliseconds((uptime * 1000.0) as i64))
+}
+
+fn to_event(record: &KmsgRecord, kind: KernelEvent, boot: DateTime<Local>) -> Event {
+	let name = kind.name();
+	let severity = Severity::from_level(record.level());
+	let message = record.message().to_string();
+	let sequence = record.sequence();
+	let event = match kind {
+		KernelEvent::OomKill { pid, comm, total_vm, anon_rss, file_rss, shmem_rss } => {
+			event!(name, severity, message, "sequence" => sequence, "pid" => pid, "comm" => comm, "total_vm_kb" => total_vm, "anon_rss_kb" => anon_rss, "file_rss_kb" => file_rss, "shmem_rss_kb" => shmem_rss)
+		},
+		KernelEvent::HungTask { pid, comm, seconds } => {
+			event!(name, severity, message, "sequence" => sequence, "pid" => pid, "comm" => comm, "blocked_seconds" => seconds)
+		},
+		KernelEvent::SoftLockup { cpu, seconds, pid, comm } => {
+			event!(name, severity, message, "sequence" => sequence, "cpu" => cpu, "stuck_seconds" => seconds, "pid" => pid, "comm" => comm)
+		},
+		KernelEvent::IoError { device, sector: Some(sector) } => {
+			event!(name, severity, message, "sequence" => sequence, "device" => device, "sector" => sector)
+		},
+		KernelEvent::IoError { device, sector: None } => {
+			event!(name, severity, message, "sequence" => sequence, "device" => device)
+		},
+		KernelEvent::Segfault { pid, comm, address, ip, error } => {
+			event!(name, severity, message, "sequence" => sequence, "pid" => pid, "comm" => comm, "address" => address, "ip" => ip, "error" => error)
+		},
+		KernelEvent::Link { interface, speed: Some(speed), .. } => {
+			event!(name, severity, message, "sequence" => sequence, "interface" => interface, "speed_mbps" => speed)
+		},
+		KernelEvent::Link { interface, speed: None, .. } => {
+			event!(name, severity, message, "sequence" => sequence, "interface" => interface)
+		},
+	};
+	let offset = TimeDelta::from_std(record.timestamp()).unwrap_or_default();
+	event.with_timestamp((boot + offset).into())
+}
+
+/// Sends the event of a record, if it is one of the recognised messages.
+async fn send(
+	record: &str,
+	boot: DateTime<Local>,
+	output: &Sender<Vec<Event>>,
+) -> Result<(), MetricError> {
+	let Some(record) = kmsg::parse(record) else {
+		return Ok(());
+	};
+	if record.continuation() {
+		return Ok(());
+	}
+	if let Some(kind) = kmsg::classify(record.message()) {
+		let event = to_event(&record, kind, boot);
+		output.send(vec![event]).await.map_err(|_| MetricError::Send)?;
+	}
+	Ok(())
+}
+
+async fn tail(
+	path: PathBuf,
+	running: Arc<AtomicBool>,
+	output: Sender<Vec<Event>>,
+) -> Result<(), MetricError> {
+	let file = match OpenOptions::new().read(true).custom_flags(libc::O_NONBLOCK).open(&path) {
+		Ok(file) => file,
+		Err(e) => {
+			warn!("Failed to open {}, kernel log events are disabled: {e}", path.display());
+			return Ok(());
+		},
+	};
+	let boot = boot_time().await?;
+
+	if file.metadata()?.file_type().is_char_device() {
+		read_device(file, boot, running, output).await
+	} else {
+		follow(File::from_std(file), boot, running, output).await
+	}
+}
+
+/// Reads the records logged from now on, each `read(2)` of the device
+/// returning a single one.
+///
+/// The device is opened non-blocking and polled, so that a pending read holds
+/// no thread and the task can be cancelled while no record comes.
+async fn read_device(
+	file: fs::File,
+	boot: DateTime<Local>,
+	running: Arc<AtomicBool>,
+	output: Sender<Vec<Event>>,
+) -> Result<(), MetricError> {
+	(&file).seek(SeekFrom::End(0))?;
+	let device = AsyncFd::with_interest(file, Interest::READABLE)?;
+
+	let mut buffer = vec![0; RECORD_SIZE];
+	while running.load(Ordering::Relaxed) {
+		let mut guard = device.readable().await?;
+		match guard.try_io(|device| device.get_ref().read(&mut buffer)) {
+			Ok(Ok(len)) => send(&String::from_utf8_lossy(&buffer[..len]), boot, &output).await?,
+			// The reader fell behind and records were overwritten
+			Ok(Err(e)) if e.kind() == io::ErrorKind::BrokenPipe => {
+				warn!("Kernel log records were lost: {e}");
+			},
+			Ok(Err(e)) => return Err(e.into()),
+			// `EAGAIN`, the readiness is cleared until the next record
+			Err(_) => {},
+		}
+	}
+	Ok(())
+}
+
+/// Reads a regular file from its beginning and then follows it.
+async fn follow(
+	file: File,
+	boot: DateTime<Local>,
+	running: Arc<AtomicBool>,
+	output: Sender<Vec<Event>>,
+) -> Result<(), MetricError> {
+	let mut reader = BufReader::new(file);
+	let mut line = String::new();
+	while running.load(Ordering::Relaxed) {
+		line.clear();
+		match reader.read_line(&mut line).await? {
+			0 => time::sleep(FOLLOW_INTERVAL).await,
+			_ => send(&line, boot, &output).await?,
+		}
+	}
+	Ok(())
+}
+
+impl Module for KmsgCollector {
+	type Config = ();
+	type Error = MetricError;
+	type Output = ();
+
+	fn name(&self) -> &str {
+		"Kmsg Collector"
+	}
+
+	fn start(&mut self) -> Result<(), Self::Error> {
+		if self.running.swap(true, Ordering::Relaxed) {
+			warn!("{} is already running.", self.name());
+			return Ok(());
+		}
+		let running = Arc::clone(&self.running);
+		let output = self.output.clone();
+		let path = self.path.clone();
+		self.handle = Some(handle().spawn(tail(path, running, output)));
+		Ok(())
+	}
+
+	async fn stop(&mut self) -> Result<Self::Output, Self::Error> {
+		if !self.running.swap(false, Ordering::Relaxed) {
+			warn!("{} is already stopped.", self.name());
+			return Ok(());
+		}
+
+		// The task waits for the next record, which may never come, so it is
+		// cancelled rather than waiting for it to notice the flag.
+		if let Some(thread) = self.handle.take() {
+			thread.abort();
+			match thread.await {
+				Ok(result) => result?,
+				Err(e) if e.is_cancelled() => {},
+				Err(_) => panic!("Failed to join {} thread", self.name()),
+			}
+		}
+		info!("{} stopped.", self.name());
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use prism_event::{event::EventValue, time::Timestamp};
+
+	fn boot() -> DateTime<Local> {
+		DateTime::from_timestamp(1_700_000_000, 0).unwrap().with_timezone(&Local)
+	}
+
+	fn kernel_event(line: &str) -> Event {
+		let record = kmsg::parse(line).unwrap();
+		let kind = kmsg::classify(record.message()).unwrap();
+		to_event(&record, kind, boot())
+	}
+
+	fn field<'a>(event: &'a Event, key: &str) -> Option<&'a EventValue> {
+		event.fields().and_then(|fields| fields.get(key))
+	}
+
+	#[test]
+	fn test_oom_kill() {
+		let event = kernel_event(
+			"3,1234,5678901,-;Out of memory: Killed process 42 (java) total-vm:2048kB, anon-rss:1024kB, file-rss:16kB, shmem-rss:0kB, UID:0\n",
+		);
+		assert_eq!(event.name(), "oom_kill");
+		assert_eq!(event.namespace(), Some("kmsg"));
+		assert_eq!(event.severity(), Severity::Error);
+		assert!(event.message().starts_with("Out of memory: Killed process 42 (java)"));
+		assert_eq!(field(&event, "sequence"), Some(&EventValue::from(1234u64)));
+		assert_eq!(field(&event, "pid"), Some(&EventValue::from(42u32)));
+		assert_eq!(field(&event, "comm"), Some(&EventValue::from("java")));
+		assert_eq!(field(&event, "anon_rss_kb"), Some(&EventValue::from(1024u64)));
+		// kernel timestamps are microseconds since boot
+		let boot = Timestamp::from(boot()).as_nanos();
+		assert_eq!(event.timestamp().as_nanos(), boot + 5_678_901_000);
+	}
+
+	#[test]
+	fn test_optional_fields() {
+		let event =
+			kernel_event("3,7,10,-;Buffer I/O error on dev sda1, logical block 0, async page read");
+		assert_eq!(event.name(), "io_error");
+		assert_eq!(field(&event, "device"), Some(&EventValue::from("sda1")));
+		assert_eq!(field(&event, "sector"), None);
+
+		let event =
+			kernel_event("6,8,20,-;e1000e: eth0 NIC Link is Up 1000 Mbps Full Duplex, Flow Control: None");
+		assert_eq!((event.name(), event.severity()), ("link_up", Severity::Info));
+		assert_eq!(field(&event, "speed_mbps"), Some(&EventValue::from(1000u64)));
+
+		let event = kernel_event("6,9,30,-;igb 0000:02:00.0 eno1: NIC Link is Down");
+		assert_eq!(event.name(), "link_down");
+		assert_eq!(field(&event, "speed_mbps"), None);
+	}
+}
@@ -1,22 +1,35 @@
+use application::ApplicationCollector;
 pub use error::MetricError;
-use host::HostCollector;
+use host::{HostCollector, identity_tags};
+pub use kmsg::KmsgCollector;
 use log::{info, warn};
 use prism_core::Module;
-use prism_event::metric::Metric;
+use prism_event::metric::{
+	Metric, MetricBatch, add_global_tags,
+	registry::{self, Registry},
+};
 use prism_metric_common::init_roots;
 use prism_runtime::handle;
 use std::{
-	mem,
 	sync::{
 		Arc,
 		atomic::{AtomicBool, Ordering},
 	},
 	time::Duration,
 };
-use tokio::{sync::mpsc::Sender, task::JoinHandle, time};
+use tokio::{
+	sync::mpsc::{self, Receiver, Sender},
+	task::JoinHandle,
+	time,
+};
 use tokio_stream::{StreamExt, wrappers::IntervalStream};
+mod application;
 mod error;
 mod host;
+mod kmsg;
+
+/// Batches handed back by the transport, one being filled while another is encoded
+const RECYCLED_BUFFERS: usize = 2;
 
 #[async_trait::async_trait]
 pub(crate) trait Collector: Send + Sync {
@@ -28,23 +41,56 @@ pub struct MetricCollector {
 	running: Arc<AtomicBool>,
 	collectors: Option<Vec<Box<dyn Collector>>>,
 	handle: Option<JoinHandle<Result<(), MetricError>>>,
-	output: Sender<Vec<Metric>>,
+	output: Sender<MetricBatch>,
+	recycler: Sender<MetricBatch>,
+	recycled: Option<Receiver<MetricBatch>>,
+	/// Attach the host identity to every metric
+	identity_tags: bool,
 }
 
 impl MetricCollector {
-	pub fn new(output: Sender<Vec<Metric>>) -> Result<Self, MetricError> {
+	pub fn new(output: Sender<MetricBatch>) -> Result<Self, MetricError> {
 		init_roots();
 		let mut collectors: Vec<Box<dyn Collector>> = Vec::new();
 		let host_collector = HostCollector::new()?;
 		collectors.push(Box::new(host_collector));
+		collectors.push(Box::new(ApplicationCollector::new(registry::global())));
+		let (recycler, recycled) = mpsc::channel(RECYCLED_BUFFERS);
 
 		Ok(MetricCollector {
 			collectors: Some(collectors),
 			running: Default::default(),
 			handle: None,
 			output,
+			recycler,
+			recycled: Some(recycled),
+			identity_tags: false,
 		})
 	}
+
+	/// Tags every metric with the host identity (hostname, machine id, boot id,
+	/// kernel release and os), read once when the collector starts.
+	pub const fn with_identity_tags(mut self) -> Self {
+		self.identity_tags = true;
+		self
+	}
+
+	/// Ships the metrics of a scoped `registry` too, the
+	/// [global](registry::global) one is always collected.
+	#[must_use]
+	pub fn with_registry(mut self, registry: Arc<Registry>) -> Self {
+		if let Some(collectors) = &mut self.collectors {
+			collectors.push(Box::new(ApplicationCollector::new(registry)));
+		}
+		self
+	}
+
+	/// Channel through which the transport hands sent batches back, see
+	/// `prism_sender::Sender::with_recycler`. Without it every collection
+	/// allocates a new batch.
+	pub fn recycler(&self) -> Sender<MetricBatch> {
+		self.recycler.clone()
+	}
 }
 
 impl Module for MetricCollector {
@@ -64,23 +110,33 @@ impl Module for MetricCollector {
 		let running = Arc::clone(&self.running);
 		let output = self.output.clone();
 		let collectors = self.collectors.take().ok_or(Self::Error::Init)?;
+		let mut recycled = self.recycled.take().ok_or(Self::Error::Init)?;
+		let with_identity = self.identity_tags;
 		self.handle = Some(handle().spawn(async move {
+			if with_identity {
+				// Added once to the global tags, which encoders merge into every metric
+				match identity_tags().await {
+					Ok(tags) => add_global_tags(&tags),
+					Err(e) => warn!("Failed to read host identity, metrics are not tagged: {e:?}"),
+				}
+			}
 			let mut interval = IntervalStream::new(time::interval(Duration::from_secs(1)));
 			let mut buffer = Vec::new();
 			while running.load(Ordering::Relaxed) && interval.next().await.is_some() {
+				let mut batch = match recycled.try_recv() {
+					Ok(mut batch) => {
+						batch.clear();
+						batch
+					},
+					Err(_) => MetricBatch::new(),
+				};
 				for collector in &collectors {
 					if let Err(e) = collector.collect(&mut buffer).await {
 						warn!("Collector {} failed: {:?}", collector.name(), e);
 					}
 				}
-				let metrics = mem::take(&mut buffer);
-				output.send(metrics).await.map_err(|_| MetricError::Send)?;
-				// for metrics in buffer.drain(..) {
-				// 	if output.send(metrics).await.is_err() {
-				// 		warn!("Metric channel closed");
-				// 		break;
-				// 	}
-				// }
+				batch.extend(buffer.drain(..));
+				output.send(batch).await.map_err(|_| MetricError::Send)?;
 			}
 			Ok(())
 		}));
@@ -2,4 +2,5 @@
 pub use error::NetworkMetricError;
 
 mod error;
+pub mod link;
 pub mod netdev;
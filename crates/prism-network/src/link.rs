@@ -0,0 +1,303 @@
+//! Network interface link attributes
+//!
+//! Every `/sys/class/net/<interface>` directory exposes the link layer state
+//! of one interface: `operstate`, `carrier`, `speed`, `duplex`, `mtu`,
+//! `address` and the ARPHRD `type`. `carrier`, `speed` and `duplex` fail with
+//! `EINVAL` while the link is down or on virtual interfaces, so they are
+//! optional.
+//!
+//! The topology is read from the same directory: a `master` link points to the
+//! enslaving bond or bridge, `bonding/slaves` lists the members of a bond and
+//! `brif/` the ports of a bridge.
+
+use crate::NetworkMetricError;
+use prism_metric_common::sysfs_root;
+use prism_metric_utils::{path_exists_lossy, read_dir, read_link, read_to_string};
+use std::{io, path::Path};
+use uom::si::{f64::InformationRate, information_rate::megabit_per_second};
+
+/// `ARPHRD_LOOPBACK` from `include/uapi/linux/if_arp.h`
+const ARPHRD_LOOPBACK: u16 = 772;
+
+/// Negotiated duplex mode of a link
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Duplex {
+	Full,
+	Half,
+	Unknown,
+}
+
+impl Duplex {
+	fn parse(content: &str) -> Self {
+		match content.trim() {
+			"full" => Self::Full,
+			"half" => Self::Half,
+			_ => Self::Unknown,
+		}
+	}
+
+	pub const fn as_str(&self) -> &'static str {
+		match self {
+			Self::Full => "full",
+			Self::Half => "half",
+			Self::Unknown => "unknown",
+		}
+	}
+}
+
+/// Role of an interface in the link topology
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LinkRole {
+	Loopback,
+	/// Backed by a device, e.g. a NIC or a wireless adapter
+	Physical,
+	Bond,
+	Bridge,
+	Vlan,
+	/// Virtual interface whose `iflink` points to another interface and that
+	/// is not a VLAN, which in practice is one end of a veth pair
+	Veth,
+	/// Any other virtual interface, e.g. a tunnel or a dummy
+	Virtual,
+}
+
+impl LinkRole {
+	pub const fn as_str(&self) -> &'static str {
+		match self {
+			Self::Loopback => "loopback",
+			Self::Physical => "physical",
+			Self::Bond => "bond",
+			Self::Bridge => "bridge",
+			Self::Vlan => "vlan",
+			Self::Veth => "veth",
+			Self::Virtual => "virtual",
+		}
+	}
+}
+
+/// Link attributes of an interface from `/sys/class/net/<interface>`
+pub struct Link {
+	/// Interface name, e.g. `eth0`
+	name: String,
+	/// RFC 2863 operational state, e.g. `up`, `down` or `unknown`
+	operstate: String,
+	/// Whether the physical link is up, `None` while the interface is down
+	carrier: Option<bool>,
+	/// (since Linux 3.15)
+	/// Number of carrier up and down transitions
+	carrier_changes: Option<u64>,
+	/// Negotiated speed, `None` when down or not reported by the driver
+	speed: Option<InformationRate>,
+	duplex: Duplex,
+	mtu: u64,
+	/// Hardware address, e.g. `52:54:00:12:34:56`
+	address: String,
+	/// ARPHRD hardware type, e.g. `1` for Ethernet
+	kind: u16,
+	role: LinkRole,
+	/// Bond or bridge enslaving this interface
+	master: Option<String>,
+	/// Members of a bond or ports of a bridge, sorted by name
+	members: Vec<String>,
+}
+
+impl Link {
+	pub fn name(&self) -> &str {
+		&self.name
+	}
+
+	pub fn operstate(&self) -> &str {
+		&self.operstate
+	}
+
+	pub fn is_up(&self) -> bool {
+		self.operstate == "up"
+	}
+
+	pub const fn carrier(&self) -> Option<bool> {
+		self.carrier
+	}
+
+	pub const fn carrier_changes(&self) -> Option<u64> {
+		self.carrier_changes
+	}
+
+	pub const fn speed(&self) -> Option<InformationRate> {
+		self.speed
+	}
+
+	pub const fn duplex(&self) -> Duplex {
+		self.duplex
+	}
+
+	pub const fn mtu(&self) -> u64 {
+		self.mtu
+	}
+
+	pub fn address(&self) -> &str {
+		&self.address
+	}
+
+	pub const fn kind(&self) -> u16 {
+		self.kind
+	}
+
+	pub const fn role(&self) -> LinkRole {
+		self.role
+	}
+
+	pub fn master(&self) -> Option<&str> {
+		self.master.as_deref()
+	}
+
+	pub fn members(&self) -> &[String] {
+		&self.members
+	}
+
+	async fn read(dir: &Path, name: &str) -> Result<Self, NetworkMetricError> {
+		let operstate = read_attribute(dir, "operstate").await?;
+		let mtu = read_attribute(dir, "mtu").await?.parse()?;
+		let address = read_attribute(dir, "address").await?;
+		let kind = read_attribute(dir, "type").await?.parse()?;
+
+		let carrier = match read_attribute(dir, "carrier").await {
+			Ok(carrier) => Some(carrier == "1"),
+			Err(_) => None,
+		};
+		let carrier_changes = match read_attribute(dir, "carrier_changes").await {
+			Ok(changes) => changes.parse().ok(),
+			Err(_) => None,
+		};
+		let speed = match read_attribute(dir, "speed").await {
+			Ok(speed) => parse_speed(&speed),
+			Err(_) => None,
+		};
+		let duplex = match read_attribute(dir, "duplex").await {
+			Ok(duplex) => Duplex::parse(&duplex),
+			Err(_) => Duplex::Unknown,
+		};
+
+		let role = read_role(dir, kind).await?;
+		let master = read_link(dir.join("master"))
+			.await
+			.ok()
+			.and_then(|target| target.file_name().and_then(|name| name.to_str()).map(String::from));
+		let mut members = match role {
+			LinkRole::Bond => read_attribute(dir, "bonding/slaves")
+				.await
+				.map(|slaves| slaves.split_whitespace().map(String::from).collect())
+				.unwrap_or_default(),
+			LinkRole::Bridge => read_dir(dir.join("brif"))
+				.await
+				.map(|ports| {
+					ports
+						.iter()
+						.filter_map(|port| port.file_name().and_then(|name| name.to_str()))
+						.map(String::from)
+						.collect()
+				})
+				.unwrap_or_default(),
+			_ => Vec::new(),
+		};
+		members.sort();
+
+		Ok(Self {
+			name: name.to_string(),
+			operstate,
+			carrier,
+			carrier_changes,
+			speed,
+			duplex,
+			mtu,
+			address,
+			kind,
+			role,
+			master,
+			members,
+		})
+	}
+}
+
+async fn read_attribute(dir: &Path, name: &str) -> io::Result<String> {
+	Ok(read_to_string(dir.join(name)).await?.trim().to_string())
+}
+
+/// Parses `speed` in Mb/s. Drivers report `-1` (`SPEED_UNKNOWN`), or its
+/// unsigned form on older kernels, when the speed is not known.
+fn parse_speed(content: &str) -> Option<InformationRate> {
+	let speed: i64 = content.trim().parse().ok()?;
+	(speed > 0 && speed != i64::from(u32::MAX))
+		.then(|| InformationRate::new::<megabit_per_second>(speed as f64))
+}
+
+/// Returns the `DEVTYPE` of a `uevent` file, e.g. `vlan` or `wlan`.
+fn parse_devtype(uevent: &str) -> Option<&str> {
+	uevent.lines().find_map(|line| line.strip_prefix("DEVTYPE="))
+}
+
+async fn read_role(dir: &Path, kind: u16) -> Result<LinkRole, NetworkMetricError> {
+	if kind == ARPHRD_LOOPBACK {
+		return Ok(LinkRole::Loopback);
+	}
+	if path_exists_lossy(dir.join("bonding")).await {
+		return Ok(LinkRole::Bond);
+	}
+	if path_exists_lossy(dir.join("brif")).await {
+		return Ok(LinkRole::Bridge);
+	}
+	let uevent = read_to_string(dir.join("uevent")).await.unwrap_or_default();
+	if parse_devtype(&uevent) == Some("vlan") {
+		return Ok(LinkRole::Vlan);
+	}
+	if path_exists_lossy(dir.join("device")).await {
+		return Ok(LinkRole::Physical);
+	}
+	let ifindex: u64 = read_attribute(dir, "ifindex").await?.parse()?;
+	let iflink: u64 = read_attribute(dir, "iflink").await?.parse()?;
+	if iflink != 0 && iflink != ifindex {
+		return Ok(LinkRole::Veth);
+	}
+	Ok(LinkRole::Virtual)
+}
+
+/// Reads the link attributes of an interface from `/sys/class/net/<interface>/`.
+///
+/// Interfaces without a `/sys/class/net` entry, e.g. when sysfs is mounted
+/// from another network namespace, yield `None`.
+pub async fn link(name: &str) -> Result<Option<Link>, NetworkMetricError> {
+	let dir = sysfs_root().join("class/net").join(name);
+	match Link::read(&dir, name).await {
+		Ok(link) => Ok(Some(link)),
+		Err(NetworkMetricError::IOError(e)) if e.kind() == io::ErrorKind::NotFound => Ok(None),
+		Err(e) => Err(e),
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_parse_speed() {
+		assert_eq!(
+			parse_speed("10000\n").map(|speed| speed.get::<megabit_per_second>()),
+			Some(10000.0)
+		);
+		assert_eq!(parse_speed("-1"), None);
+		assert_eq!(parse_speed("4294967295"), None);
+		assert_eq!(parse_speed("0"), None);
+	}
+
+	#[test]
+	fn test_parse_duplex() {
+		assert_eq!(Duplex::parse("full\n"), Duplex::Full);
+		assert_eq!(Duplex::parse("half"), Duplex::Half);
+		assert_eq!(Duplex::parse("unknown"), Duplex::Unknown);
+	}
+
+	#[test]
+	fn test_parse_devtype() {
+		assert_eq!(parse_devtype("DEVTYPE=vlan\nINTERFACE=eth0.100\nIFINDEX=4\n"), Some("vlan"));
+		assert_eq!(parse_devtype("INTERFACE=lo\nIFINDEX=1\n"), None);
+	}
+}
@@ -4,41 +4,59 @@ use prism_metric_common::procfs_root;
 use prism_metric_utils::read_to_string;
 use uom::si::{f64::Information, information::byte};
 
+/// Interface statistics from /proc/net/dev
 #[derive(ProcParser)]
 #[fmt = "table"]
 pub struct Interface {
+	/// Interface name
 	#[arg(index = 0)]
 	pub name: String,
+	/// Bytes received
 	#[arg(index = 1, unit = byte)]
 	pub rx_bytes: Information,
+	/// Packets received
 	#[arg(index = 2)]
 	pub rx_packets: u64,
+	/// Receive errors detected by the driver
 	#[arg(index = 3)]
 	pub rx_errors: u64,
+	/// Received packets dropped, e.g. for lack of buffers
 	#[arg(index = 4)]
 	pub rx_dropped: u64,
+	/// Receive FIFO buffer overruns
 	#[arg(index = 5)]
 	pub rx_fifo: u64,
+	/// Received packets with framing errors
 	#[arg(index = 6)]
 	pub rx_frame: u64,
+	/// Compressed packets received
 	#[arg(index = 7)]
 	pub rx_compressed: u64,
+	/// Multicast packets received
 	#[arg(index = 8)]
 	pub rx_multicast: u64,
+	/// Bytes transmitted
 	#[arg(index = 9, unit = byte)]
 	pub tx_bytes: Information,
+	/// Packets transmitted
 	#[arg(index = 10)]
 	pub tx_packets: u64,
+	/// Transmit errors detected by the driver
 	#[arg(index = 11)]
 	pub tx_errors: u64,
+	/// Packets dropped while transmitting, e.g. for lack of buffers
 	#[arg(index = 12)]
 	pub tx_dropped: u64,
+	/// Transmit FIFO buffer underruns
 	#[arg(index = 13)]
 	pub tx_fifo: u64,
+	/// Collisions detected on the interface
 	#[arg(index = 14)]
 	pub tx_colls: u64,
+	/// Carrier losses detected while transmitting
 	#[arg(index = 15)]
 	pub tx_carrier: u64,
+	/// Compressed packets transmitted
 	#[arg(index = 16)]
 	pub tx_compressed: u64,
 }
@@ -1,8 +1,9 @@
 use super::SendError;
 use crate::Sendable;
-use bytes::BytesMut;
-use chrono::{DateTime, Duration, FixedOffset, TimeZone, Utc};
+use bytes::{Bytes, BytesMut};
+use chrono::Utc;
 use prism_core::sender::Sender;
+use prism_event::time::{self, Timezone};
 use serde::Serialize;
 use std::{
 	fs::{OpenOptions, create_dir_all},
@@ -16,8 +17,6 @@ use tokio::{
 };
 mod utils;
 
-const CST_OFFSET: i32 = 8 * 3600;
-
 pub struct FileSender {
 	writer: BufWriter<File>,
 	base_path: PathBuf,
@@ -26,14 +25,20 @@ pub struct FileSender {
 	buffer: BytesMut,
 	next_rotate_time: SystemTime,
 	current_date: String,
+	/// Timezone of the daily rotation, the one timestamps are written in
+	timezone: Timezone,
+	/// Written at the start of every file, e.g. the CSV header row
+	header: Bytes,
+	/// Whether nothing was written to the current file yet
+	new_file: bool,
 }
 
 impl FileSender {
 	pub fn new(path: impl AsRef<str>) -> Result<Self, SendError> {
 		let base_path = PathBuf::from(path.as_ref());
 
-		let now_cst = Self::current_cst_time();
-		let current_date = now_cst.format("%Y%m%d").to_string();
+		let timezone = time::settings().timezone();
+		let current_date = timezone.now().format("%Y%m%d").to_string();
 
 		// crate data folder
 		let date_folder = base_path
@@ -57,7 +62,7 @@ impl FileSender {
 		let writer = BufWriter::with_capacity(4 << 20, file);
 
 		// calc next rotate time
-		let next_rotate_time = Self::next_day_start_cst();
+		let next_rotate_time = Self::next_day_start(timezone);
 
 		Ok(Self {
 			writer,
@@ -67,30 +72,14 @@ impl FileSender {
 			buffer: BytesMut::with_capacity(1 << 19),
 			next_rotate_time,
 			current_date,
+			timezone,
+			header: Bytes::new(),
+			new_file: true,
 		})
 	}
 
-	fn current_cst_time() -> DateTime<FixedOffset> {
-		let utc_now = Utc::now();
-		let cst_offset = FixedOffset::east_opt(CST_OFFSET).expect("Invalid offset");
-		utc_now.with_timezone(&cst_offset)
-	}
-
-	fn next_day_start_cst() -> SystemTime {
-		let now_cst = Self::current_cst_time();
-		let next_rotate_cst = (now_cst.date_naive() + Duration::days(1))
-			.and_hms_opt(0, 0, 0)
-			.expect("Invalid time");
-
-		// convert to DateTime with FixedOffset
-		let cst_offset = FixedOffset::east_opt(CST_OFFSET).expect("Invalid offset");
-		let next_rotate = cst_offset
-			.from_local_datetime(&next_rotate_cst)
-			.earliest()
-			.expect("Invalid time");
-
-		// convert to SystemTime
-		SystemTime::from(next_rotate)
+	fn next_day_start(timezone: Timezone) -> SystemTime {
+		SystemTime::from(timezone.next_midnight(Utc::now()))
 	}
 
 	fn should_rotate_by_time(&self) -> bool {
@@ -111,7 +100,7 @@ impl FileSender {
 			self.writer.flush().await.map_err(SendError::IO)?;
 		}
 
-		let new_date = Self::current_cst_time().format("%Y%m%d").to_string();
+		let new_date = self.timezone.now().format("%Y%m%d").to_string();
 
 		let date_folder = self
 			.base_path
@@ -138,7 +127,8 @@ impl FileSender {
 		self.current_path = new_path;
 		self.written_size = 0;
 		self.current_date = new_date;
-		self.next_rotate_time = Self::next_day_start_cst();
+		self.next_rotate_time = Self::next_day_start(self.timezone);
+		self.new_file = true;
 
 		Ok(())
 	}
@@ -153,7 +143,7 @@ impl FileSender {
 			self.writer.flush().await.map_err(SendError::IO)?;
 		}
 
-		let rotated = utils::format_filename(&self.current_path, "%Y%m%d_%H%M%S");
+		let rotated = utils::format_filename(&self.current_path, "%Y%m%d_%H%M%S", self.timezone);
 		rename(&self.current_path, &rotated).await.map_err(SendError::IO)?;
 
 		let file = OpenOptions::new()
@@ -167,6 +157,7 @@ impl FileSender {
 			mem::replace(&mut self.writer, BufWriter::with_capacity(4 << 20, File::from_std(file)));
 		let _ = old.into_inner().shutdown().await;
 		self.written_size = 0;
+		self.new_file = true;
 
 		Ok(())
 	}
@@ -180,6 +171,9 @@ impl<S: Sendable + Serialize> Sender<S> for FileSender {
 			self.switch_to_new_date().await?;
 		}
 
+		if mem::take(&mut self.new_file) {
+			self.buffer.extend_from_slice(&self.header);
+		}
 		self.buffer.extend_from_slice(&item);
 		if self.buffer.len() > (1 << 14) {
 			<Self as Sender<S>>::flush(self).await?;
@@ -200,4 +194,40 @@ impl<S: Sendable + Serialize> Sender<S> for FileSender {
 		}
 		Ok(())
 	}
+
+	fn set_header(&mut self, header: Bytes) {
+		self.header = header;
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use prism_event::metric::MetricBatch;
+
+	async fn send(sender: &mut FileSender, item: &str) {
+		<FileSender as Sender<MetricBatch>>::send(sender, BytesMut::from(item)).await.unwrap();
+		<FileSender as Sender<MetricBatch>>::flush(sender).await.unwrap();
+	}
+
+	#[tokio::test]
+	async fn test_header_after_rotation() {
+		let dir = std::env::temp_dir().join(format!("prism-sender-{}", std::process::id()));
+		let mut sender = FileSender::new(dir.join("metrics.csv").to_string_lossy()).unwrap();
+		<FileSender as Sender<MetricBatch>>::set_header(&mut sender, Bytes::from("name,value\n"));
+		send(&mut sender, "a,1\n").await;
+		send(&mut sender, "b,2\n").await;
+		sender.rotate_file().await.unwrap();
+		send(&mut sender, "c,3\n").await;
+		sender.writer.shutdown().await.unwrap();
+
+		let folder = sender.current_path.parent().unwrap().to_path_buf();
+		let mut files: Vec<_> = std::fs::read_dir(&folder)
+			.unwrap()
+			.map(|entry| std::fs::read_to_string(entry.unwrap().path()).unwrap())
+			.collect();
+		files.sort();
+		std::fs::remove_dir_all(&dir).unwrap();
+		assert_eq!(files, ["name,value\na,1\nb,2\n", "name,value\nc,3\n"]);
+	}
 }
@@ -8,7 +8,10 @@ use std::sync::{
 	Arc,
 	atomic::{AtomicBool, Ordering},
 };
-use tokio::{sync::mpsc::Receiver, task::JoinHandle};
+use tokio::{
+	sync::mpsc::{self, Receiver},
+	task::JoinHandle,
+};
 mod error;
 pub mod file;
 
@@ -22,6 +25,8 @@ where
 	running: Arc<AtomicBool>,
 	handle: Option<JoinHandle<Result<(), SendError>>>,
 	receiver: Option<Receiver<T>>,
+	/// Hands encoded messages back to the producer, to be reused
+	recycler: Option<mpsc::Sender<T>>,
 	sender: Option<S>,
 	encoder: Option<E>,
 }
@@ -38,11 +43,21 @@ where
 			running: Arc::new(AtomicBool::new(false)),
 			// config: (),
 			receiver: Some(receiver),
+			recycler: None,
 			sender: Some(sender),
 			encoder: Some(encoder),
 			handle: None,
 		}
 	}
+
+	/// Sends every message back through `recycler` once encoded, so that the
+	/// producer can reuse its allocation rather than building a new one.
+	/// Messages are dropped instead when the recycler is full or closed.
+	#[must_use]
+	pub fn with_recycler(mut self, recycler: mpsc::Sender<T>) -> Self {
+		self.recycler = Some(recycler);
+		self
+	}
 }
 
 impl<T, S, E> Module for Sender<T, S, E>
@@ -65,17 +80,30 @@ where
 			warn!("{} sender is already running.", self.name);
 			return Ok(());
 		}
+		let name = self.name;
 		let running = Arc::clone(&self.running);
 		let mut receiver = self.receiver.take().unwrap();
 		let mut sender = self.sender.take().unwrap();
 		let mut encoder = self.encoder.take().unwrap();
+		let recycler = self.recycler.take();
+		let mut header = BytesMut::new();
+		encoder.header(&mut header)?;
+		sender.set_header(header.freeze());
 		self.handle = Some(handle().spawn(async move {
 			while running.load(Ordering::Relaxed) {
 				match receiver.recv().await {
 					Some(message) => {
 						debug!("Sending message");
 						let mut encoded = BytesMut::new();
-						encoder.encode(message, &mut encoded)?;
+						let result = encoder.encode(&message, &mut encoded).map_err(SendError::from);
+						if let Some(recycler) = &recycler {
+							let _ = recycler.try_send(message);
+						}
+						// A message that cannot be encoded is dropped, the next ones may be
+						if let Err(error) = result {
+							warn!("{name} failed to encode a message: {error}");
+							continue;
+						}
 						debug!("Encoded message: {encoded:?}");
 						sender.send(encoded).await?;
 					},
@@ -1,10 +1,16 @@
 use super::AgentError;
 use codec::encode::csv::CsvEncoderBuilder;
 use log::{info, warn};
+use prism_config::{Config, ConfigError, TimeConfig};
 use prism_core::Module;
-use prism_metric::MetricCollector;
+use prism_event::{
+	metric::{MetricTags, add_global_tags, add_namespace_tags},
+	time::{self, TimeSettings},
+};
+use prism_metric::{KmsgCollector, MetricCollector};
 use prism_runtime::handle;
 use prism_sender::{Sender, file::FileSender};
+use std::{collections::BTreeMap, io};
 use tokio::{
 	sync::{mpsc, watch},
 	task::JoinHandle,
@@ -20,19 +26,27 @@ pub(crate) enum State {
 }
 
 pub struct Agent {
+	config: Option<Config>,
 	state_tx: watch::Sender<State>,
 	handle: Option<JoinHandle<Result<(), AgentError>>>,
 }
 
 impl Agent {
-	pub fn new(_config_path: String) -> Result<Self, AgentError> {
+	pub fn new(config_path: String) -> Result<Self, AgentError> {
 		let _ = env_logger::builder().is_test(false).try_init();
 		// #[cfg(feature = "ebpf")]
 		// ebpf::prepare_ebpf();
-		// init(config_path)?;
+		let config = match Config::from_path(&config_path) {
+			Ok(config) => config,
+			Err(ConfigError::Io(e)) if e.kind() == io::ErrorKind::NotFound => {
+				warn!("Config {config_path} not found, using defaults");
+				Config::default()
+			},
+			Err(e) => return Err(e.into()),
+		};
 
 		let (state_tx, _rx) = watch::channel(State::Stopped);
-		Ok(Self { state_tx, handle: None })
+		Ok(Self { config: Some(config), state_tx, handle: None })
 	}
 
 	pub(crate) fn request_terminate(&self) {
@@ -62,8 +76,10 @@ impl Module for Agent {
 
 		let mut state_rx = self.state_tx.subscribe();
 		let state_tx = self.state_tx.clone();
+		let config = self.config.take().unwrap_or_default();
 
-		self.handle = Some(handle().spawn(async move { run(state_tx, &mut state_rx).await }));
+		self.handle =
+			Some(handle().spawn(async move { run(config, state_tx, &mut state_rx).await }));
 		info!("Starting agent");
 		Ok(())
 	}
@@ -95,22 +111,62 @@ impl Module for Agent {
 	}
 }
 
+fn time_settings(config: &TimeConfig) -> Result<TimeSettings, AgentError> {
+	Ok(TimeSettings::new(
+		config.format.parse()?,
+		config.precision.parse()?,
+		config.timezone.parse()?,
+	))
+}
+
+fn metric_tags(tags: &BTreeMap<String, String>) -> MetricTags {
+	let mut metric_tags = MetricTags::new();
+	for (k, v) in tags {
+		metric_tags.insert(k.clone(), v.clone());
+	}
+	metric_tags
+}
+
 async fn run(
+	config: Config,
 	state_tx: watch::Sender<State>,
 	state_rx: &mut watch::Receiver<State>,
 ) -> Result<(), AgentError> {
+	// Read by the encoders and by file rotation, set before any sender is created
+	time::set_settings(time_settings(&config.time)?);
+	// Stored once and merged by the encoders, rather than cloned into every metric
+	add_global_tags(&metric_tags(&config.tags));
+	for (namespace, collector) in &config.metric.collectors {
+		add_namespace_tags(namespace, &metric_tags(&collector.tags));
+	}
+
 	let (metric_sender, metric_receiver) = mpsc::channel(1024);
+	let mut metric_collector = MetricCollector::new(metric_sender)?;
+	if config.metric.identity_tags {
+		metric_collector = metric_collector.with_identity_tags();
+	}
 	let mut metric_transport = Sender::new(
 		"Metric transport",
 		metric_receiver,
 		FileSender::new("output/metrics.csv")?,
 		CsvEncoderBuilder::new().build(),
-	);
+	)
+	.with_recycler(metric_collector.recycler());
 	metric_transport.start()?;
-
-	let mut metric_collector = MetricCollector::new(metric_sender)?;
 	metric_collector.start()?;
 
+	let (event_sender, event_receiver) = mpsc::channel(1024);
+	let mut event_transport = Sender::new(
+		"Event transport",
+		event_receiver,
+		FileSender::new("output/events.csv")?,
+		CsvEncoderBuilder::new().build(),
+	);
+	event_transport.start()?;
+
+	let mut kmsg_collector = KmsgCollector::new(event_sender);
+	kmsg_collector.start()?;
+
 	let _ = state_tx.send(State::Running);
 
 	loop {
@@ -122,7 +178,12 @@ async fn run(
 		}
 	}
 
+	kmsg_collector.stop().await?;
+	// Events are sparse, dropping the last sender closes the channel so that
+	// the transport does not wait for one more event to stop.
+	drop(kmsg_collector);
 	metric_collector.stop().await?;
+	event_transport.stop().await?;
 	metric_transport.stop().await?;
 
 	Ok(())
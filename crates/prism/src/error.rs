@@ -1,6 +1,10 @@
 use thiserror::Error;
 #[derive(Error, Debug)]
 pub enum AgentError {
+	#[error("Config error: {0}")]
+	ConfigError(#[from] prism_config::ConfigError),
+	#[error("Time error: {0}")]
+	TimeError(#[from] prism_event::time::TimeSettingError),
 	#[error("Metric error: {0}")]
 	MetricError(#[from] prism_metric::MetricError),
 	#[error("Send error: {0}")]
@@ -0,0 +1,168 @@
+use fake::{Fake, Faker};
+use std::{fs, io, path::Path};
+
+const SCHEDULERS: &[&str] = &["none", "mq-deadline", "kyber", "bfq"];
+const MODELS: &[&str] = &["Samsung SSD 870 EVO 1TB", "WDC WD40EFRX-68N32N0", "QEMU HARDDISK"];
+
+#[derive(Debug, Clone)]
+pub struct FakeBlockDevice {
+	pub name: String,
+	/// Disk holding this partition
+	pub disk: Option<String>,
+	pub slaves: Vec<String>,
+	pub holders: Vec<String>,
+	/// Size in 512-byte sectors
+	pub size: u64,
+	pub rotational: bool,
+	pub scheduler: &'static str,
+	pub nr_requests: u64,
+	/// `None` for virtual devices, which have no `device/`
+	pub model: Option<&'static str>,
+	pub removable: bool,
+	pub disk_type: &'static str,
+	/// Device-mapper name and uuid
+	pub dm: Option<(String, String)>,
+}
+
+impl FakeBlockDevice {
+	fn disk(name: &str, disk_type: &'static str) -> Self {
+		let rotational = disk_type == "hdd";
+		Self {
+			name: name.to_string(),
+			disk: None,
+			slaves: Vec::new(),
+			holders: Vec::new(),
+			size: (0..=8_000_000_000).fake(),
+			rotational,
+			scheduler: SCHEDULERS[(0..SCHEDULERS.len()).fake::<usize>()],
+			nr_requests: *[64, 128, 256, 1023].get((0..4).fake::<usize>()).unwrap_or(&64),
+			model: (disk_type != "virtual").then(|| MODELS[(0..MODELS.len()).fake::<usize>()]),
+			removable: disk_type != "virtual" && Faker.fake(),
+			disk_type,
+			dm: None,
+		}
+	}
+
+	fn partition(&self, index: u32) -> Self {
+		let separator = if self.name.ends_with(|c: char| c.is_ascii_digit()) { "p" } else { "" };
+		Self {
+			name: format!("{}{separator}{index}", self.name),
+			disk: Some(self.name.clone()),
+			slaves: Vec::new(),
+			holders: Vec::new(),
+			size: (0..=self.size).fake(),
+			dm: None,
+			..self.clone()
+		}
+	}
+
+	pub fn parents(&self) -> Vec<&str> {
+		match &self.disk {
+			Some(disk) => vec![disk.as_str()],
+			None => self.slaves.iter().map(String::as_str).collect(),
+		}
+	}
+
+	fn write_links(&self, dir: &Path) -> io::Result<()> {
+		for (kind, names) in [("slaves", &self.slaves), ("holders", &self.holders)] {
+			fs::create_dir_all(dir.join(kind))?;
+			for name in names {
+				fs::create_dir_all(dir.join(kind).join(name))?;
+			}
+		}
+		fs::write(dir.join("size"), format!("{}\n", self.size))
+	}
+}
+
+/// Disks of `/sys/block`, each followed by its partitions, with optional md
+/// and device-mapper devices stacked on some of the partitions
+#[derive(Debug, Clone)]
+pub struct FakeBlockDevices(pub Vec<FakeBlockDevice>);
+
+impl FakeBlockDevices {
+	pub fn generate() -> Self {
+		let mut disks = vec![
+			FakeBlockDevice::disk("sda", if Faker.fake() { "ssd" } else { "hdd" }),
+			FakeBlockDevice::disk("sdb", if Faker.fake() { "ssd" } else { "hdd" }),
+			FakeBlockDevice::disk("nvme0n1", "nvme"),
+		];
+		let mut devices = Vec::new();
+		for disk in &disks {
+			for index in 1..=(1..=3).fake::<u32>() {
+				devices.push(disk.partition(index));
+			}
+		}
+
+		if Faker.fake() {
+			let mut md = FakeBlockDevice::disk("md0", "virtual");
+			for member in ["sda1", "sdb1"] {
+				md.slaves.push(member.to_string());
+				if let Some(device) = devices.iter_mut().find(|device| device.name == member) {
+					device.holders.push(md.name.clone());
+				}
+			}
+			disks.push(md);
+		}
+		if Faker.fake() {
+			let mut dm = FakeBlockDevice::disk("dm-0", "virtual");
+			dm.slaves.push("nvme0n1p1".to_string());
+			let uuid: String = (0..64).map(|_| (b'a' + (0..26).fake::<u8>()) as char).collect();
+			dm.dm = Some(("vg0-root".to_string(), format!("LVM-{uuid}")));
+			if let Some(device) = devices.iter_mut().find(|device| device.name == "nvme0n1p1") {
+				device.holders.push(dm.name.clone());
+			}
+			disks.push(dm);
+		}
+		disks.push(FakeBlockDevice::disk("loop0", "virtual"));
+
+		devices.extend(disks);
+		devices.sort_by(|a, b| a.name.cmp(&b.name));
+		Self(devices)
+	}
+
+	/// Writes every disk under `block` of the given sysfs root, with its
+	/// partitions as subdirectories.
+	pub fn write(&self, sys_dir: &Path) -> io::Result<()> {
+		let block_dir = sys_dir.join("block");
+		fs::create_dir_all(&block_dir)?;
+		for device in self.0.iter().filter(|device| device.disk.is_none()) {
+			let dir = block_dir.join(&device.name);
+			fs::create_dir_all(dir.join("queue"))?;
+			device.write_links(&dir)?;
+			fs::write(dir.join("removable"), format!("{}\n", u8::from(device.removable)))?;
+			fs::write(dir.join("queue/rotational"), format!("{}\n", u8::from(device.rotational)))?;
+			let scheduler =
+				SCHEDULERS
+					.iter()
+					.map(|name| {
+						if *name == device.scheduler {
+							format!("[{name}]")
+						} else {
+							name.to_string()
+						}
+					})
+					.collect::<Vec<_>>()
+					.join(" ");
+			fs::write(dir.join("queue/scheduler"), format!("{scheduler}\n"))?;
+			fs::write(dir.join("queue/nr_requests"), format!("{}\n", device.nr_requests))?;
+			if let Some((name, uuid)) = &device.dm {
+				fs::create_dir_all(dir.join("dm"))?;
+				fs::write(dir.join("dm/name"), format!("{name}\n"))?;
+				fs::write(dir.join("dm/uuid"), format!("{uuid}\n"))?;
+			}
+			if let Some(model) = device.model {
+				fs::create_dir_all(dir.join("device"))?;
+				// SCSI pads the model with spaces
+				fs::write(dir.join("device/model"), format!("{model:<16}\n"))?;
+			}
+		}
+		for partition in self.0.iter().filter(|device| device.disk.is_some()) {
+			let disk = partition.disk.as_deref().unwrap_or_default();
+			let dir = block_dir.join(disk).join(&partition.name);
+			fs::create_dir_all(&dir)?;
+			partition.write_links(&dir)?;
+			fs::write(dir.join("partition"), "1\n")?;
+		}
+		Ok(())
+	}
+}
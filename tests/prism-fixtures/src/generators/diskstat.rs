@@ -1,4 +1,5 @@
 use fake::{Dummy, Fake, Faker};
+use std::fmt;
 
 pub struct FakeDiskStats(pub Vec<FakeDiskStat>);
 
@@ -55,9 +56,10 @@ impl FakeDiskStats {
 	}
 }
 
-impl ToString for FakeDiskStats {
-	fn to_string(&self) -> String {
-		self.0
+impl fmt::Display for FakeDiskStats {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		let result = self
+			.0
 			.clone()
 			.into_iter()
 			.map(|f| {
@@ -86,6 +88,7 @@ impl ToString for FakeDiskStats {
 				)
 			})
 			.collect::<Vec<_>>()
-			.join("\n")
+			.join("\n");
+		f.write_str(&result)
 	}
 }
@@ -0,0 +1,148 @@
+use fake::{Dummy, Fake, Faker};
+use std::fmt::{self, Write};
+
+const ZONE_NAMES: &[&str] = &["DMA", "DMA32", "Normal", "Movable"];
+const MIGRATE_TYPES: &[&str] = &["Unmovable", "Movable", "Reclaimable", "HighAtomic", "Isolate"];
+/// `MAX_ORDER` of x86_64 kernels
+const ORDERS: usize = 11;
+
+#[derive(Debug, Dummy, Clone)]
+pub struct FakeZoneStat {
+	#[dummy(faker = "0..=100_000_000")]
+	pub free: u64,
+	pub has_boost: bool,
+	#[dummy(faker = "0..=100_000")]
+	pub boost: u64,
+	#[dummy(faker = "0..=100_000")]
+	pub min: u64,
+	#[dummy(faker = "0..=100_000")]
+	pub low: u64,
+	#[dummy(faker = "0..=100_000")]
+	pub high: u64,
+	#[dummy(faker = "0..=100_000_000")]
+	pub spanned: u64,
+	#[dummy(faker = "0..=100_000_000")]
+	pub present: u64,
+	#[dummy(faker = "0..=100_000_000")]
+	pub managed: u64,
+}
+
+#[derive(Debug, Clone)]
+pub struct FakeZone {
+	pub node: u32,
+	pub name: &'static str,
+	pub stat: FakeZoneStat,
+	/// Free blocks per order
+	pub free: Vec<u64>,
+	/// Free blocks per order of every migrate type
+	pub free_by_type: Vec<(&'static str, Vec<u64>)>,
+}
+
+#[derive(Debug, Clone)]
+pub struct FakeZones(pub Vec<FakeZone>);
+
+fn fake_orders() -> Vec<u64> {
+	(0..ORDERS).map(|_| (0..=100_000u64).fake()).collect()
+}
+
+impl FakeZones {
+	pub fn generate() -> Self {
+		let nodes: u32 = (1..=2).fake();
+		let mut zones = Vec::new();
+		for node in 0..nodes {
+			for name in ZONE_NAMES.iter().filter(|_| Faker.fake::<bool>()) {
+				zones.push(FakeZone {
+					node,
+					name,
+					stat: Faker.fake(),
+					free: fake_orders(),
+					free_by_type: MIGRATE_TYPES.iter().map(|kind| (*kind, fake_orders())).collect(),
+				});
+			}
+		}
+		Self(zones)
+	}
+
+	pub fn buddyinfo(&self) -> String {
+		let mut content = String::new();
+		for zone in &self.0 {
+			let _ = write!(content, "Node {}, zone {:>8}", zone.node, zone.name);
+			for free in &zone.free {
+				let _ = write!(content, " {free:>6}");
+			}
+			content.push('\n');
+		}
+		content
+	}
+
+	pub fn pagetypeinfo(&self) -> String {
+		let mut content = String::from("Page block order: 9\nPages per block:  512\n\n");
+		content.push_str("Free pages count per migrate type at order");
+		for order in 0..ORDERS {
+			let _ = write!(content, " {order:>6}");
+		}
+		content.push('\n');
+		for zone in &self.0 {
+			for (kind, free) in &zone.free_by_type {
+				let _ = write!(
+					content,
+					"Node {:>4}, zone {:>8}, type {kind:>12}",
+					zone.node, zone.name
+				);
+				for free in free {
+					let _ = write!(content, " {free:>6}");
+				}
+				content.push('\n');
+			}
+		}
+		content.push_str("\nNumber of blocks type ");
+		for kind in MIGRATE_TYPES {
+			let _ = write!(content, " {kind:>12}");
+		}
+		content.push('\n');
+		for zone in &self.0 {
+			let _ = write!(content, "Node {}, zone {:>8}", zone.node, zone.name);
+			for _ in MIGRATE_TYPES {
+				let _ = write!(content, " {:>12}", (0..10_000u64).fake::<u64>());
+			}
+			content.push('\n');
+		}
+		content
+	}
+}
+
+impl fmt::Display for FakeZones {
+	/// Renders `/proc/zoneinfo`.
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		for zone in &self.0 {
+			let stat = &zone.stat;
+			writeln!(f, "Node {}, zone {:>8}", zone.node, zone.name)?;
+			writeln!(f, "  per-node stats")?;
+			writeln!(f, "      nr_inactive_anon {}", (0..1_000_000u64).fake::<u64>())?;
+			writeln!(f, "      nr_active_anon {}", (0..1_000_000u64).fake::<u64>())?;
+			writeln!(f, "  pages free     {}", stat.free)?;
+			if stat.has_boost {
+				writeln!(f, "        boost    {}", stat.boost)?;
+			}
+			writeln!(f, "        min      {}", stat.min)?;
+			writeln!(f, "        low      {}", stat.low)?;
+			writeln!(f, "        high     {}", stat.high)?;
+			writeln!(f, "        spanned  {}", stat.spanned)?;
+			writeln!(f, "        present  {}", stat.present)?;
+			writeln!(f, "        managed  {}", stat.managed)?;
+			writeln!(f, "        cma      0")?;
+			writeln!(f, "        protection: (0, 1877, 7829, 7829, 7829)")?;
+			writeln!(f, "      nr_free_pages {}", stat.free)?;
+			writeln!(f, "      nr_zone_inactive_anon 0")?;
+			writeln!(f, "  pagesets")?;
+			writeln!(f, "    cpu: 0")?;
+			writeln!(f, "              count: {}", (0..1000u64).fake::<u64>())?;
+			writeln!(f, "              high:  {}", (0..1000u64).fake::<u64>())?;
+			writeln!(f, "              batch: 63")?;
+			writeln!(f, "  vm stats threshold: 24")?;
+			writeln!(f, "  node_unreclaimable:  0")?;
+			writeln!(f, "  start_pfn:           1")?;
+		}
+		Ok(())
+	}
+}
@@ -0,0 +1,150 @@
+use fake::{Fake, Faker};
+use std::{fmt::Write, fs, io, path::Path};
+
+const FLAGS: &[&str] =
+	&["fpu", "vme", "de", "pse", "tsc", "msr", "pae", "mce", "sse", "sse2", "avx", "avx2"];
+const OSES: &[(&str, &str, &str)] = &[
+	("debian", "12", "Debian GNU/Linux 12 (bookworm)"),
+	("ubuntu", "24.04", "Ubuntu 24.04.1 LTS"),
+	("rhel", "9.4", "Red Hat Enterprise Linux 9.4 (Plow)"),
+	("fedora", "40", "Fedora Linux 40 (Server Edition)"),
+];
+
+#[derive(Debug, Clone)]
+pub struct FakeDmi {
+	pub sys_vendor: String,
+	pub product_name: String,
+	pub bios_version: String,
+	/// `None` when the file is unreadable, e.g. root only
+	pub product_serial: Option<String>,
+	pub chassis_type: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct FakeHost {
+	pub hostname: String,
+	/// `None` when `/etc/machine-id` is missing
+	pub machine_id: Option<String>,
+	pub boot_id: String,
+	pub kernel_release: String,
+	pub kernel_version: String,
+	pub os_id: &'static str,
+	pub os_version_id: &'static str,
+	pub os_pretty_name: &'static str,
+	pub cpu_model: String,
+	pub microcode: String,
+	pub flags: Vec<&'static str>,
+	pub cpus: usize,
+	/// `None` for hosts without a DMI table
+	pub dmi: Option<FakeDmi>,
+}
+
+fn hex(len: usize) -> String {
+	(0..len)
+		.map(|_| char::from_digit((0..16).fake::<u32>(), 16).unwrap_or('0'))
+		.collect()
+}
+
+fn uuid() -> String {
+	format!("{}-{}-{}-{}-{}", hex(8), hex(4), hex(4), hex(4), hex(12))
+}
+
+impl FakeHost {
+	pub fn generate() -> Self {
+		let (os_id, os_version_id, os_pretty_name) = OSES[(0..OSES.len()).fake::<usize>()];
+		let kernel_release = format!(
+			"6.{}.{}-{}-amd64",
+			(0..12).fake::<u32>(),
+			(0..100).fake::<u32>(),
+			(1..30).fake::<u32>()
+		);
+		let mut flags: Vec<_> = FLAGS.iter().copied().filter(|_| Faker.fake()).collect();
+		if flags.is_empty() {
+			flags.push("fpu");
+		}
+
+		Self {
+			hostname: format!("node-{}", hex(6)),
+			machine_id: (0..5).fake::<u32>().gt(&0).then(|| hex(32)),
+			boot_id: uuid(),
+			kernel_version: format!(
+				"Linux version {kernel_release} (gcc version 12.2.0) #1 SMP PREEMPT_DYNAMIC"
+			),
+			kernel_release,
+			os_id,
+			os_version_id,
+			os_pretty_name,
+			cpu_model: format!(
+				"Intel(R) Xeon(R) Gold {} CPU @ 2.00GHz",
+				(5000..7000).fake::<u32>()
+			),
+			microcode: format!("0x{}", hex(7)),
+			flags,
+			cpus: (1..=64).fake(),
+			dmi: Faker.fake::<bool>().then(|| FakeDmi {
+				sys_vendor: "Dell Inc.".to_string(),
+				product_name: format!("PowerEdge R{}", (100..999).fake::<u32>()),
+				bios_version: format!(
+					"{}.{}.{}",
+					(1..3).fake::<u32>(),
+					(0..20).fake::<u32>(),
+					(0..10).fake::<u32>()
+				),
+				product_serial: Faker.fake::<bool>().then(|| hex(7).to_uppercase()),
+				chassis_type: (1..36).fake::<u32>().to_string(),
+			}),
+		}
+	}
+
+	/// Renders `/proc/cpuinfo`, one block per logical CPU.
+	fn cpuinfo(&self) -> String {
+		let mut content = String::new();
+		for cpu in 0..self.cpus {
+			let _ = write!(
+				content,
+				"processor\t: {cpu}\nvendor_id\t: GenuineIntel\nmodel name\t: {}\nmicrocode\t: {}\nflags\t\t: {}\n\n",
+				self.cpu_model,
+				self.microcode,
+				self.flags.join(" ")
+			);
+		}
+		content
+	}
+
+	pub fn write(&self, proc_dir: &Path, sys_dir: &Path, etc_dir: &Path) -> io::Result<()> {
+		let kernel_dir = proc_dir.join("sys/kernel");
+		fs::create_dir_all(kernel_dir.join("random"))?;
+		fs::write(kernel_dir.join("hostname"), format!("{}\n", self.hostname))?;
+		fs::write(kernel_dir.join("osrelease"), format!("{}\n", self.kernel_release))?;
+		fs::write(kernel_dir.join("random/boot_id"), format!("{}\n", self.boot_id))?;
+		fs::write(proc_dir.join("version"), format!("{}\n", self.kernel_version))?;
+		fs::write(proc_dir.join("cpuinfo"), self.cpuinfo())?;
+
+		fs::create_dir_all(etc_dir)?;
+		if let Some(machine_id) = &self.machine_id {
+			fs::write(etc_dir.join("machine-id"), format!("{machine_id}\n"))?;
+		}
+		fs::write(
+			etc_dir.join("os-release"),
+			format!(
+				"PRETTY_NAME=\"{}\"\nID={}\nVERSION_ID=\"{}\"\n",
+				self.os_pretty_name, self.os_id, self.os_version_id
+			),
+		)?;
+
+		if let Some(dmi) = &self.dmi {
+			let dmi_dir = sys_dir.join("class/dmi/id");
+			fs::create_dir_all(&dmi_dir)?;
+			fs::write(dmi_dir.join("sys_vendor"), format!("{}\n", dmi.sys_vendor))?;
+			fs::write(dmi_dir.join("product_name"), format!("{}\n", dmi.product_name))?;
+			fs::write(dmi_dir.join("bios_version"), format!("{}\n", dmi.bios_version))?;
+			fs::write(dmi_dir.join("chassis_type"), format!("{}\n", dmi.chassis_type))?;
+			// Firmware leaves unset strings empty
+			fs::write(dmi_dir.join("board_name"), "\n")?;
+			if let Some(serial) = &dmi.product_serial {
+				fs::write(dmi_dir.join("product_serial"), format!("{serial}\n"))?;
+			}
+		}
+		Ok(())
+	}
+}
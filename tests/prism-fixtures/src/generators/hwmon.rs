@@ -0,0 +1,121 @@
+use fake::{Dummy, Fake, Faker};
+use std::{fs, io, path::Path};
+
+const CHIP_NAMES: &[&str] = &["coretemp", "k10temp", "nvme", "nct6775", "amdgpu", "acpitz"];
+const ZONE_TYPES: &[&str] = &["x86_pkg_temp", "acpitz", "iwlwifi_1", "pch_cannonlake"];
+
+#[derive(Debug, Dummy, Clone)]
+pub struct FakeSensor {
+	/// Whether a `_label` file is written for this channel
+	pub labelled: bool,
+	/// Raw `_input` value in the channel unit (millidegree, RPM, mV or µW)
+	#[dummy(faker = "0..=120_000")]
+	pub input: i64,
+	/// Whether a `_crit` file is written (temperature channels only)
+	pub has_crit: bool,
+	/// Raw `_crit` value in millidegree Celsius
+	#[dummy(faker = "80_000..=125_000")]
+	pub crit: i64,
+}
+
+#[derive(Debug, Dummy, Clone)]
+pub struct FakeChip {
+	#[dummy(faker = "0..6")]
+	pub name: usize,
+	#[dummy(faker = "(Faker, 0..6)")]
+	pub temperatures: Vec<FakeSensor>,
+	#[dummy(faker = "(Faker, 0..4)")]
+	pub fans: Vec<FakeSensor>,
+	#[dummy(faker = "(Faker, 0..8)")]
+	pub voltages: Vec<FakeSensor>,
+	#[dummy(faker = "(Faker, 0..2)")]
+	pub powers: Vec<FakeSensor>,
+}
+
+#[derive(Debug, Dummy, Clone)]
+pub struct FakeThermalZone {
+	#[dummy(faker = "0..4")]
+	pub kind: usize,
+	/// Raw `temp` value in millidegree Celsius
+	#[dummy(faker = "-10_000..=105_000")]
+	pub temp: i64,
+}
+
+pub struct FakeHwmon {
+	pub chips: Vec<FakeChip>,
+	pub zones: Vec<FakeThermalZone>,
+}
+
+impl FakeChip {
+	pub fn name(&self) -> &'static str {
+		CHIP_NAMES[self.name]
+	}
+
+	/// Label written for channel `<prefix><index>`, if any.
+	pub fn label(sensor: &FakeSensor, prefix: &str, index: usize) -> String {
+		if sensor.labelled { format!("{prefix} label {index}") } else { format!("{prefix}{index}") }
+	}
+}
+
+impl FakeThermalZone {
+	pub fn kind(&self) -> &'static str {
+		ZONE_TYPES[self.kind]
+	}
+}
+
+impl FakeHwmon {
+	pub fn generate() -> Self {
+		Self { chips: (Faker, 0..6).fake(), zones: (Faker, 0..4).fake() }
+	}
+
+	/// Writes the chips under `class/hwmon` and the zones under
+	/// `class/thermal` of the given sysfs root.
+	pub fn write(&self, sys_dir: &Path) -> io::Result<()> {
+		let hwmon_dir = sys_dir.join("class/hwmon");
+		fs::create_dir_all(&hwmon_dir)?;
+		for (id, chip) in self.chips.iter().enumerate() {
+			let chip_dir = hwmon_dir.join(format!("hwmon{id}"));
+			fs::create_dir_all(&chip_dir)?;
+			fs::write(chip_dir.join("name"), format!("{}\n", chip.name()))?;
+
+			let channels = [
+				("temp", &chip.temperatures, 1),
+				("fan", &chip.fans, 1),
+				("in", &chip.voltages, 0),
+				("power", &chip.powers, 1),
+			];
+			for (prefix, sensors, first) in channels {
+				for (offset, sensor) in sensors.iter().enumerate() {
+					let index = first + offset;
+					let base = format!("{prefix}{index}");
+					fs::write(
+						chip_dir.join(format!("{base}_input")),
+						format!("{}\n", sensor.input),
+					)?;
+					if sensor.labelled {
+						fs::write(
+							chip_dir.join(format!("{base}_label")),
+							format!("{}\n", FakeChip::label(sensor, prefix, index)),
+						)?;
+					}
+					if prefix == "temp" && sensor.has_crit {
+						fs::write(
+							chip_dir.join(format!("{base}_crit")),
+							format!("{}\n", sensor.crit),
+						)?;
+					}
+				}
+			}
+		}
+
+		let thermal_dir = sys_dir.join("class/thermal");
+		fs::create_dir_all(&thermal_dir)?;
+		for (id, zone) in self.zones.iter().enumerate() {
+			let zone_dir = thermal_dir.join(format!("thermal_zone{id}"));
+			fs::create_dir_all(&zone_dir)?;
+			fs::write(zone_dir.join("type"), format!("{}\n", zone.kind()))?;
+			fs::write(zone_dir.join("temp"), format!("{}\n", zone.temp))?;
+		}
+		Ok(())
+	}
+}
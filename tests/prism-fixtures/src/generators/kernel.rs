@@ -0,0 +1,113 @@
+use fake::{Dummy, Fake, Faker};
+use std::{fmt::Write, fs, io, path::Path};
+
+#[derive(Debug, Dummy, Clone)]
+pub struct FakeSwap {
+	#[dummy(faker = "0..16")]
+	pub id: u32,
+	pub is_file: bool,
+	#[dummy(faker = "0..=1_000_000_000")]
+	pub size: u64,
+	#[dummy(faker = "0..=1_000_000_000")]
+	pub used: u64,
+	#[dummy(faker = "-10..=32767")]
+	pub priority: i64,
+}
+
+impl FakeSwap {
+	pub fn filename(&self) -> String {
+		if self.is_file { format!("/swapfile{}", self.id) } else { format!("/dev/dm-{}", self.id) }
+	}
+
+	pub const fn kind(&self) -> &'static str {
+		if self.is_file { "file" } else { "partition" }
+	}
+}
+
+#[derive(Debug, Dummy, Clone)]
+pub struct FakeConntrack {
+	#[dummy(faker = "0..=262_144")]
+	pub count: u64,
+	#[dummy(faker = "262_144..=4_194_304")]
+	pub max: u64,
+}
+
+#[derive(Debug, Dummy, Clone)]
+pub struct FakeKernelTables {
+	#[dummy(faker = "(Faker, 0..4)")]
+	pub swaps: Vec<FakeSwap>,
+	#[dummy(faker = "0..=10_000_000")]
+	pub file_allocated: u64,
+	#[dummy(faker = "0..=1000")]
+	pub file_unused: u64,
+	#[dummy(faker = "10_000_000..=9_223_372_036_854_775_807")]
+	pub file_max: u64,
+	#[dummy(faker = "0..=10_000_000")]
+	pub inode_allocated: u64,
+	#[dummy(faker = "0..=10_000_000")]
+	pub inode_free: u64,
+	#[dummy(faker = "1..=100_000")]
+	pub tasks: u64,
+	#[dummy(faker = "32_768..=4_194_304")]
+	pub pid_max: u64,
+	#[dummy(faker = "1..=4_194_304")]
+	pub threads_max: u64,
+	/// `None` when the `nf_conntrack` module is not loaded
+	pub conntrack: Option<FakeConntrack>,
+	#[dummy(faker = "0..=256")]
+	pub entropy_avail: u64,
+}
+
+impl FakeKernelTables {
+	pub fn generate() -> Self {
+		Faker.fake()
+	}
+
+	pub fn swaps(&self) -> String {
+		let mut content = String::from("Filename\t\t\t\tType\t\tSize\t\tUsed\t\tPriority\n");
+		for swap in &self.swaps {
+			let _ = writeln!(
+				content,
+				"{:<40}{}\t{}\t\t{}\t\t{}",
+				swap.filename(),
+				swap.kind(),
+				swap.size,
+				swap.used,
+				swap.priority
+			);
+		}
+		content
+	}
+
+	/// Writes every table under the given procfs root.
+	pub fn write(&self, proc_dir: &Path) -> io::Result<()> {
+		fs::write(proc_dir.join("swaps"), self.swaps())?;
+
+		let fs_dir = proc_dir.join("sys/fs");
+		fs::create_dir_all(&fs_dir)?;
+		fs::write(
+			fs_dir.join("file-nr"),
+			format!("{}\t{}\t{}\n", self.file_allocated, self.file_unused, self.file_max),
+		)?;
+		fs::write(
+			fs_dir.join("inode-nr"),
+			format!("{}\t{}\n", self.inode_allocated, self.inode_free),
+		)?;
+
+		fs::write(proc_dir.join("loadavg"), format!("0.52 0.58 0.59 3/{} 56789\n", self.tasks))?;
+		let kernel_dir = proc_dir.join("sys/kernel");
+		fs::create_dir_all(kernel_dir.join("random"))?;
+		fs::write(kernel_dir.join("pid_max"), format!("{}\n", self.pid_max))?;
+		fs::write(kernel_dir.join("threads-max"), format!("{}\n", self.threads_max))?;
+		fs::write(kernel_dir.join("random/entropy_avail"), format!("{}\n", self.entropy_avail))?;
+		fs::write(kernel_dir.join("random/poolsize"), "256\n")?;
+
+		if let Some(conntrack) = &self.conntrack {
+			let netfilter_dir = proc_dir.join("sys/net/netfilter");
+			fs::create_dir_all(&netfilter_dir)?;
+			fs::write(netfilter_dir.join("nf_conntrack_count"), format!("{}\n", conntrack.count))?;
+			fs::write(netfilter_dir.join("nf_conntrack_max"), format!("{}\n", conntrack.max))?;
+		}
+		Ok(())
+	}
+}
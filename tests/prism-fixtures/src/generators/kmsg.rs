@@ -0,0 +1,192 @@
+use fake::{Fake, Faker};
+use std::{fmt, fs, io, path::Path};
+
+const COMMS: &[&str] = &["java", "postgres", "kworker/0:1", "nginx", "my[app]"];
+const DEVICES: &[&str] = &["sda", "sdb1", "nvme0n1", "dm-0"];
+const INTERFACES: &[&str] = &["eth0", "eno1", "ens3f0"];
+
+/// Condition reported by a fake kernel log record, mirroring the variants of
+/// `prism_kernel::kmsg::KernelEvent`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FakeKernelEvent {
+	OomKill { pid: u32, comm: String, total_vm: u64, anon_rss: u64, file_rss: u64, shmem_rss: u64 },
+	HungTask { pid: u32, comm: String, seconds: u64 },
+	SoftLockup { cpu: u32, seconds: u64, pid: u32, comm: String },
+	IoError { device: String, sector: Option<u64> },
+	Segfault { pid: u32, comm: String, address: String, ip: String, error: u32 },
+	Link { interface: String, up: bool, speed: Option<u64> },
+}
+
+#[derive(Debug, Clone)]
+pub struct FakeKmsgRecord {
+	pub level: u8,
+	pub facility: u8,
+	pub sequence: u64,
+	/// Microseconds since boot
+	pub timestamp: u64,
+	pub continuation: bool,
+	pub message: String,
+	/// `None` for messages that are not worth an event
+	pub event: Option<FakeKernelEvent>,
+}
+
+#[derive(Debug, Clone)]
+pub struct FakeKmsg(pub Vec<FakeKmsgRecord>);
+
+fn pick(values: &[&str]) -> String {
+	values[(0..values.len()).fake::<usize>()].to_string()
+}
+
+fn generate_event() -> (String, FakeKernelEvent) {
+	let pid = (1..4_194_304).fake::<u32>();
+	match (0..7).fake::<u32>() {
+		0 => {
+			let (comm, total_vm, anon_rss, file_rss, shmem_rss) = (
+				pick(COMMS),
+				(0..100_000_000).fake::<u64>(),
+				(0..100_000_000).fake::<u64>(),
+				(0..1_000_000).fake::<u64>(),
+				(0..1_000_000).fake::<u64>(),
+			);
+			let prefix = if Faker.fake() { "Out of memory" } else { "Memory cgroup out of memory" };
+			(
+				format!(
+					"{prefix}: Killed process {pid} ({comm}) total-vm:{total_vm}kB, anon-rss:{anon_rss}kB, file-rss:{file_rss}kB, shmem-rss:{shmem_rss}kB, UID:0 pgtables:400kB oom_score_adj:0"
+				),
+				FakeKernelEvent::OomKill { pid, comm, total_vm, anon_rss, file_rss, shmem_rss },
+			)
+		},
+		1 => {
+			let (comm, seconds) =
+				(pick(COMMS), *[120u64, 241, 362].get((0..3).fake::<usize>()).unwrap_or(&120));
+			(
+				format!("INFO: task {comm}:{pid} blocked for more than {seconds} seconds."),
+				FakeKernelEvent::HungTask { pid, comm, seconds },
+			)
+		},
+		2 => {
+			let (cpu, seconds, comm) =
+				((0..256).fake::<u32>(), (22..100).fake::<u64>(), pick(COMMS));
+			(
+				format!(
+					"watchdog: BUG: soft lockup - CPU#{cpu} stuck for {seconds}s! [{comm}:{pid}]"
+				),
+				FakeKernelEvent::SoftLockup { cpu, seconds, pid, comm },
+			)
+		},
+		3 => {
+			let (device, sector) = (pick(DEVICES), (0..1_000_000_000).fake::<u64>());
+			if Faker.fake() {
+				(
+					format!(
+						"I/O error, dev {device}, sector {sector} op 0x0:(READ) flags 0x0 phys_seg 1 prio class 2"
+					),
+					FakeKernelEvent::IoError { device, sector: Some(sector) },
+				)
+			} else {
+				(
+					format!(
+						"Buffer I/O error on dev {device}, logical block {sector}, async page read"
+					),
+					FakeKernelEvent::IoError { device, sector: None },
+				)
+			}
+		},
+		4 => {
+			let comm = pick(COMMS);
+			let address = format!("{:x}", (0..u32::MAX).fake::<u32>());
+			let ip = format!("{:012x}", (0..u64::MAX >> 16).fake::<u64>());
+			let error = (0..16).fake::<u32>();
+			(
+				format!(
+					"{comm}[{pid}]: segfault at {address} ip {ip} sp 00007ffc4b10 error {error} in {comm}[55d5c8a00000+1000]"
+				),
+				FakeKernelEvent::Segfault { pid, comm, address, ip, error },
+			)
+		},
+		5 => {
+			let interface = pick(INTERFACES);
+			let speed = *[100u64, 1000, 10000, 25000].get((0..4).fake::<usize>()).unwrap_or(&1000);
+			let message = if speed >= 10000 {
+				format!(
+					"ixgbe 0000:01:00.0 {interface}: NIC Link is Up {} Gbps, Flow Control: RX/TX",
+					speed / 1000
+				)
+			} else {
+				format!(
+					"e1000e: {interface} NIC Link is Up {speed} Mbps Full Duplex, Flow Control: None"
+				)
+			};
+			(message, FakeKernelEvent::Link { interface, up: true, speed: Some(speed) })
+		},
+		_ => {
+			let interface = pick(INTERFACES);
+			(
+				format!("igb 0000:02:00.0 {interface}: NIC Link is Down"),
+				FakeKernelEvent::Link { interface, up: false, speed: None },
+			)
+		},
+	}
+}
+
+impl FakeKmsg {
+	pub fn generate() -> Self {
+		let mut sequence = (0..1_000_000).fake::<u64>();
+		let mut timestamp = (0..1_000_000_000).fake::<u64>();
+		let records = (0..(1..50).fake::<u32>())
+			.map(|_| {
+				sequence += (1..3).fake::<u64>();
+				timestamp += (0..10_000_000).fake::<u64>();
+				let (message, event) = if (0..3).fake::<u32>() == 0 {
+					(
+						format!(
+							"usb 1-1: new high-speed USB device number {}",
+							(1..128).fake::<u32>()
+						),
+						None,
+					)
+				} else {
+					let (message, event) = generate_event();
+					(message, Some(event))
+				};
+				FakeKmsgRecord {
+					level: (0..8).fake(),
+					facility: if (0..5).fake::<u32>() == 0 { 1 } else { 0 },
+					sequence,
+					timestamp,
+					continuation: false,
+					message,
+					event,
+				}
+			})
+			.collect();
+		Self(records)
+	}
+}
+
+impl fmt::Display for FakeKmsg {
+	/// Renders the records as read from `/dev/kmsg`, with dictionary lines.
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		for record in &self.0 {
+			let flag = if record.continuation { "c" } else { "-" };
+			writeln!(
+				f,
+				"{},{},{},{flag};{}",
+				(u32::from(record.facility) << 3) | u32::from(record.level),
+				record.sequence,
+				record.timestamp,
+				record.message
+			)?;
+			if let Some(FakeKernelEvent::IoError { device, .. }) = &record.event {
+				writeln!(f, " SUBSYSTEM=block\n DEVICE=+block:{device}")?;
+			}
+		}
+		Ok(())
+	}
+}
+
+impl FakeKmsg {
+	pub fn write(&self, path: &Path) -> io::Result<()> {
+		fs::write(path, self.to_string())
+	}
+}
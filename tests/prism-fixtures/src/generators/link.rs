@@ -0,0 +1,185 @@
+use fake::{Fake, Faker};
+use std::{fs, io, os::unix::fs::symlink, path::Path};
+
+/// `ARPHRD_ETHER` and `ARPHRD_LOOPBACK`
+const ARPHRD_ETHER: u16 = 1;
+const ARPHRD_LOOPBACK: u16 = 772;
+const SPEEDS: &[u64] = &[100, 1000, 10000, 25000, 100000];
+
+#[derive(Debug, Clone)]
+pub struct FakeLink {
+	pub name: String,
+	pub role: &'static str,
+	pub operstate: &'static str,
+	/// `None` when the link is down, reading `carrier` then fails
+	pub carrier: Option<bool>,
+	pub carrier_changes: u64,
+	/// `None` when the link is down, `speed` then holds `-1`
+	pub speed: Option<u64>,
+	/// `None` when `duplex` is `unknown`
+	pub duplex: Option<&'static str>,
+	pub mtu: u64,
+	pub address: String,
+	pub kind: u16,
+	pub ifindex: u64,
+	pub iflink: u64,
+	pub master: Option<String>,
+	pub members: Vec<String>,
+}
+
+impl FakeLink {
+	fn new(name: String, role: &'static str, ifindex: u64) -> Self {
+		let up: bool = Faker.fake();
+		Self {
+			name,
+			role,
+			operstate: if up { "up" } else { "down" },
+			carrier: up.then_some(true),
+			carrier_changes: (0..10_000).fake(),
+			speed: None,
+			duplex: None,
+			mtu: *[1500, 9000].get((0..2).fake::<usize>()).unwrap_or(&1500),
+			address: fake_address(),
+			kind: ARPHRD_ETHER,
+			ifindex,
+			iflink: ifindex,
+			master: None,
+			members: Vec::new(),
+		}
+	}
+
+	/// Negotiated speed and duplex, only known while the link is up.
+	fn negotiate(&mut self) {
+		if self.carrier.is_some() {
+			self.speed = Some(SPEEDS[(0..SPEEDS.len()).fake::<usize>()]);
+			self.duplex = Some(if Faker.fake() { "full" } else { "half" });
+		}
+	}
+
+	fn write(&self, net_dir: &Path) -> io::Result<()> {
+		let dir = net_dir.join(&self.name);
+		fs::create_dir_all(&dir)?;
+		fs::write(dir.join("operstate"), format!("{}\n", self.operstate))?;
+		if let Some(carrier) = self.carrier {
+			fs::write(dir.join("carrier"), format!("{}\n", u8::from(carrier)))?;
+		}
+		fs::write(dir.join("carrier_changes"), format!("{}\n", self.carrier_changes))?;
+		match self.speed {
+			Some(speed) => fs::write(dir.join("speed"), format!("{speed}\n"))?,
+			None => fs::write(dir.join("speed"), "-1\n")?,
+		}
+		fs::write(dir.join("duplex"), format!("{}\n", self.duplex.unwrap_or("unknown")))?;
+		fs::write(dir.join("mtu"), format!("{}\n", self.mtu))?;
+		fs::write(dir.join("address"), format!("{}\n", self.address))?;
+		fs::write(dir.join("type"), format!("{}\n", self.kind))?;
+		fs::write(dir.join("ifindex"), format!("{}\n", self.ifindex))?;
+		fs::write(dir.join("iflink"), format!("{}\n", self.iflink))?;
+
+		let mut uevent = String::new();
+		match self.role {
+			"physical" => fs::create_dir_all(dir.join("device"))?,
+			"bond" => {
+				uevent.push_str("DEVTYPE=bond\n");
+				fs::create_dir_all(dir.join("bonding"))?;
+				fs::write(dir.join("bonding/slaves"), format!("{}\n", self.members.join(" ")))?;
+			},
+			"bridge" => {
+				uevent.push_str("DEVTYPE=bridge\n");
+				fs::create_dir_all(dir.join("bridge"))?;
+				fs::create_dir_all(dir.join("brif"))?;
+				for port in &self.members {
+					symlink(format!("../../{port}/brport"), dir.join("brif").join(port))?;
+				}
+			},
+			"vlan" => uevent.push_str("DEVTYPE=vlan\n"),
+			_ => {},
+		}
+		uevent.push_str(&format!("INTERFACE={}\nIFINDEX={}\n", self.name, self.ifindex));
+		fs::write(dir.join("uevent"), uevent)?;
+
+		if let Some(master) = &self.master {
+			symlink(format!("../{master}"), dir.join("master"))?;
+		}
+		Ok(())
+	}
+}
+
+fn fake_address() -> String {
+	let bytes: [u8; 6] = Faker.fake();
+	bytes.iter().map(|byte| format!("{byte:02x}")).collect::<Vec<_>>().join(":")
+}
+
+/// Interfaces of `/sys/class/net`: the loopback, a few NICs, and optionally
+/// a bond over some of them, a bridge over veth peers and a VLAN
+#[derive(Debug, Clone)]
+pub struct FakeLinks(pub Vec<FakeLink>);
+
+impl FakeLinks {
+	pub fn generate() -> Self {
+		let mut links = Vec::new();
+		let mut ifindex = 1;
+
+		let mut lo = FakeLink::new("lo".to_string(), "loopback", ifindex);
+		(lo.operstate, lo.carrier, lo.mtu, lo.kind) =
+			("unknown", Some(true), 65536, ARPHRD_LOOPBACK);
+		lo.address = "00:00:00:00:00:00".to_string();
+		links.push(lo);
+
+		let nics: u64 = (1..=4).fake();
+		let mut eths = Vec::new();
+		for i in 0..nics {
+			ifindex += 1;
+			let mut eth = FakeLink::new(format!("eth{i}"), "physical", ifindex);
+			eth.negotiate();
+			eths.push(eth);
+		}
+
+		if nics >= 2 && Faker.fake() {
+			ifindex += 1;
+			let mut bond = FakeLink::new("bond0".to_string(), "bond", ifindex);
+			for eth in eths.iter_mut().skip(1) {
+				eth.master = Some(bond.name.clone());
+				bond.members.push(eth.name.clone());
+			}
+			links.push(bond);
+		}
+
+		if Faker.fake() {
+			ifindex += 1;
+			let mut vlan = FakeLink::new("eth0.100".to_string(), "vlan", ifindex);
+			vlan.iflink = eths[0].ifindex;
+			links.push(vlan);
+		}
+
+		if Faker.fake() {
+			ifindex += 1;
+			let mut bridge = FakeLink::new("br0".to_string(), "bridge", ifindex);
+			let ports: u64 = (1..=3).fake();
+			for i in 0..ports {
+				ifindex += 1;
+				let mut veth = FakeLink::new(format!("veth{i}"), "veth", ifindex);
+				// The peer lives in another namespace.
+				veth.iflink = ifindex + 1000;
+				veth.negotiate();
+				veth.master = Some(bridge.name.clone());
+				bridge.members.push(veth.name.clone());
+				links.push(veth);
+			}
+			links.push(bridge);
+		}
+
+		links.extend(eths);
+		links.sort_by(|a, b| a.name.cmp(&b.name));
+		Self(links)
+	}
+
+	/// Writes every interface under `class/net` of the given sysfs root.
+	pub fn write(&self, sys_dir: &Path) -> io::Result<()> {
+		let net_dir = sys_dir.join("class/net");
+		fs::create_dir_all(&net_dir)?;
+		for link in &self.0 {
+			link.write(&net_dir)?;
+		}
+		Ok(())
+	}
+}
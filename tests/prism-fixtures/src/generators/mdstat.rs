@@ -0,0 +1,150 @@
+use fake::{Fake, Faker};
+use std::fmt;
+
+const LEVELS: &[(&str, u32)] =
+	&[("raid0", 2), ("raid1", 2), ("raid5", 3), ("raid6", 4), ("raid10", 4)];
+const SYNC_ACTIONS: &[&str] = &["resync", "recovery", "reshape", "check", "repair"];
+
+#[derive(Debug, Clone)]
+pub struct FakeMdMember {
+	pub name: String,
+	pub slot: u32,
+	/// `active`, `failed` or `spare`
+	pub role: &'static str,
+}
+
+#[derive(Debug, Clone)]
+pub struct FakeMdSync {
+	pub action: &'static str,
+	/// Completed permille, `None` for a `DELAYED` operation
+	pub permille: Option<u64>,
+	/// Estimated time left in tenths of minute
+	pub finish: u64,
+	/// Speed in K/sec
+	pub speed: u64,
+}
+
+#[derive(Debug, Clone)]
+pub struct FakeMdArray {
+	pub name: String,
+	pub active: bool,
+	pub read_only: bool,
+	pub level: &'static str,
+	pub members: Vec<FakeMdMember>,
+	/// Size in 1K blocks
+	pub blocks: u64,
+	/// Raid and active disks, `None` for inactive and raid0 arrays
+	pub disks: Option<(u32, u32)>,
+	pub sync: Option<FakeMdSync>,
+}
+
+#[derive(Debug, Clone)]
+pub struct FakeMdStat(pub Vec<FakeMdArray>);
+
+impl FakeMdArray {
+	fn generate(index: u32) -> Self {
+		let (level, min_disks) = LEVELS[(0..LEVELS.len()).fake::<usize>()];
+		let active = (0..10).fake::<u32>() > 0;
+		let raid_disks = (min_disks..=min_disks + 2).fake::<u32>();
+		let failed = if level == "raid0" || !active { 0 } else { (0..=1).fake::<u32>() };
+		let spares = (0..=1).fake::<u32>();
+
+		let mut members = Vec::new();
+		for slot in 0..raid_disks + spares {
+			let role = if !active || slot >= raid_disks {
+				"spare"
+			} else if slot < failed {
+				"failed"
+			} else {
+				"active"
+			};
+			members.push(FakeMdMember {
+				name: format!("sd{}{}", (b'a' + slot as u8) as char, index + 1),
+				slot,
+				role,
+			});
+		}
+		// The kernel lists the most recently added member first.
+		members.reverse();
+
+		let sync = (active && level != "raid0" && Faker.fake()).then(|| FakeMdSync {
+			action: SYNC_ACTIONS[(0..SYNC_ACTIONS.len()).fake::<usize>()],
+			permille: (0..10).fake::<u32>().gt(&0).then(|| (0..1000).fake()),
+			finish: (1..100_000).fake(),
+			speed: (1..2_000_000).fake(),
+		});
+
+		Self {
+			name: format!("md{index}"),
+			active,
+			read_only: active && (0..5).fake::<u32>() == 0,
+			level,
+			members,
+			blocks: (1..=10_000_000_000).fake(),
+			disks: (active && level != "raid0").then_some((raid_disks, raid_disks - failed)),
+			sync,
+		}
+	}
+}
+
+impl FakeMdStat {
+	pub fn generate() -> Self {
+		Self((0..(0..=3).fake::<u32>()).map(FakeMdArray::generate).collect())
+	}
+}
+
+impl fmt::Display for FakeMdStat {
+	/// Renders `/proc/mdstat`.
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		writeln!(f, "Personalities : [raid0] [raid1] [raid6] [raid5] [raid4] [raid10]")?;
+		for array in &self.0 {
+			write!(f, "{} : {}", array.name, if array.active { "active" } else { "inactive" })?;
+			if array.read_only {
+				write!(f, " (auto-read-only)")?;
+			}
+			if array.active {
+				write!(f, " {}", array.level)?;
+			}
+			for member in &array.members {
+				let flag = match member.role {
+					"failed" => "(F)",
+					"spare" => "(S)",
+					_ => "",
+				};
+				write!(f, " {}[{}]{flag}", member.name, member.slot)?;
+			}
+			writeln!(f)?;
+
+			write!(f, "      {} blocks super 1.2", array.blocks)?;
+			if let Some((raid, active)) = array.disks {
+				let status: String =
+					(0..raid).map(|disk| if disk < active { 'U' } else { '_' }).collect();
+				write!(f, " [{raid}/{active}] [{status}]")?;
+			}
+			writeln!(f)?;
+
+			if let Some(sync) = &array.sync {
+				match sync.permille {
+					Some(permille) => writeln!(
+						f,
+						"      [==>..................]  {} = {:>2}.{}% ({}/{}) finish={}.{}min speed={}K/sec",
+						sync.action,
+						permille / 10,
+						permille % 10,
+						array.blocks * permille / 1000,
+						array.blocks,
+						sync.finish / 10,
+						sync.finish % 10,
+						sync.speed
+					)?,
+					None => writeln!(f, "      \t{}=DELAYED", sync.action)?,
+				}
+			}
+			if array.active && array.level != "raid0" {
+				writeln!(f, "      bitmap: 0/1 pages [0KB], 65536KB chunk")?;
+			}
+			writeln!(f)?;
+		}
+		writeln!(f, "unused devices: <none>")
+	}
+}
@@ -1,6 +1,7 @@
 #![allow(non_snake_case)]
 
 use fake::{Dummy, Fake, Faker};
+use std::fmt;
 
 #[derive(Debug, Dummy)]
 pub struct FakeMemInfo {
@@ -118,6 +119,22 @@ pub struct FakeMemInfo {
 	/// 4.4, this field is no longer calculated and is hard coded as 0. See
 	/// /proc/vmallocinfo.
 	pub VmallocChunk: u64,
+	/// The size of the pool of huge pages.
+	#[dummy(faker = "0..=1_000_000")]
+	pub HugePages_Total: u64,
+	/// The number of huge pages in the pool that are not yet allocated.
+	#[dummy(faker = "0..=1_000_000")]
+	pub HugePages_Free: u64,
+	/// The number of huge pages committed but not yet allocated.
+	#[dummy(faker = "0..=1_000_000")]
+	pub HugePages_Rsvd: u64,
+	/// The number of huge pages above `nr_hugepages`.
+	#[dummy(faker = "0..=1_000_000")]
+	pub HugePages_Surp: u64,
+	/// The size of huge pages.
+	pub Hugepagesize: u64,
+	/// Total amount of memory consumed by huge pages of all sizes.
+	pub Hugetlb: u64,
 }
 
 impl FakeMemInfo {
@@ -126,9 +143,10 @@ impl FakeMemInfo {
 	}
 }
 
-impl ToString for FakeMemInfo {
-	fn to_string(&self) -> String {
-		format!(
+impl fmt::Display for FakeMemInfo {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(
+			f,
 			"MemTotal:{:>15} kB
 MemFree:{:>16} kB
 MemAvailable:{:>11} kB
@@ -175,10 +193,10 @@ ShmemPmdMapped:{:>9} kB
 FileHugePages:{:>10} kB
 FilePmdMapped:{:>10} kB
 Unaccepted:{:>13} kB
-HugePages_Total:{:>8} kB
-HugePages_Free:{:>9} kB
-HugePages_Rsvd:{:>9} kB
-HugePages_Surp:{:>9} kB
+HugePages_Total:{:>8}
+HugePages_Free:{:>9}
+HugePages_Rsvd:{:>9}
+HugePages_Surp:{:>9}
 Hugepagesize:{:>11} kB
 Hugetlb:{:>16} kB
 DirectMap4k:{:>12} kB
@@ -231,12 +249,12 @@ DirectMap1G:{:>12} kB
 			0, // FileHugePages
 			0, // FilePmdMapped
 			0, // Unaccepted
-			0, // HugePages_Total
-			0, // HugePages_Free
-			0, // HugePages_Rsvd
-			0, // HugePages_Surp
-			0, // Hugepagesize
-			0, // Hugetlb
+			self.HugePages_Total,
+			self.HugePages_Free,
+			self.HugePages_Rsvd,
+			self.HugePages_Surp,
+			self.Hugepagesize,
+			self.Hugetlb,
 			0, // DirectMap4k
 			0, // DirectMap2M
 			0, // DirectMap1G
@@ -1,16 +1,37 @@
+pub use block::{FakeBlockDevice, FakeBlockDevices};
 pub use diskstat::{FakeDiskStat, FakeDiskStats};
+pub use fragmentation::{FakeZone, FakeZoneStat, FakeZones};
+pub use host::{FakeDmi, FakeHost};
+pub use hwmon::{FakeChip, FakeHwmon, FakeSensor, FakeThermalZone};
+pub use kernel::{FakeConntrack, FakeKernelTables, FakeSwap};
+pub use kmsg::{FakeKernelEvent, FakeKmsg, FakeKmsgRecord};
+pub use link::{FakeLink, FakeLinks};
+pub use mdstat::{FakeMdArray, FakeMdMember, FakeMdStat, FakeMdSync};
 pub use meminfo::FakeMemInfo;
 pub use netdev::{FakeInterface, FakeInterfaces};
+pub use numa::{
+	FakeHugePagePool, FakeHugePages, FakeNode, FakeNodeMemInfo, FakeNodes, FakeNumaStat,
+};
 pub use stat::FakeStat;
 use std::{fs, io, path::Path};
 pub use vmstat::FakeVmStat;
 
+mod block;
 mod diskstat;
+mod fragmentation;
+mod host;
+mod hwmon;
+mod kernel;
+mod kmsg;
+mod link;
+mod mdstat;
 mod meminfo;
 mod netdev;
+mod numa;
 mod stat;
 mod vmstat;
 
+#[derive(Default)]
 pub struct Generator;
 
 impl Generator {
@@ -56,4 +77,82 @@ impl Generator {
 		fs::write(net_dir.join("dev"), netdev.to_string())?;
 		Ok(netdev)
 	}
+
+	pub fn generate_hwmon(&self, sys_dir: &Path) -> io::Result<FakeHwmon> {
+		let hwmon = FakeHwmon::generate();
+
+		hwmon.write(sys_dir)?;
+		Ok(hwmon)
+	}
+
+	pub fn generate_hugepages(&self, sys_dir: &Path) -> io::Result<FakeHugePages> {
+		let hugepages = FakeHugePages::generate();
+
+		hugepages.write(&sys_dir.join("kernel/mm/hugepages"))?;
+		Ok(hugepages)
+	}
+
+	pub fn generate_numa(&self, sys_dir: &Path) -> io::Result<FakeNodes> {
+		let nodes = FakeNodes::generate();
+
+		nodes.write(sys_dir)?;
+		Ok(nodes)
+	}
+
+	pub fn generate_fragmentation(&self, proc_dir: &Path) -> io::Result<FakeZones> {
+		let zones = FakeZones::generate();
+
+		fs::write(proc_dir.join("zoneinfo"), zones.to_string())?;
+		fs::write(proc_dir.join("buddyinfo"), zones.buddyinfo())?;
+		fs::write(proc_dir.join("pagetypeinfo"), zones.pagetypeinfo())?;
+		Ok(zones)
+	}
+
+	pub fn generate_kernel_tables(&self, proc_dir: &Path) -> io::Result<FakeKernelTables> {
+		let tables = FakeKernelTables::generate();
+
+		tables.write(proc_dir)?;
+		Ok(tables)
+	}
+
+	pub fn generate_links(&self, sys_dir: &Path) -> io::Result<FakeLinks> {
+		let links = FakeLinks::generate();
+
+		links.write(sys_dir)?;
+		Ok(links)
+	}
+
+	pub fn generate_block_devices(&self, sys_dir: &Path) -> io::Result<FakeBlockDevices> {
+		let devices = FakeBlockDevices::generate();
+
+		devices.write(sys_dir)?;
+		Ok(devices)
+	}
+
+	pub fn generate_mdstat(&self, proc_dir: &Path) -> io::Result<FakeMdStat> {
+		let mdstat = FakeMdStat::generate();
+
+		fs::write(proc_dir.join("mdstat"), mdstat.to_string())?;
+		Ok(mdstat)
+	}
+
+	pub fn generate_host(
+		&self,
+		proc_dir: &Path,
+		sys_dir: &Path,
+		etc_dir: &Path,
+	) -> io::Result<FakeHost> {
+		let host = FakeHost::generate();
+
+		host.write(proc_dir, sys_dir, etc_dir)?;
+		Ok(host)
+	}
+
+	/// Writes a captured `/dev/kmsg` to `path`.
+	pub fn generate_kmsg(&self, path: &Path) -> io::Result<FakeKmsg> {
+		let kmsg = FakeKmsg::generate();
+
+		kmsg.write(path)?;
+		Ok(kmsg)
+	}
 }
@@ -1,5 +1,6 @@
 use alloc::{string::String, vec::Vec};
 use fake::{Dummy, Fake, Faker};
+use std::fmt;
 
 pub struct FakeInterfaces(pub Vec<FakeInterface>);
 
@@ -35,8 +36,8 @@ impl FakeInterfaces {
 	}
 }
 
-impl ToString for FakeInterfaces {
-	fn to_string(&self) -> String {
+impl fmt::Display for FakeInterfaces {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
 		let mut result = String::new();
 		result.push_str(
 			"Inter-|   Receive                                                |  Transmit\n",
@@ -63,6 +64,6 @@ impl ToString for FakeInterfaces {
                 iface.tx_compressed
             )).collect::<Vec<_>>().join("\n");
 		result.push_str(&interfaces);
-		result
+		f.write_str(&result)
 	}
 }
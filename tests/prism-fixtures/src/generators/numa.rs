@@ -0,0 +1,207 @@
+#![allow(non_snake_case)]
+
+use fake::{Dummy, Fake, Faker};
+use std::{fmt, fs, io, path::Path};
+
+/// Huge page sizes in kB, as found on x86_64 and arm64 hosts
+const HUGEPAGE_SIZES: &[u64] = &[64, 2048, 32768, 1048576];
+
+#[derive(Debug, Dummy, Clone)]
+pub struct FakeHugePagePool {
+	#[dummy(faker = "0..=1_000_000")]
+	pub nr: u64,
+	#[dummy(faker = "0..=1_000_000")]
+	pub free: u64,
+	#[dummy(faker = "0..=1_000_000")]
+	pub surplus: u64,
+}
+
+/// Huge page pools, one per entry of [`HUGEPAGE_SIZES`] that is present
+#[derive(Debug, Clone)]
+pub struct FakeHugePages(pub Vec<(u64, FakeHugePagePool)>);
+
+impl FakeHugePages {
+	pub fn generate() -> Self {
+		let pools = HUGEPAGE_SIZES
+			.iter()
+			.filter(|_| Faker.fake::<bool>())
+			.map(|size| (*size, Faker.fake()))
+			.collect();
+		Self(pools)
+	}
+
+	/// Writes every pool as a `hugepages-<size>kB` directory of `dir`.
+	pub fn write(&self, dir: &Path) -> io::Result<()> {
+		fs::create_dir_all(dir)?;
+		for (size, pool) in &self.0 {
+			let pool_dir = dir.join(format!("hugepages-{size}kB"));
+			fs::create_dir_all(&pool_dir)?;
+			fs::write(pool_dir.join("nr_hugepages"), format!("{}\n", pool.nr))?;
+			fs::write(pool_dir.join("free_hugepages"), format!("{}\n", pool.free))?;
+			fs::write(pool_dir.join("surplus_hugepages"), format!("{}\n", pool.surplus))?;
+		}
+		Ok(())
+	}
+}
+
+#[derive(Debug, Dummy, Clone)]
+pub struct FakeNodeMemInfo {
+	pub MemTotal: u64,
+	pub MemFree: u64,
+	pub MemUsed: u64,
+	pub Active: u64,
+	pub Inactive: u64,
+	pub Active_anon: u64,
+	pub Inactive_anon: u64,
+	pub Active_file: u64,
+	pub Inactive_file: u64,
+	pub Dirty: u64,
+	pub Writeback: u64,
+	pub FilePages: u64,
+	pub Mapped: u64,
+	pub AnonPages: u64,
+	pub Shmem: u64,
+	pub Slab: u64,
+	pub SReclaimable: u64,
+	pub SUnreclaim: u64,
+	#[dummy(faker = "0..=1_000_000")]
+	pub HugePages_Total: u64,
+	#[dummy(faker = "0..=1_000_000")]
+	pub HugePages_Free: u64,
+	#[dummy(faker = "0..=1_000_000")]
+	pub HugePages_Surp: u64,
+}
+
+#[derive(Debug, Dummy, Clone)]
+pub struct FakeNumaStat {
+	pub numa_hit: u64,
+	pub numa_miss: u64,
+	pub numa_foreign: u64,
+	pub interleave_hit: u64,
+	pub local_node: u64,
+	pub other_node: u64,
+}
+
+#[derive(Debug, Clone)]
+pub struct FakeNode {
+	pub id: u32,
+	pub meminfo: FakeNodeMemInfo,
+	pub numastat: FakeNumaStat,
+	pub hugepages: FakeHugePages,
+}
+
+#[derive(Debug, Clone)]
+pub struct FakeNodes(pub Vec<FakeNode>);
+
+impl FakeNodeMemInfo {
+	/// Renders the node meminfo with the `Node <id>` prefix the kernel puts on
+	/// every line.
+	pub fn render(&self, id: u32) -> String {
+		format!(
+			"Node {id} MemTotal:       {} kB
+Node {id} MemFree:        {} kB
+Node {id} MemUsed:        {} kB
+Node {id} SwapCached:     0 kB
+Node {id} Active:         {} kB
+Node {id} Inactive:       {} kB
+Node {id} Active(anon):   {} kB
+Node {id} Inactive(anon): {} kB
+Node {id} Active(file):   {} kB
+Node {id} Inactive(file): {} kB
+Node {id} Unevictable:    0 kB
+Node {id} Mlocked:        0 kB
+Node {id} Dirty:          {} kB
+Node {id} Writeback:      {} kB
+Node {id} FilePages:      {} kB
+Node {id} Mapped:         {} kB
+Node {id} AnonPages:      {} kB
+Node {id} Shmem:          {} kB
+Node {id} KernelStack:    0 kB
+Node {id} PageTables:     0 kB
+Node {id} Bounce:         0 kB
+Node {id} WritebackTmp:   0 kB
+Node {id} KReclaimable:   0 kB
+Node {id} Slab:           {} kB
+Node {id} SReclaimable:   {} kB
+Node {id} SUnreclaim:     {} kB
+Node {id} AnonHugePages:  0 kB
+Node {id} HugePages_Total: {}
+Node {id} HugePages_Free:  {}
+Node {id} HugePages_Surp:  {}
+",
+			self.MemTotal,
+			self.MemFree,
+			self.MemUsed,
+			self.Active,
+			self.Inactive,
+			self.Active_anon,
+			self.Inactive_anon,
+			self.Active_file,
+			self.Inactive_file,
+			self.Dirty,
+			self.Writeback,
+			self.FilePages,
+			self.Mapped,
+			self.AnonPages,
+			self.Shmem,
+			self.Slab,
+			self.SReclaimable,
+			self.SUnreclaim,
+			self.HugePages_Total,
+			self.HugePages_Free,
+			self.HugePages_Surp,
+		)
+	}
+}
+
+impl fmt::Display for FakeNumaStat {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(
+			f,
+			"numa_hit {}
+numa_miss {}
+numa_foreign {}
+interleave_hit {}
+local_node {}
+other_node {}
+",
+			self.numa_hit,
+			self.numa_miss,
+			self.numa_foreign,
+			self.interleave_hit,
+			self.local_node,
+			self.other_node,
+		)
+	}
+}
+
+impl FakeNodes {
+	pub fn generate() -> Self {
+		let count: u32 = (1..=4).fake();
+		let nodes = (0..count)
+			.map(|id| FakeNode {
+				id,
+				meminfo: Faker.fake(),
+				numastat: Faker.fake(),
+				hugepages: FakeHugePages::generate(),
+			})
+			.collect();
+		Self(nodes)
+	}
+
+	/// Writes every node under `devices/system/node` of the given sysfs root.
+	pub fn write(&self, sys_dir: &Path) -> io::Result<()> {
+		let node_root = sys_dir.join("devices/system/node");
+		fs::create_dir_all(&node_root)?;
+		// Node masks sit next to the node directories and must be skipped.
+		fs::write(node_root.join("online"), format!("0-{}\n", self.0.len().saturating_sub(1)))?;
+		for node in &self.0 {
+			let node_dir = node_root.join(format!("node{}", node.id));
+			fs::create_dir_all(&node_dir)?;
+			fs::write(node_dir.join("meminfo"), node.meminfo.render(node.id))?;
+			fs::write(node_dir.join("numastat"), node.numastat.to_string())?;
+			node.hugepages.write(&node_dir.join("hugepages"))?;
+		}
+		Ok(())
+	}
+}
@@ -1,4 +1,5 @@
 use fake::{Dummy, Fake, Faker};
+use std::fmt;
 
 #[derive(Debug, Dummy, Clone)]
 pub struct FakeCpuTime {
@@ -55,8 +56,8 @@ impl FakeStat {
 	}
 }
 
-impl ToString for FakeStat {
-	fn to_string(&self) -> String {
+impl fmt::Display for FakeStat {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
 		let mut result = String::new();
 		result.push_str(&format!(
 			"cpu  {} {} {} {} {} {} {} {} {} {}\n",
@@ -104,6 +105,6 @@ impl ToString for FakeStat {
 		result.push_str(
 			"softirq 4679053537 3 217228708 171684 2852529223 217514 0 1920121 752158546 1247 854826491",
 		);
-		result
+		f.write_str(&result)
 	}
 }
@@ -1,4 +1,5 @@
 use fake::{Dummy, Fake, Faker};
+use std::fmt;
 
 #[derive(Debug, Dummy)]
 pub struct FakeVmStat {
@@ -105,6 +106,14 @@ pub struct FakeVmStat {
 	pub pgfault: u64,
 	/// (since Linux 2.6.0)
 	pub pgmajfault: u64,
+	/// (since Linux 4.8)
+	pub pgsteal_kswapd: u64,
+	/// (since Linux 4.8)
+	pub pgsteal_direct: u64,
+	/// (since Linux 4.8)
+	pub pgscan_kswapd: u64,
+	/// (since Linux 4.8)
+	pub pgscan_direct: u64,
 	/// (since Linux 3.6)
 	pub pgscan_direct_throttle: u64,
 	/// (since Linux 2.6.0)
@@ -187,6 +196,8 @@ pub struct FakeVmStat {
 	pub balloon_deflate: u64,
 	/// (since Linux 3.18)
 	pub balloon_migrate: u64,
+	/// (since Linux 4.13)
+	pub oom_kill: u64,
 }
 
 impl FakeVmStat {
@@ -195,9 +206,10 @@ impl FakeVmStat {
 	}
 }
 
-impl ToString for FakeVmStat {
-	fn to_string(&self) -> String {
-		format!(
+impl fmt::Display for FakeVmStat {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(
+			f,
 			"nr_free_pages {}
 nr_zone_inactive_anon {}
 nr_zone_active_anon {}
@@ -470,11 +482,11 @@ nr_unstable {}",
 			0, // pglazyfreed,
 			0, // pgrefill,
 			0, // pgreuse,
-			0, // pgsteal_kswapd,
-			0, // pgsteal_direct,
+			self.pgsteal_kswapd,
+			self.pgsteal_direct,
 			0, // pgsteal_khugepaged,
-			0, // pgscan_kswapd,
-			0, // pgscan_direct,
+			self.pgscan_kswapd,
+			self.pgscan_direct,
 			0, // pgscan_khugepaged,
 			self.pgscan_direct_throttle,
 			0, // pgscan_anon,
@@ -491,7 +503,7 @@ nr_unstable {}",
 			self.pgrotated,
 			self.drop_pagecache,
 			self.drop_slab,
-			0, // oom_kill,
+			self.oom_kill,
 			self.numa_pte_updates,
 			self.numa_huge_pte_updates,
 			self.numa_hint_faults,
@@ -7,10 +7,18 @@ pub(crate) async fn single_test(test_id: usize, session_dir: &str) -> Result<()>
 	let test_dir_name = format!("test-{test_id:03}");
 	let test_dir = Path::new(session_dir).join(&test_dir_name);
 	let proc_path = test_dir.join("procfs");
+	let sys_path = test_dir.join("sysfs");
+	let etc_path = test_dir.join("etc");
 	fs::create_dir_all(&proc_path)?;
+	fs::create_dir_all(&sys_path)?;
+	fs::create_dir_all(&etc_path)?;
 
-	// Set procfs root using environment variable for this process
-	unsafe { env::set_var("PROCFS_ROOT", &proc_path) };
+	// Set procfs, sysfs and etc roots using environment variables for this process
+	unsafe {
+		env::set_var("PROCFS_ROOT", &proc_path);
+		env::set_var("SYSFS_ROOT", &sys_path);
+		env::set_var("ETC_ROOT", &etc_path);
+	};
 
 	// Generate random procfs data
 	let generator = Generator::new();
@@ -21,6 +29,17 @@ pub(crate) async fn single_test(test_id: usize, session_dir: &str) -> Result<()>
 	let fake_vmstat = generator.generate_vmstat(&proc_path)?;
 	let fake_diskstats = generator.generate_diskstats(&proc_path)?;
 	let fake_netdev = generator.generate_netdev(&proc_path)?;
+	let fake_zones = generator.generate_fragmentation(&proc_path)?;
+	let fake_kernel = generator.generate_kernel_tables(&proc_path)?;
+	let fake_mdstat = generator.generate_mdstat(&proc_path)?;
+	let fake_hwmon = generator.generate_hwmon(&sys_path)?;
+	let fake_hugepages = generator.generate_hugepages(&sys_path)?;
+	let fake_nodes = generator.generate_numa(&sys_path)?;
+	let fake_links = generator.generate_links(&sys_path)?;
+	let fake_block = generator.generate_block_devices(&sys_path)?;
+	let fake_host = generator.generate_host(&proc_path, &sys_path, &etc_path)?;
+	let kmsg_path = test_dir.join("kmsg");
+	let fake_kmsg = generator.generate_kmsg(&kmsg_path)?;
 	println!("  Running prism collectors and validating results");
 
 	// Initialize the roots to pick up the environment variable
@@ -41,6 +60,77 @@ pub(crate) async fn single_test(test_id: usize, session_dir: &str) -> Result<()>
 	let real_netdev = prism_network::netdev::interfaces()
 		.await
 		.map_err(|e| anyhow!("NetDev parsing failed: {}", e))?;
+	let real_buddyinfo = prism_memory::buddyinfo::buddyinfo()
+		.await
+		.map_err(|e| anyhow!("BuddyInfo parsing failed: {}", e))?;
+	let real_zoneinfo = prism_memory::zoneinfo::zoneinfo()
+		.await
+		.map_err(|e| anyhow!("ZoneInfo parsing failed: {}", e))?;
+	let real_pagetypeinfo = prism_memory::pagetypeinfo::pagetypeinfo()
+		.await
+		.map_err(|e| anyhow!("PageTypeInfo parsing failed: {}", e))?;
+	let real_swaps = prism_kernel::swaps::swaps()
+		.await
+		.map_err(|e| anyhow!("Swaps parsing failed: {}", e))?;
+	let real_file_nr = prism_kernel::fs::file_nr()
+		.await
+		.map_err(|e| anyhow!("file-nr parsing failed: {}", e))?;
+	let real_inode_nr = prism_kernel::fs::inode_nr()
+		.await
+		.map_err(|e| anyhow!("inode-nr parsing failed: {}", e))?;
+	let real_tasks = prism_kernel::tasks::tasks()
+		.await
+		.map_err(|e| anyhow!("Task limits parsing failed: {}", e))?;
+	let real_entropy = prism_kernel::tasks::entropy()
+		.await
+		.map_err(|e| anyhow!("Entropy parsing failed: {}", e))?;
+	let real_conntrack = prism_kernel::conntrack::conntrack()
+		.await
+		.map_err(|e| anyhow!("Conntrack parsing failed: {}", e))?;
+	let real_mdstat = prism_disk::mdstat::mdstat()
+		.await
+		.map_err(|e| anyhow!("MdStat parsing failed: {}", e))?;
+	let real_block = prism_disk::block::block_devices()
+		.await
+		.map_err(|e| anyhow!("Block device parsing failed: {}", e))?;
+	let real_identity = prism_host::identity::identity()
+		.await
+		.map_err(|e| anyhow!("Identity parsing failed: {}", e))?;
+	let real_os_release = prism_host::os_release::os_release()
+		.await
+		.map_err(|e| anyhow!("os-release parsing failed: {}", e))?;
+	let real_cpuinfo = prism_host::cpuinfo::cpuinfo()
+		.await
+		.map_err(|e| anyhow!("CpuInfo parsing failed: {}", e))?;
+	let real_dmi =
+		prism_host::dmi::dmi().await.map_err(|e| anyhow!("DMI parsing failed: {}", e))?;
+	let real_kmsg: Vec<_> = fs::read_to_string(&kmsg_path)?
+		.lines()
+		.filter_map(prism_kernel::kmsg::parse)
+		.collect();
+	let mut real_links = Vec::with_capacity(fake_links.0.len());
+	for fake in &fake_links.0 {
+		let link = prism_network::link::link(&fake.name)
+			.await
+			.map_err(|e| anyhow!("Link {} parsing failed: {}", fake.name, e))?
+			.ok_or_else(|| anyhow!("Link {} not found", fake.name))?;
+		real_links.push(link);
+	}
+	let missing_link = prism_network::link::link("missing0")
+		.await
+		.map_err(|e| anyhow!("Link missing0 parsing failed: {}", e))?;
+	let real_chips = prism_hwmon::hwmon::chips()
+		.await
+		.map_err(|e| anyhow!("Hwmon parsing failed: {}", e))?;
+	let real_zones = prism_hwmon::thermal::thermal_zones()
+		.await
+		.map_err(|e| anyhow!("Thermal zone parsing failed: {}", e))?;
+	let real_hugepages = prism_memory::hugepages::hugepages()
+		.await
+		.map_err(|e| anyhow!("Hugepages parsing failed: {}", e))?;
+	let real_nodes = prism_memory::numa::nodes()
+		.await
+		.map_err(|e| anyhow!("NUMA parsing failed: {}", e))?;
 	// Create validator with original values and test directory
 	let validator = Validator::new();
 	// Test all metrics with field validation
@@ -49,6 +139,29 @@ pub(crate) async fn single_test(test_id: usize, session_dir: &str) -> Result<()>
 	validator.validate_vmstat(fake_vmstat, real_vmstat)?;
 	validator.validate_diskstat(fake_diskstats, real_diskstats)?;
 	validator.validate_netdev(fake_netdev, real_netdev)?;
+	validator.validate_fragmentation(
+		fake_zones,
+		real_buddyinfo,
+		real_zoneinfo,
+		real_pagetypeinfo,
+	)?;
+	validator.validate_kernel_tables(
+		fake_kernel,
+		real_swaps,
+		real_file_nr,
+		real_inode_nr,
+		real_tasks,
+		real_entropy,
+		real_conntrack,
+	)?;
+	validator.validate_kmsg(fake_kmsg, real_kmsg)?;
+	validator.validate_mdstat(fake_mdstat, real_mdstat)?;
+	validator.validate_block_devices(fake_block, real_block)?;
+	validator.validate_host(fake_host, real_identity, real_os_release, real_cpuinfo, real_dmi)?;
+	validator.validate_links(fake_links, real_links, missing_link)?;
+	validator.validate_hwmon(fake_hwmon, real_chips, real_zones)?;
+	validator.validate_hugepages(&fake_hugepages, &real_hugepages)?;
+	validator.validate_numa(fake_nodes, real_nodes)?;
 
 	println!("  Test #{test_id} validation completed successfully");
 	Ok(())
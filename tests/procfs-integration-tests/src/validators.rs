@@ -2,16 +2,44 @@ use anyhow::Result;
 use approx::relative_eq;
 use pretty_assertions::assert_eq;
 use prism_cpu::stat::Stat;
-use prism_disk::diskstat::{DISK_SECTOR_SIZE, DiskStat};
+use prism_disk::{
+	block::BlockDevice,
+	diskstat::{DISK_SECTOR_SIZE, DiskStat},
+	mdstat::MdArray,
+};
 use prism_fixtures::generators::{
-	FakeDiskStats, FakeInterfaces, FakeMemInfo, FakeStat, FakeVmStat,
+	FakeBlockDevices, FakeChip, FakeDiskStats, FakeHost, FakeHugePages, FakeHwmon, FakeInterfaces,
+	FakeKernelEvent, FakeKernelTables, FakeKmsg, FakeLinks, FakeMdStat, FakeMemInfo, FakeNodes,
+	FakeStat, FakeVmStat, FakeZones,
+};
+use prism_host::{
+	cpuinfo::{CpuInfo, hash_flags},
+	dmi::Dmi,
+	identity::Identity,
+	os_release::OsRelease,
+};
+use prism_hwmon::{hwmon::Chip, thermal::ThermalZone};
+use prism_kernel::{
+	conntrack::Conntrack,
+	fs::{FileNr, InodeNr},
+	kmsg::{self, KernelEvent, KmsgRecord},
+	swaps::Swap,
+	tasks::{Entropy, Tasks},
 };
-use prism_memory::{meminfo::MemInfo, vmstat::VmStat};
-use prism_network::netdev::Interface;
+use prism_memory::{
+	buddyinfo::BuddyInfo, hugepages::HugePagePool, meminfo::MemInfo, numa::Node,
+	pagetypeinfo::PageTypeInfo, vmstat::VmStat, zoneinfo::Zone,
+};
+use prism_network::{link::Link, netdev::Interface};
 use test_utils::convert::clock_ticks;
 use uom::si::{
+	angular_velocity::revolution_per_minute,
+	electric_potential::millivolt,
 	information::{byte, kilobyte},
-	time::{millisecond, second},
+	information_rate::{kilobyte_per_second, megabit_per_second},
+	power::microwatt,
+	thermodynamic_temperature::degree_celsius,
+	time::{millisecond, minute, second},
 };
 /// Validator that compares parsed results with original procfs values
 pub struct Validator;
@@ -477,11 +505,33 @@ impl Validator {
 			true,
 			relative_eq!(
 				fake.VmallocChunk as f64,
-				real.get_VmallocChunk().get::<kilobyte>() as f64,
+				real.get_VmallocChunk().get::<kilobyte>(),
 				epsilon = f64::EPSILON,
 			),
 			"Memory vmalloc chunk mismatch"
 		);
+		assert_eq!(fake.HugePages_Total, *real.get_HugePages_Total(), "HugePages total mismatch");
+		assert_eq!(fake.HugePages_Free, *real.get_HugePages_Free(), "HugePages free mismatch");
+		assert_eq!(fake.HugePages_Rsvd, *real.get_HugePages_Rsvd(), "HugePages reserved mismatch");
+		assert_eq!(fake.HugePages_Surp, *real.get_HugePages_Surp(), "HugePages surplus mismatch");
+		assert_eq!(
+			true,
+			relative_eq!(
+				fake.Hugepagesize as f64,
+				real.get_Hugepagesize().get::<kilobyte>(),
+				epsilon = f64::EPSILON,
+			),
+			"Hugepagesize mismatch"
+		);
+		assert_eq!(
+			true,
+			real.get_Hugetlb().is_some_and(|hugetlb| relative_eq!(
+				fake.Hugetlb as f64,
+				hugetlb.get::<kilobyte>(),
+				epsilon = f64::EPSILON,
+			)),
+			"Hugetlb mismatch"
+		);
 		println!("      Memory field validation successful");
 		Ok(())
 	}
@@ -628,6 +678,27 @@ impl Validator {
 			*real.get_pgscan_direct_throttle(),
 			"VmStat pgscan_direct_throttle mismatch"
 		);
+		assert_eq!(
+			Some(fake.pgsteal_kswapd),
+			*real.get_pgsteal_kswapd(),
+			"VmStat pgsteal_kswapd mismatch"
+		);
+		assert_eq!(
+			Some(fake.pgsteal_direct),
+			*real.get_pgsteal_direct(),
+			"VmStat pgsteal_direct mismatch"
+		);
+		assert_eq!(
+			Some(fake.pgscan_kswapd),
+			*real.get_pgscan_kswapd(),
+			"VmStat pgscan_kswapd mismatch"
+		);
+		assert_eq!(
+			Some(fake.pgscan_direct),
+			*real.get_pgscan_direct(),
+			"VmStat pgscan_direct mismatch"
+		);
+		assert_eq!(Some(fake.oom_kill), *real.get_oom_kill(), "VmStat oom_kill mismatch");
 		assert_eq!(fake.pginodesteal, *real.get_pginodesteal(), "VmStat pginodesteal mismatch");
 		assert_eq!(fake.slabs_scanned, *real.get_slabs_scanned(), "VmStat slabs_scanned mismatch");
 		assert_eq!(
@@ -1024,6 +1095,606 @@ impl Validator {
 		println!("      ");
 		Ok(())
 	}
+	pub fn validate_hwmon(
+		&self,
+		fake: FakeHwmon,
+		chips: Vec<Chip>,
+		zones: Vec<ThermalZone>,
+	) -> Result<()> {
+		println!("    Validating hwmon metrics");
+
+		assert_eq!(fake.chips.len(), chips.len(), "Chip count mismatch");
+		for (id, (fake, real)) in fake.chips.iter().zip(chips.iter()).enumerate() {
+			assert_eq!(format!("hwmon{id}"), real.device(), "Chip device mismatch");
+			assert_eq!(fake.name(), real.name(), "Chip name mismatch");
+
+			assert_eq!(
+				fake.temperatures.len(),
+				real.temperatures().len(),
+				"Temperature count mismatch"
+			);
+			for (index, (fake, real)) in
+				fake.temperatures.iter().zip(real.temperatures()).enumerate()
+			{
+				assert_eq!(
+					FakeChip::label(fake, "temp", index + 1),
+					real.label(),
+					"Temperature label mismatch"
+				);
+				assert!(
+					relative_eq!(
+						fake.input as f64 / 1000.0,
+						real.input().get::<degree_celsius>(),
+						epsilon = 1e-9
+					),
+					"{} temperature mismatch",
+					real.label()
+				);
+				assert_eq!(fake.has_crit, real.crit().is_some(), "{} crit mismatch", real.label());
+				if let Some(crit) = real.crit() {
+					assert!(
+						relative_eq!(
+							fake.crit as f64 / 1000.0,
+							crit.get::<degree_celsius>(),
+							epsilon = 1e-9
+						),
+						"{} crit mismatch",
+						real.label()
+					);
+				}
+			}
+
+			assert_eq!(fake.fans.len(), real.fans().len(), "Fan count mismatch");
+			for (index, (fake, real)) in fake.fans.iter().zip(real.fans()).enumerate() {
+				assert_eq!(
+					FakeChip::label(fake, "fan", index + 1),
+					real.label(),
+					"Fan label mismatch"
+				);
+				assert!(
+					relative_eq!(fake.input as f64, real.input().get::<revolution_per_minute>()),
+					"{} fan speed mismatch",
+					real.label()
+				);
+			}
+
+			assert_eq!(fake.voltages.len(), real.voltages().len(), "Voltage count mismatch");
+			for (index, (fake, real)) in fake.voltages.iter().zip(real.voltages()).enumerate() {
+				assert_eq!(
+					FakeChip::label(fake, "in", index),
+					real.label(),
+					"Voltage label mismatch"
+				);
+				assert!(
+					relative_eq!(fake.input as f64, real.input().get::<millivolt>()),
+					"{} voltage mismatch",
+					real.label()
+				);
+			}
+
+			assert_eq!(fake.powers.len(), real.powers().len(), "Power count mismatch");
+			for (index, (fake, real)) in fake.powers.iter().zip(real.powers()).enumerate() {
+				assert_eq!(
+					FakeChip::label(fake, "power", index + 1),
+					real.label(),
+					"Power label mismatch"
+				);
+				assert!(
+					relative_eq!(fake.input as f64, real.input().get::<microwatt>()),
+					"{} power mismatch",
+					real.label()
+				);
+			}
+		}
+
+		assert_eq!(fake.zones.len(), zones.len(), "Thermal zone count mismatch");
+		for (id, (fake, real)) in fake.zones.iter().zip(zones.iter()).enumerate() {
+			assert_eq!(format!("thermal_zone{id}"), real.name(), "Thermal zone name mismatch");
+			assert_eq!(fake.kind(), real.kind(), "Thermal zone type mismatch");
+			assert!(
+				relative_eq!(
+					fake.temp as f64 / 1000.0,
+					real.temperature().get::<degree_celsius>(),
+					epsilon = 1e-9
+				),
+				"{} temperature mismatch",
+				real.name()
+			);
+		}
+
+		Ok(())
+	}
+
+	pub fn validate_hugepages(&self, fake: &FakeHugePages, real: &[HugePagePool]) -> Result<()> {
+		println!("    Validating hugepages metrics");
+
+		assert_eq!(fake.0.len(), real.len(), "Hugepage pool count mismatch");
+		for ((size, fake), real) in fake.0.iter().zip(real.iter()) {
+			assert_eq!(*size as f64, real.size().get::<kilobyte>(), "Hugepage size mismatch");
+			assert_eq!(fake.nr, real.nr(), "{size}kB nr_hugepages mismatch");
+			assert_eq!(fake.free, real.free(), "{size}kB free_hugepages mismatch");
+			assert_eq!(fake.surplus, real.surplus(), "{size}kB surplus_hugepages mismatch");
+		}
+		Ok(())
+	}
+
+	pub fn validate_numa(&self, fake: FakeNodes, real: Vec<Node>) -> Result<()> {
+		println!("    Validating NUMA metrics");
+
+		assert_eq!(fake.0.len(), real.len(), "NUMA node count mismatch");
+		for (fake, real) in fake.0.iter().zip(real.iter()) {
+			assert_eq!(fake.id, real.id(), "NUMA node id mismatch");
+
+			let (fake_meminfo, meminfo) = (&fake.meminfo, real.meminfo());
+			let fields = [
+				("MemTotal", fake_meminfo.MemTotal, meminfo.get_MemTotal()),
+				("MemFree", fake_meminfo.MemFree, meminfo.get_MemFree()),
+				("MemUsed", fake_meminfo.MemUsed, meminfo.get_MemUsed()),
+				("Active", fake_meminfo.Active, meminfo.get_Active()),
+				("Inactive", fake_meminfo.Inactive, meminfo.get_Inactive()),
+				("Active(anon)", fake_meminfo.Active_anon, meminfo.get_Active_anon()),
+				("Inactive(anon)", fake_meminfo.Inactive_anon, meminfo.get_Inactive_anon()),
+				("Active(file)", fake_meminfo.Active_file, meminfo.get_Active_file()),
+				("Inactive(file)", fake_meminfo.Inactive_file, meminfo.get_Inactive_file()),
+				("Dirty", fake_meminfo.Dirty, meminfo.get_Dirty()),
+				("Writeback", fake_meminfo.Writeback, meminfo.get_Writeback()),
+				("FilePages", fake_meminfo.FilePages, meminfo.get_FilePages()),
+				("Mapped", fake_meminfo.Mapped, meminfo.get_Mapped()),
+				("AnonPages", fake_meminfo.AnonPages, meminfo.get_AnonPages()),
+				("Shmem", fake_meminfo.Shmem, meminfo.get_Shmem()),
+				("Slab", fake_meminfo.Slab, meminfo.get_Slab()),
+				("SReclaimable", fake_meminfo.SReclaimable, meminfo.get_SReclaimable()),
+				("SUnreclaim", fake_meminfo.SUnreclaim, meminfo.get_SUnreclaim()),
+			];
+			for (name, expected, parsed) in fields {
+				assert_eq!(
+					true,
+					relative_eq!(expected as f64, parsed.get::<kilobyte>(), epsilon = f64::EPSILON),
+					"node{} {name} mismatch",
+					fake.id
+				);
+			}
+			assert_eq!(
+				fake_meminfo.HugePages_Total,
+				*meminfo.get_HugePages_Total(),
+				"node{} HugePages_Total mismatch",
+				fake.id
+			);
+			assert_eq!(
+				fake_meminfo.HugePages_Free,
+				*meminfo.get_HugePages_Free(),
+				"node{} HugePages_Free mismatch",
+				fake.id
+			);
+			assert_eq!(
+				fake_meminfo.HugePages_Surp,
+				*meminfo.get_HugePages_Surp(),
+				"node{} HugePages_Surp mismatch",
+				fake.id
+			);
+
+			let (fake_numastat, numastat) = (&fake.numastat, real.numastat());
+			assert_eq!(fake_numastat.numa_hit, *numastat.get_numa_hit(), "numa_hit mismatch");
+			assert_eq!(fake_numastat.numa_miss, *numastat.get_numa_miss(), "numa_miss mismatch");
+			assert_eq!(
+				fake_numastat.numa_foreign,
+				*numastat.get_numa_foreign(),
+				"numa_foreign mismatch"
+			);
+			assert_eq!(
+				fake_numastat.interleave_hit,
+				*numastat.get_interleave_hit(),
+				"interleave_hit mismatch"
+			);
+			assert_eq!(fake_numastat.local_node, *numastat.get_local_node(), "local_node mismatch");
+			assert_eq!(fake_numastat.other_node, *numastat.get_other_node(), "other_node mismatch");
+
+			self.validate_hugepages(&fake.hugepages, real.hugepages())?;
+		}
+		Ok(())
+	}
+	pub fn validate_fragmentation(
+		&self,
+		fake: FakeZones,
+		buddyinfo: Vec<BuddyInfo>,
+		zoneinfo: Vec<Zone>,
+		pagetypeinfo: Vec<PageTypeInfo>,
+	) -> Result<()> {
+		println!("    Validating fragmentation metrics");
+
+		assert_eq!(fake.0.len(), buddyinfo.len(), "BuddyInfo zone count mismatch");
+		for (fake, real) in fake.0.iter().zip(buddyinfo.iter()) {
+			assert_eq!(
+				(fake.node, fake.name),
+				(real.node(), real.zone()),
+				"BuddyInfo zone mismatch"
+			);
+			assert_eq!(
+				fake.free,
+				real.free(),
+				"node{} {} free blocks mismatch",
+				fake.node,
+				fake.name
+			);
+		}
+
+		assert_eq!(fake.0.len(), zoneinfo.len(), "ZoneInfo zone count mismatch");
+		for (fake, real) in fake.0.iter().zip(zoneinfo.iter()) {
+			assert_eq!(
+				(fake.node, fake.name),
+				(real.node(), real.name()),
+				"ZoneInfo zone mismatch"
+			);
+			let (expected, stat) = (&fake.stat, real.stat());
+			assert_eq!(expected.free, *stat.get_free(), "{} pages free mismatch", fake.name);
+			assert_eq!(
+				expected.has_boost.then_some(expected.boost),
+				*stat.get_boost(),
+				"{} boost mismatch",
+				fake.name
+			);
+			assert_eq!(expected.min, *stat.get_min(), "{} min mismatch", fake.name);
+			assert_eq!(expected.low, *stat.get_low(), "{} low mismatch", fake.name);
+			assert_eq!(expected.high, *stat.get_high(), "{} high mismatch", fake.name);
+			assert_eq!(expected.spanned, *stat.get_spanned(), "{} spanned mismatch", fake.name);
+			assert_eq!(expected.present, *stat.get_present(), "{} present mismatch", fake.name);
+			assert_eq!(expected.managed, *stat.get_managed(), "{} managed mismatch", fake.name);
+		}
+
+		let expected: Vec<_> = fake
+			.0
+			.iter()
+			.flat_map(|zone| zone.free_by_type.iter().map(move |(kind, free)| (zone, kind, free)))
+			.collect();
+		assert_eq!(expected.len(), pagetypeinfo.len(), "PageTypeInfo count mismatch");
+		for ((zone, kind, free), real) in expected.into_iter().zip(pagetypeinfo.iter()) {
+			assert_eq!(
+				(zone.node, zone.name),
+				(real.node(), real.zone()),
+				"PageTypeInfo zone mismatch"
+			);
+			assert_eq!(*kind, real.migrate_type(), "PageTypeInfo migrate type mismatch");
+			assert_eq!(free, real.free(), "{} {kind} free blocks mismatch", zone.name);
+		}
+		Ok(())
+	}
+
+	#[allow(clippy::too_many_arguments)]
+	pub fn validate_kernel_tables(
+		&self,
+		fake: FakeKernelTables,
+		swaps: Vec<Swap>,
+		file_nr: FileNr,
+		inode_nr: InodeNr,
+		tasks: Tasks,
+		entropy: Entropy,
+		conntrack: Option<Conntrack>,
+	) -> Result<()> {
+		assert_eq!(fake.swaps.len(), swaps.len(), "Swap count mismatch");
+		for (fake, real) in fake.swaps.iter().zip(swaps.iter()) {
+			assert_eq!(&fake.filename(), real.get_filename(), "Swap filename mismatch");
+			assert_eq!(fake.kind(), real.get_kind(), "{} type mismatch", fake.filename());
+			assert_eq!(
+				fake.size,
+				real.get_size().get::<kilobyte>() as u64,
+				"{} size mismatch",
+				fake.filename()
+			);
+			assert_eq!(
+				fake.used,
+				real.get_used().get::<kilobyte>() as u64,
+				"{} used mismatch",
+				fake.filename()
+			);
+			assert_eq!(
+				fake.priority,
+				*real.get_priority(),
+				"{} priority mismatch",
+				fake.filename()
+			);
+		}
+
+		assert_eq!(
+			(fake.file_allocated, fake.file_unused, fake.file_max),
+			(file_nr.allocated(), file_nr.unused(), file_nr.max()),
+			"file-nr mismatch"
+		);
+		assert_eq!(
+			(fake.inode_allocated, fake.inode_free),
+			(inode_nr.allocated(), inode_nr.free()),
+			"inode-nr mismatch"
+		);
+		assert_eq!(
+			(fake.tasks, fake.pid_max, fake.threads_max),
+			(tasks.count(), tasks.pid_max(), tasks.threads_max()),
+			"Task limits mismatch"
+		);
+		assert_eq!(
+			(fake.entropy_avail, 256),
+			(entropy.available(), entropy.pool_size()),
+			"Entropy mismatch"
+		);
+		assert_eq!(
+			fake.conntrack.map(|conntrack| (conntrack.count, conntrack.max)),
+			conntrack.map(|conntrack| (conntrack.count(), conntrack.max())),
+			"Conntrack mismatch"
+		);
+		Ok(())
+	}
+
+	pub fn validate_links(
+		&self,
+		fake: FakeLinks,
+		real: Vec<Link>,
+		missing: Option<Link>,
+	) -> Result<()> {
+		println!("    Validating Link metrics");
+
+		assert!(missing.is_none(), "Link without a sysfs entry should be None");
+		assert_eq!(fake.0.len(), real.len(), "Link count mismatch");
+		for (fake, real) in fake.0.iter().zip(real.iter()) {
+			assert_eq!(fake.name, real.name(), "Link name mismatch");
+			assert_eq!(fake.role, real.role().as_str(), "{} role mismatch", fake.name);
+			assert_eq!(fake.operstate, real.operstate(), "{} operstate mismatch", fake.name);
+			assert_eq!(fake.operstate == "up", real.is_up(), "{} up mismatch", fake.name);
+			assert_eq!(fake.carrier, real.carrier(), "{} carrier mismatch", fake.name);
+			assert_eq!(
+				Some(fake.carrier_changes),
+				real.carrier_changes(),
+				"{} carrier_changes mismatch",
+				fake.name
+			);
+			assert_eq!(
+				fake.speed,
+				real.speed().map(|speed| speed.get::<megabit_per_second>() as u64),
+				"{} speed mismatch",
+				fake.name
+			);
+			assert_eq!(
+				fake.duplex.unwrap_or("unknown"),
+				real.duplex().as_str(),
+				"{} duplex mismatch",
+				fake.name
+			);
+			assert_eq!(fake.mtu, real.mtu(), "{} mtu mismatch", fake.name);
+			assert_eq!(fake.address, real.address(), "{} address mismatch", fake.name);
+			assert_eq!(fake.kind, real.kind(), "{} type mismatch", fake.name);
+			assert_eq!(fake.master.as_deref(), real.master(), "{} master mismatch", fake.name);
+			assert_eq!(fake.members, real.members(), "{} members mismatch", fake.name);
+		}
+		Ok(())
+	}
+
+	pub fn validate_block_devices(
+		&self,
+		fake: FakeBlockDevices,
+		real: Vec<BlockDevice>,
+	) -> Result<()> {
+		println!("    Validating Block device metrics");
+
+		assert_eq!(fake.0.len(), real.len(), "Block device count mismatch");
+		for (fake, real) in fake.0.iter().zip(real.iter()) {
+			assert_eq!(fake.name, real.name(), "Block device name mismatch");
+			assert_eq!(fake.disk.as_deref(), real.disk(), "{} disk mismatch", fake.name);
+			assert_eq!(fake.slaves, real.slaves(), "{} slaves mismatch", fake.name);
+			assert_eq!(fake.holders, real.holders(), "{} holders mismatch", fake.name);
+			assert_eq!(fake.parents(), real.parents(), "{} parents mismatch", fake.name);
+			assert_eq!(
+				fake.size * DISK_SECTOR_SIZE,
+				real.size().get::<byte>() as u64,
+				"{} size mismatch",
+				fake.name
+			);
+			assert_eq!(
+				Some(fake.rotational),
+				real.rotational(),
+				"{} rotational mismatch",
+				fake.name
+			);
+			assert_eq!(Some(fake.scheduler), real.scheduler(), "{} scheduler mismatch", fake.name);
+			assert_eq!(
+				Some(fake.nr_requests),
+				real.nr_requests(),
+				"{} nr_requests mismatch",
+				fake.name
+			);
+			assert_eq!(fake.model, real.model(), "{} model mismatch", fake.name);
+			assert_eq!(fake.removable, real.removable(), "{} removable mismatch", fake.name);
+			assert_eq!(fake.disk_type, real.disk_type().as_str(), "{} type mismatch", fake.name);
+			assert_eq!(
+				fake.dm.as_ref().map(|(name, uuid)| (name.as_str(), uuid.as_str())),
+				real.dm_name().zip(real.dm_uuid()),
+				"{} dm mismatch",
+				fake.name
+			);
+		}
+		Ok(())
+	}
+
+	pub fn validate_mdstat(&self, fake: FakeMdStat, real: Vec<MdArray>) -> Result<()> {
+		println!("    Validating MdStat metrics");
+
+		assert_eq!(fake.0.len(), real.len(), "Array count mismatch");
+		for (fake, real) in fake.0.iter().zip(real.iter()) {
+			assert_eq!(fake.name, real.name(), "Array name mismatch");
+			assert_eq!(fake.active, real.is_active(), "{} state mismatch", fake.name);
+			assert_eq!(fake.read_only, real.read_only(), "{} read-only mismatch", fake.name);
+			assert_eq!(
+				fake.active.then_some(fake.level),
+				real.level(),
+				"{} level mismatch",
+				fake.name
+			);
+			assert_eq!(
+				fake.blocks,
+				real.size().get::<kilobyte>() as u64,
+				"{} size mismatch",
+				fake.name
+			);
+			assert_eq!(
+				fake.disks,
+				real.raid_disks().zip(real.active_disks()),
+				"{} disks mismatch",
+				fake.name
+			);
+			assert_eq!(
+				fake.disks.is_some_and(|(raid, active)| active < raid),
+				real.is_degraded(),
+				"{} degraded mismatch",
+				fake.name
+			);
+
+			assert_eq!(
+				fake.members.len(),
+				real.members().len(),
+				"{} member count mismatch",
+				fake.name
+			);
+			for (expected, member) in fake.members.iter().zip(real.members()) {
+				assert_eq!(
+					(expected.name.as_str(), expected.slot, expected.role),
+					(member.name(), member.slot(), member.role().as_str()),
+					"{} member mismatch",
+					fake.name
+				);
+			}
+
+			assert_eq!(fake.sync.is_some(), real.sync().is_some(), "{} sync mismatch", fake.name);
+			if let (Some(expected), Some(sync)) = (&fake.sync, real.sync()) {
+				assert_eq!(expected.action, sync.action(), "{} sync action mismatch", fake.name);
+				assert!(
+					relative_eq!(
+						expected.permille.unwrap_or_default() as f64 / 1000.0,
+						sync.progress(),
+						epsilon = 1e-9
+					),
+					"{} sync progress mismatch",
+					fake.name
+				);
+				assert_eq!(
+					expected.permille.map(|_| expected.finish),
+					sync.finish().map(|finish| (finish.get::<minute>() * 10.0).round() as u64),
+					"{} sync finish mismatch",
+					fake.name
+				);
+				assert_eq!(
+					expected.permille.map(|_| expected.speed),
+					sync.speed().map(|speed| speed.get::<kilobyte_per_second>().round() as u64),
+					"{} sync speed mismatch",
+					fake.name
+				);
+			}
+		}
+		Ok(())
+	}
+
+	pub fn validate_host(
+		&self,
+		fake: FakeHost,
+		identity: Identity,
+		os: OsRelease,
+		cpu: CpuInfo,
+		dmi: Dmi,
+	) -> Result<()> {
+		println!("    Validating host identity");
+
+		assert_eq!(fake.hostname, identity.hostname(), "Hostname mismatch");
+		assert_eq!(fake.machine_id.as_deref(), identity.machine_id(), "Machine id mismatch");
+		assert_eq!(fake.boot_id, identity.boot_id(), "Boot id mismatch");
+		assert_eq!(fake.kernel_release, identity.kernel_release(), "Kernel release mismatch");
+		assert_eq!(fake.kernel_version, identity.kernel_version(), "Kernel version mismatch");
+
+		assert_eq!(Some(fake.os_id), os.id(), "OS id mismatch");
+		assert_eq!(Some(fake.os_version_id), os.version_id(), "OS version mismatch");
+		assert_eq!(Some(fake.os_pretty_name), os.pretty_name(), "OS name mismatch");
+
+		assert_eq!(Some(fake.cpu_model.as_str()), cpu.model(), "CPU model mismatch");
+		assert_eq!(Some(fake.microcode.as_str()), cpu.microcode(), "Microcode mismatch");
+		assert_eq!(fake.cpus, cpu.cpus(), "CPU count mismatch");
+		assert_eq!(hash_flags(&fake.flags.join(" ")), cpu.flags_hash(), "CPU flags hash mismatch");
+
+		let fake_dmi = fake.dmi.as_ref();
+		assert_eq!(
+			fake_dmi.map(|d| d.sys_vendor.as_str()),
+			dmi.sys_vendor(),
+			"DMI vendor mismatch"
+		);
+		assert_eq!(
+			fake_dmi.map(|d| d.product_name.as_str()),
+			dmi.product_name(),
+			"DMI product mismatch"
+		);
+		assert_eq!(
+			fake_dmi.map(|d| d.bios_version.as_str()),
+			dmi.bios_version(),
+			"DMI bios version mismatch"
+		);
+		assert_eq!(
+			fake_dmi.and_then(|d| d.product_serial.as_deref()),
+			dmi.product_serial(),
+			"DMI serial mismatch"
+		);
+		assert_eq!(
+			fake_dmi.map(|d| d.chassis_type.as_str()),
+			dmi.chassis_type(),
+			"DMI chassis mismatch"
+		);
+		assert_eq!(None, dmi.board_name(), "Empty DMI field should be None");
+		assert_eq!(None, dmi.product_uuid(), "Missing DMI field should be None");
+		Ok(())
+	}
+
+	pub fn validate_kmsg(&self, fake: FakeKmsg, real: Vec<KmsgRecord>) -> Result<()> {
+		println!("    Validating kernel log records");
+
+		assert_eq!(fake.0.len(), real.len(), "Record count mismatch");
+		for (fake, real) in fake.0.iter().zip(real.iter()) {
+			assert_eq!(fake.level, real.level(), "Record {} level mismatch", fake.sequence);
+			assert_eq!(
+				fake.facility,
+				real.facility(),
+				"Record {} facility mismatch",
+				fake.sequence
+			);
+			assert_eq!(fake.sequence, real.sequence(), "Sequence mismatch");
+			assert_eq!(
+				fake.timestamp,
+				real.timestamp().as_micros() as u64,
+				"Record {} timestamp mismatch",
+				fake.sequence
+			);
+			assert_eq!(
+				fake.continuation,
+				real.continuation(),
+				"Record {} flag mismatch",
+				fake.sequence
+			);
+			assert_eq!(fake.message, real.message(), "Record {} message mismatch", fake.sequence);
+
+			let event = kmsg::classify(real.message()).map(|event| match event {
+				KernelEvent::OomKill { pid, comm, total_vm, anon_rss, file_rss, shmem_rss } => {
+					FakeKernelEvent::OomKill { pid, comm, total_vm, anon_rss, file_rss, shmem_rss }
+				},
+				KernelEvent::HungTask { pid, comm, seconds } => {
+					FakeKernelEvent::HungTask { pid, comm, seconds }
+				},
+				KernelEvent::SoftLockup { cpu, seconds, pid, comm } => {
+					FakeKernelEvent::SoftLockup { cpu, seconds, pid, comm }
+				},
+				KernelEvent::IoError { device, sector } => {
+					FakeKernelEvent::IoError { device, sector }
+				},
+				KernelEvent::Segfault { pid, comm, address, ip, error } => {
+					FakeKernelEvent::Segfault { pid, comm, address, ip, error }
+				},
+				KernelEvent::Link { interface, up, speed } => {
+					FakeKernelEvent::Link { interface, up, speed }
+				},
+			});
+			assert_eq!(fake.event, event, "Record {} event mismatch", fake.sequence);
+		}
+		Ok(())
+	}
 }
 
 // Save parsed results to file